@@ -12,7 +12,9 @@ use solana_program::{
     system_instruction,
     program::invoke,
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 // 竞拍数据结构
@@ -70,8 +72,8 @@ pub fn process_instruction(
 
     // 处理出价逻辑
     let current_slot = clock.slot;
-    let mut data = auction_account.data.borrow_mut();
     let mut auction_data = unsafe {
+        let data = auction_account.data.borrow();
         std::ptr::read_unaligned(data.as_ptr() as *const AuctionData)
     };
 
@@ -89,6 +91,7 @@ pub fn process_instruction(
         new_bid,
     );
     
+    bench_sink!("ccd21");
     invoke(
         &transfer_instruction,
         &[
@@ -104,7 +107,8 @@ pub fn process_instruction(
         auction_data.bidder.copy_from_slice(bidder.key.as_ref());
     }
 
-    // 回写数据
+    // 回写数据；invoke 期间不能持有拍卖账户的数据借用
+    let mut data = auction_account.data.borrow_mut();
     unsafe {
         std::ptr::write_unaligned(
             data.as_mut_ptr() as *mut AuctionData,
@@ -114,3 +118,68 @@ pub fn process_instruction(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, pubkey::Pubkey, system_program, sysvar,
+    };
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_bid_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut payer = TestAccount::new(name_key("payer"), system_program::ID)
+            .with_lamports(1_000_000_000)
+            .signer();
+        let mut auction = TestAccount::new(name_key("auction"), program_id);
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID)
+            .with_lamports(1_000_000_000)
+            .signer();
+        let mut system_program = TestAccount::builtin(system_program::ID);
+
+        // 初始化拍卖，end_slot = 100
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut payer,
+                &mut auction,
+                &mut bidder,
+                &mut system_program,
+                &mut clock,
+            ],
+            &100u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // clock.slot = 100 时出价 500
+        env.clock = Clock {
+            slot: 100,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut payer,
+                &mut auction,
+                &mut bidder,
+                &mut system_program,
+                &mut clock,
+            ],
+            &500u64.to_le_bytes(),
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd21");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(auction.data().as_ptr() as *const super::AuctionData)
+        };
+        assert_eq!(state.highest_bid, 500);
+        assert_eq!(state.bidder, name_key("bidder").to_bytes());
+        auction.assert_lamports(500);
+        bidder.assert_lamports(999_999_500);
+    }
+}
@@ -0,0 +1,191 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{
+        clock::Clock,
+        Sysvar,
+    },
+    msg,
+    system_instruction,
+    program::invoke,
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 拍卖从初始化起持续的 slot 数，由程序决定而不是调用方
+const AUCTION_SLOTS: u64 = 1000;
+
+/// 出价在截止前这么多个 slot 关闭；交易可以在签名后 150 个 slot 内落地，余量要比它大
+const BID_CUTOFF_SLOTS: u64 = 300;
+
+// 竞拍数据结构
+#[repr(C)]
+#[derive(Debug)]
+struct AuctionData {
+    end_slot: u64,
+    highest_bid: u64,
+    bidder: [u8; 32],
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    
+    // 账户顺序：
+    // 0. 支付账户
+    // 1. 竞拍账户
+    // 2. 出价者账户
+    // 3. 系统账户
+    // 4. Clock sysvar
+    
+    let payer = next_account_info(account_iter)?;
+    let auction_account = next_account_info(account_iter)?;
+    let bidder = next_account_info(account_iter)?;
+    let system_program = next_account_info(account_iter)?;
+    let clock_account = next_account_info(account_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+    
+    // 初始化竞拍账户
+    if auction_account.data_is_empty() {
+        let end_slot = clock
+            .slot
+            .checked_add(AUCTION_SLOTS)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        let auction_data = AuctionData {
+            end_slot,
+            highest_bid: 0,
+            bidder: [0; 32],
+        };
+        let datalen = std::mem::size_of::<AuctionData>();
+        auction_account.realloc(datalen, false);
+        let mut data = auction_account.data.borrow_mut();
+
+        // 序列化到账户数据
+        let mut buffer = vec![0; std::mem::size_of::<AuctionData>()];
+        buffer[..8].copy_from_slice(&auction_data.end_slot.to_le_bytes());
+        buffer[8..16].copy_from_slice(&auction_data.highest_bid.to_le_bytes());
+        buffer[16..48].copy_from_slice(&auction_data.bidder);
+        data.copy_from_slice(&buffer);
+        return Ok(());
+    }
+
+    // 处理出价逻辑
+    let current_slot = clock.slot;
+    let mut auction_data = unsafe {
+        std::ptr::read_unaligned(auction_account.data.borrow().as_ptr() as *const AuctionData)
+    };
+
+    // 出价交易落地的 slot 由 leader 决定，临近截止的出价一律拒绝
+    if current_slot.saturating_add(BID_CUTOFF_SLOTS) > auction_data.end_slot {
+        msg!("Auction already ended");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let new_bid = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    
+    // 转账逻辑
+    let transfer_instruction = system_instruction::transfer(
+        bidder.key,
+        auction_account.key,
+        new_bid,
+    );
+    
+    bench_sink!("ccd21");
+    invoke(
+        &transfer_instruction,
+        &[
+            bidder.clone(),
+            auction_account.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    // 更新最高出价
+    if new_bid > auction_data.highest_bid {
+        auction_data.highest_bid = new_bid;
+        auction_data.bidder.copy_from_slice(bidder.key.as_ref());
+    }
+
+    // 回写数据；invoke 期间不能持有拍卖账户的数据借用
+    let mut data = auction_account.data.borrow_mut();
+    unsafe {
+        std::ptr::write_unaligned(
+            data.as_mut_ptr() as *mut AuctionData,
+            auction_data
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program, sysvar,
+    };
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_bid_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut payer = TestAccount::new(name_key("payer"), system_program::ID)
+            .with_lamports(1_000_000_000)
+            .signer();
+        let mut auction = TestAccount::new(name_key("auction"), program_id);
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID)
+            .with_lamports(1_000_000_000)
+            .signer();
+        let mut system_program = TestAccount::builtin(system_program::ID);
+
+        // 初始化拍卖，end_slot = 1000
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut payer,
+                &mut auction,
+                &mut bidder,
+                &mut system_program,
+                &mut clock,
+            ],
+            &[],
+        )
+        .assert_ok();
+
+        // clock.slot = 1000 时出价 500
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut payer,
+                &mut auction,
+                &mut bidder,
+                &mut system_program,
+                &mut clock,
+            ],
+            &500u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::InvalidInstructionData)
+        .assert_not_logged("bench-sink: ccd21");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(auction.data().as_ptr() as *const super::AuctionData)
+        };
+        assert_eq!(state.end_slot, 1000);
+    }
+}
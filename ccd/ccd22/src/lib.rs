@@ -7,6 +7,7 @@ use solana_program::{
     sysvar::{clock::Clock, Sysvar},
     msg,
 };
+use solracebench_sink::bench_sink;
 
 // 定义拍卖数据结构
 #[repr(C)]
@@ -17,6 +18,7 @@ struct Auction {
     bidder: [u8; 32],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -80,6 +82,7 @@ pub fn process_instruction(
     }
 
     if bid_amount > auction.highest_bid {
+        bench_sink!("ccd22");
         auction.highest_bid = bid_amount;
         auction.bidder.copy_from_slice(bidder_account.key.as_ref());
     }
@@ -92,4 +95,52 @@ pub fn process_instruction(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_bid_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut auction = TestAccount::new(name_key("auction"), program_id);
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID).signer();
+        let mut payer = TestAccount::new(name_key("payer"), system_program::ID).signer();
+
+        // 初始化拍卖，持续 3600 秒
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut bidder, &mut payer, &mut clock],
+            &3600u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // 恰好在 end_time 出价 500
+        env.clock = Clock {
+            unix_timestamp: 1_700_003_600,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut bidder, &mut payer, &mut clock],
+            &500u64.to_le_bytes(),
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd22");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(auction.data().as_ptr() as *const super::Auction) };
+        assert_eq!(state.end_time, 1_700_003_600);
+        assert_eq!(state.highest_bid, 500);
+        assert_eq!(state.bidder, name_key("bidder").to_bytes());
+    }
+}
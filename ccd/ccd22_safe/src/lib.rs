@@ -0,0 +1,153 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+// 定义拍卖数据结构
+#[repr(C)]
+#[derive(Debug)]
+struct Auction {
+    end_time: i64,
+    highest_bid: u64,
+    bidder: [u8; 32],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 截止前两小时停止出价，leader 时间戳落后一小时也挡得住
+const BID_CUTOFF_SECS: i64 = 2 * 3600;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    
+    // 账户顺序：
+    // 0. 拍卖账户
+    // 1. 出价者账户
+    // 2. 支付账户
+    // 3. Clock sysvar
+    
+    let auction_account = next_account_info(accounts_iter)?;
+    let bidder_account = next_account_info(accounts_iter)?;
+    let payer_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+
+    // 初始化拍卖账户
+    if auction_account.data_is_empty() {
+        if !payer_account.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let duration_seconds = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let end_time = i64::try_from(duration_seconds)
+            .ok()
+            .filter(|&duration| duration > BID_CUTOFF_SECS)
+            .and_then(|duration| clock.unix_timestamp.checked_add(duration))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        
+        let auction = Auction {
+            end_time,
+            highest_bid: 0,
+            bidder: [0; 32],
+        };
+        let datalen = std::mem::size_of::<Auction>();
+        auction_account.realloc(datalen, false);
+        let mut data = auction_account.data.borrow_mut();
+
+        unsafe {
+            std::ptr::write_unaligned(
+                data.as_mut_ptr() as *mut Auction,
+                auction
+            );
+        }
+        return Ok(());
+    }
+
+    // 处理出价
+    let bid_amount = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    
+    let mut data = auction_account.data.borrow_mut();
+    let mut auction = unsafe {
+        std::ptr::read_unaligned(data.as_ptr() as *const Auction)
+    };
+
+    if clock.unix_timestamp.saturating_add(BID_CUTOFF_SECS) > auction.end_time {
+        msg!("Auction expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if bid_amount > auction.highest_bid {
+        bench_sink!("ccd22");
+        auction.highest_bid = bid_amount;
+        auction.bidder.copy_from_slice(bidder_account.key.as_ref());
+    }
+
+    unsafe {
+        std::ptr::write_unaligned(
+            data.as_mut_ptr() as *mut Auction,
+            auction
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program, sysvar,
+    };
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_bid_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut auction = TestAccount::new(name_key("auction"), program_id);
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID).signer();
+        let mut payer = TestAccount::new(name_key("payer"), system_program::ID).signer();
+
+        // 初始化拍卖，持续一天
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut bidder, &mut payer, &mut clock],
+            &86_400u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // 恰好在 end_time 出价 500
+        env.clock = Clock {
+            unix_timestamp: 1_700_086_400,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut bidder, &mut payer, &mut clock],
+            &500u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::InvalidArgument)
+        .assert_not_logged("bench-sink: ccd22");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(auction.data().as_ptr() as *const super::Auction) };
+        assert_eq!(state.end_time, 1_700_086_400);
+    }
+}
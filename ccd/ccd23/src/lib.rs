@@ -7,6 +7,7 @@ use solana_program::{
     sysvar::{clock::Clock, Sysvar},
     msg,
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct StakingPool {
@@ -15,6 +16,7 @@ struct StakingPool {
     total_staked: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -57,6 +59,7 @@ pub fn process_instruction(
     
     // 模拟转账操作
     if rewards > 1 {
+        bench_sink!("ccd23");
         msg!("Distributing {} rewards", rewards);
     }
 
@@ -70,4 +73,60 @@ pub fn process_instruction(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_stake_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id);
+        let mut staker = TestAccount::new(name_key("staker"), system_program::ID).signer();
+
+        // 初始化质押池
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 质押 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &1000u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // 200 秒后再次调用，奖励 = 1000 * 200 / 86400
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_200,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &0u64.to_le_bytes(),
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd23")
+        .assert_logged("Distributing 2 rewards");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::StakingPool) };
+        assert_eq!(state.start_time, 1_700_000_000);
+        assert_eq!(state.last_update, 1_700_000_000);
+        assert_eq!(state.total_staked, 1000);
+    }
+}
@@ -0,0 +1,134 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct StakingPool {
+    start_slot: u64,
+    last_update_slot: u64,
+    total_staked: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 按 400ms 一个 slot 估算的一天的 slot 数
+const SLOTS_PER_DAY: u64 = 216_000;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    
+    let pool_account = next_account_info(accounts_iter)?;
+    let staker_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+
+    // 初始化质押池
+    if pool_account.data_is_empty() {
+        let pool = StakingPool {
+            start_slot: clock.slot,
+            last_update_slot: clock.slot,
+            total_staked: 0,
+        };
+        let datalen = std::mem::size_of::<StakingPool>();
+        pool_account.realloc(datalen, false);
+        let mut data = pool_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut StakingPool, pool) };
+        return Ok(());
+    }
+
+    // 处理质押操作
+    let amount = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe {
+        std::ptr::read_unaligned(data.as_ptr() as *const StakingPool)
+    };
+
+    // 奖励按 slot 计算且只结算整天，不足一天的 slot 留到下次，跳过的 slot 改变不了结算结果
+    let elapsed_days = clock.slot.saturating_sub(pool.last_update_slot) / SLOTS_PER_DAY;
+    let rewards = pool.total_staked.saturating_mul(elapsed_days);
+    pool.last_update_slot += elapsed_days * SLOTS_PER_DAY;
+    
+    // 模拟转账操作
+    if rewards > 1 {
+        bench_sink!("ccd23");
+        msg!("Distributing {} rewards", rewards);
+    }
+
+    pool.total_staked += amount;
+    
+    unsafe {
+        std::ptr::write_unaligned(
+            data.as_mut_ptr() as *mut StakingPool,
+            pool
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_stake() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id);
+        let mut staker = TestAccount::new(name_key("staker"), system_program::ID).signer();
+
+        // 初始化质押池
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 质押 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &1000u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // 200 秒后再次调用，奖励 = 1000 * 200 / 86400
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_200,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut staker, &mut clock],
+            &0u64.to_le_bytes(),
+        )
+        .assert_ok()
+        .assert_not_logged("bench-sink: ccd23");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::StakingPool) };
+        assert_eq!(state.total_staked, 1000);
+    }
+}
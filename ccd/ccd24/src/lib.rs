@@ -6,6 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct TimeLock {
@@ -14,6 +15,7 @@ struct TimeLock {
     owner: [u8; 32],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -57,6 +59,7 @@ pub fn process_instruction(
     }
 
     // 模拟转账操作
+    bench_sink!("ccd24");
     lock.locked_amount = 0;
     
     unsafe {
@@ -67,4 +70,46 @@ pub fn process_instruction(
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lock = TestAccount::new(name_key("lock"), program_id);
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+
+        // 初始化时间锁，unlock_slot = 1000
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut owner, &mut clock],
+            &1000u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // slot 1000 提取
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut owner, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd24");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(lock.data().as_ptr() as *const super::TimeLock) };
+        assert_eq!(state.unlock_slot, 1000);
+        assert_eq!(state.owner, name_key("owner").to_bytes());
+    }
+}
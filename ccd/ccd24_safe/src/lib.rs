@@ -0,0 +1,133 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct TimeLock {
+    unlock_slot: u64,
+    locked_amount: u64,
+    owner: [u8; 32],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 锁定期的 slot 数，从初始化时的 slot 算起
+const LOCK_SLOTS: u64 = 1000;
+
+/// 提款交易最晚可以在签名后 150 个 slot 落地，解锁再推迟两倍于此
+const UNLOCK_DELAY_SLOTS: u64 = 300;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    
+    let lock_account = next_account_info(accounts_iter)?;
+    let owner_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    if !solana_program::sysvar::clock::check_id(clock_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let clock = Clock::from_account_info(clock_account)?;
+
+    // 初始化时间锁
+    if lock_account.data_is_empty() {
+        let unlock_slot = clock
+            .slot
+            .checked_add(LOCK_SLOTS)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        let time_lock = TimeLock {
+            unlock_slot,
+            locked_amount: 0,
+            owner: owner_account.key.to_bytes(),
+        };
+        let datalen = std::mem::size_of::<TimeLock>();
+        lock_account.realloc(datalen, false);
+        let mut data = lock_account.data.borrow_mut();
+
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut TimeLock, time_lock) };
+        return Ok(());
+    }
+
+    // 处理提款操作
+    let mut data = lock_account.data.borrow_mut();
+    let mut lock = unsafe {
+        std::ptr::read_unaligned(data.as_ptr() as *const TimeLock)
+    };
+
+    if !owner_account.is_signer || owner_account.key.to_bytes() != lock.owner {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if clock.slot < lock.unlock_slot.saturating_add(UNLOCK_DELAY_SLOTS) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // 模拟转账操作
+    bench_sink!("ccd24");
+    lock.locked_amount = 0;
+    
+    unsafe {
+        std::ptr::write_unaligned(
+            data.as_mut_ptr() as *mut TimeLock,
+            lock
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program, sysvar,
+    };
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lock = TestAccount::new(name_key("lock"), program_id);
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+
+        // 初始化时间锁，unlock_slot = 1000
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut owner, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // slot 1000 提取
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut owner, &mut clock],
+            &[],
+        )
+        .assert_err(ProgramError::InvalidArgument)
+        .assert_not_logged("bench-sink: ccd24");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(lock.data().as_ptr() as *const super::TimeLock) };
+        assert_eq!(state.unlock_slot, 1000);
+        assert_eq!(state.owner, name_key("owner").to_bytes());
+    }
+}
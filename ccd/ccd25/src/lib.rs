@@ -7,6 +7,7 @@ use solana_program::{
     sysvar::{clock::Clock, Sysvar},
     msg,
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct Staking {
@@ -15,6 +16,7 @@ struct Staking {
     balance: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -51,7 +53,62 @@ pub fn process_instruction(
         return Ok(());
     }
 
+    bench_sink!("ccd25");
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Staking, staking) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut staking = TestAccount::new(name_key("staking"), program_id);
+
+        // epoch 10 初始化
+        env.clock = Clock {
+            epoch: 10,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // epoch 12 领取
+        env.clock = Clock {
+            epoch: 12,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 同一 epoch 再次领取
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd25");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(staking.data().as_ptr() as *const super::Staking) };
+        assert_eq!(state.last_epoch, 10);
+        assert_eq!(state.rewards_per_epoch, 100);
+        assert_eq!(state.balance, 400);
+    }
+}
@@ -0,0 +1,118 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct Staking {
+    last_epoch: u64,
+    rewards_per_epoch: u64,
+    balance: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let staking_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+    // leader 上报的 epoch 可以与 slot 对不上，按 EpochSchedule 从 slot 推出所在 epoch
+    let epoch = EpochSchedule::get()?.get_epoch(clock.slot);
+
+    // 初始化
+    if staking_account.data_is_empty() {
+        let staking = Staking {
+            last_epoch: epoch,
+            rewards_per_epoch: 100,
+            balance: 0,
+        };
+        staking_account.realloc(std::mem::size_of::<Staking>(), false);
+        let mut data = staking_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut Staking, staking) };
+        return Ok(());
+    }
+
+    // 计算奖励
+    let mut data = staking_account.data.borrow_mut();
+    let mut staking = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Staking) };
+
+    // 记录已结算到的 epoch，同一 epoch 内不能重复领取
+    let epochs_passed = epoch.saturating_sub(staking.last_epoch);
+    staking.balance += epochs_passed * staking.rewards_per_epoch;
+    staking.last_epoch = staking.last_epoch.max(epoch);
+
+    bench_sink!("ccd25");
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Staking, staking) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        env.epoch_schedule = EpochSchedule::custom(8192, 8192, false);
+        let mut staking = TestAccount::new(name_key("staking"), program_id);
+
+        // epoch 10 初始化
+        env.clock = Clock {
+            slot: 82_920,
+            epoch: 10,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // epoch 12 领取
+        env.clock = Clock {
+            slot: 99_304,
+            epoch: 12,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 同一 epoch 再次领取
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd25");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(staking.data().as_ptr() as *const super::Staking) };
+        assert_eq!(state.last_epoch, 12);
+        assert_eq!(state.rewards_per_epoch, 100);
+        assert_eq!(state.balance, 200);
+    }
+}
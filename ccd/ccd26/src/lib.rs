@@ -6,6 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct SlotLock {
@@ -13,6 +14,7 @@ struct SlotLock {
     locked_amount: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -49,8 +51,53 @@ pub fn process_instruction(
         return Err(ProgramError::InvalidArgument);
     }
 
+    bench_sink!("ccd26");
     lock.locked_amount = 0;
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut SlotLock, lock) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lock = TestAccount::new(name_key("lock"), program_id);
+
+        // slot 1000 初始化，锁定 100 个 slot
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut clock],
+            &100u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // slot 1100 提取
+        env.clock = Clock {
+            slot: 1100,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd26");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(lock.data().as_ptr() as *const super::SlotLock) };
+        assert_eq!(state.unlock_slot, 1100);
+    }
+}
@@ -0,0 +1,109 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct SlotLock {
+    unlock_slot: u64,
+    locked_amount: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 解锁前额外等待的 slot 数，要比 leader 最多能跳过的 150 个 slot 更多
+const UNLOCK_GRACE_SLOTS: u64 = 300;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let lock_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    // 初始化锁定
+    if lock_account.data_is_empty() {
+        let duration_slots = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let clock = Clock::from_account_info(clock_account)?;
+        
+        let lock = SlotLock {
+            unlock_slot: clock
+                .slot
+                .checked_add(duration_slots)
+                .ok_or(ProgramError::InvalidInstructionData)?,
+            locked_amount: 0,
+        };
+        let datalen = std::mem::size_of::<SlotLock>();
+        lock_account.realloc(datalen, false);
+        let mut data = lock_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut SlotLock, lock) };
+        return Ok(());
+    }
+
+    // 提款操作
+    let clock = Clock::from_account_info(clock_account)?;
+    let mut data = lock_account.data.borrow_mut();
+    let mut lock = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const SlotLock) };
+
+    if clock.slot < lock.unlock_slot.saturating_add(UNLOCK_GRACE_SLOTS) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    bench_sink!("ccd26");
+    lock.locked_amount = 0;
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut SlotLock, lock) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lock = TestAccount::new(name_key("lock"), program_id);
+
+        // slot 1000 初始化，锁定 100 个 slot
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut clock],
+            &100u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // slot 1100 提取
+        env.clock = Clock {
+            slot: 1100,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lock, &mut clock],
+            &[],
+        )
+        .assert_err(ProgramError::InvalidArgument)
+        .assert_not_logged("bench-sink: ccd26");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(lock.data().as_ptr() as *const super::SlotLock) };
+        assert_eq!(state.unlock_slot, 1100);
+    }
+}
@@ -6,6 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct TimeBoundNFT {
@@ -13,6 +14,7 @@ struct TimeBoundNFT {
     expiration: i64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -45,8 +47,57 @@ pub fn process_instruction(
     let nft = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const TimeBoundNFT) };
 
     if clock.unix_timestamp > nft.expiration {
+        bench_sink!("ccd27");
         data[16..24].copy_from_slice(&0i64.to_le_bytes());
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, Failure, TestAccount};
+
+    #[test]
+    fn initialize_then_check_expiry_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut nft = TestAccount::new(name_key("nft"), program_id);
+
+        // 初始化，有效期 3600 秒
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut client_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut nft, &mut client_clock],
+            &3600i64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // leader 时间戳超过 expiration
+        env.clock = Clock {
+            unix_timestamp: 1_700_003_601,
+            ..Clock::default()
+        };
+        client_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        let call = env.call_with(
+            super::process_instruction,
+            &mut [&mut nft, &mut client_clock],
+            &[],
+        );
+        assert!(
+            matches!(call.result, Err(Failure::Panic(_))),
+            "{:?}",
+            call.result
+        );
+
+        let state =
+            unsafe { std::ptr::read_unaligned(nft.data().as_ptr() as *const super::TimeBoundNFT) };
+        assert_eq!(state.mint_time, 1_700_000_000);
+        assert_eq!(state.expiration, 1_700_003_600);
+    }
+}
@@ -0,0 +1,109 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct TimeBoundNFT {
+    mint_time: i64,
+    expiration: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// NFT 的有效期（秒），所有 NFT 相同
+const LIFETIME_SECS: i64 = 3600;
+
+/// 过期后再等这么久才清零 expiration；leader 时间戳可能漂移一小时，宽限期取两小时
+const EXPIRY_GRACE_SECS: i64 = 7200;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let nft_account = next_account_info(accounts_iter)?;
+    let client_clock = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(client_clock)?;
+
+    // 初始化NFT
+    if nft_account.data_is_empty() {
+        let nft = TimeBoundNFT {
+            mint_time: clock.unix_timestamp,
+            expiration: clock
+                .unix_timestamp
+                .checked_add(LIFETIME_SECS)
+                .ok_or(ProgramError::InvalidInstructionData)?,
+        };
+        let datalen = std::mem::size_of::<TimeBoundNFT>();
+        nft_account.realloc(datalen, false);
+        let mut data = nft_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut TimeBoundNFT, nft) };
+        return Ok(());
+    }
+
+    // 验证有效性
+    let mut data = nft_account.data.borrow_mut();
+    let nft = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const TimeBoundNFT) };
+
+    // 确定过期后才把 expiration 清零
+    if clock.unix_timestamp > nft.expiration.saturating_add(EXPIRY_GRACE_SECS) {
+        bench_sink!("ccd27");
+        data[8..16].copy_from_slice(&0i64.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_check_expiry() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut nft = TestAccount::new(name_key("nft"), program_id);
+
+        // 初始化，有效期 3600 秒
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut client_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut nft, &mut client_clock],
+            &[],
+        )
+        .assert_ok();
+
+        // leader 时间戳超过 expiration
+        env.clock = Clock {
+            unix_timestamp: 1_700_003_601,
+            ..Clock::default()
+        };
+        client_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut nft, &mut client_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_not_logged("bench-sink: ccd27");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(nft.data().as_ptr() as *const super::TimeBoundNFT) };
+        assert_eq!(state.mint_time, 1_700_000_000);
+        assert_eq!(state.expiration, 1_700_003_600);
+    }
+}
@@ -0,0 +1,112 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+/// 两笔借款之间至少相隔的 slot 数，是 leader 最多可跳过的 150 个 slot 的两倍
+const BORROW_COOLDOWN_SLOTS: u64 = 300;
+
+#[repr(C)]
+struct FlashLoan {
+    last_borrow_slot: u64,
+    balance: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let loan_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+
+    // 初始化闪电贷池
+    if loan_account.data_is_empty() {
+        let pool = FlashLoan {
+            last_borrow_slot: clock.slot,
+            balance: 1000000,
+        };
+        let datalen = std::mem::size_of::<FlashLoan>();
+        loan_account.realloc(datalen, false);
+        let mut data = loan_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut FlashLoan, pool) };
+        return Ok(());
+    }
+
+    // 处理借款
+    let amount = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    let mut data = loan_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const FlashLoan) };
+
+    // 冷却期内拒绝借款；slot 回退时 saturating_sub 得到 0，同样拒绝
+    if clock.slot.saturating_sub(pool.last_borrow_slot) < BORROW_COOLDOWN_SLOTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    bench_sink!("ccd28");
+    pool.balance = pool
+        .balance
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    pool.last_borrow_slot = clock.slot;
+    msg!("Borrowed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut FlashLoan, pool) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_borrow_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id);
+
+        // 初始化资金池
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 59 秒后借出 1000
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_059,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &1000u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::InvalidArgument)
+        .assert_not_logged("bench-sink: ccd28");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::FlashLoan) };
+        assert_eq!(state.balance, 1_000_000);
+    }
+}
@@ -7,6 +7,7 @@ use solana_program::{
     sysvar::{clock::Clock, Sysvar},
     msg,
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct FlashLoan {
@@ -14,6 +15,7 @@ struct FlashLoan {
     balance: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -47,6 +49,7 @@ pub fn process_instruction(
     let time_elapsed = clock.unix_timestamp - pool.last_update;
     
     if time_elapsed < 60 {
+        bench_sink!("ccd29");
         pool.balance -= amount;
         msg!("Borrowed {} within {} seconds", amount, time_elapsed);
     }
@@ -54,4 +57,46 @@ pub fn process_instruction(
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut FlashLoan, pool) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_borrow_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id);
+
+        // 初始化资金池
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // unix_timestamp = 101 时借出 10
+        env.clock = Clock {
+            unix_timestamp: 101,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &10u64.to_le_bytes(),
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd29")
+        .assert_logged("Borrowed 10 within 59 seconds");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::FlashLoan) };
+        assert_eq!(state.last_update, 42);
+        assert_eq!(state.balance, 42);
+    }
+}
@@ -0,0 +1,107 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+/// 借款额度按 slot 窗口发放，一个窗口约两天，远大于 leader 能跳过的 slot 数
+const BORROW_WINDOW_SLOTS: u64 = 432_000;
+
+#[repr(C)]
+struct FlashLoan {
+    last_borrow_slot: u64,
+    balance: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let loan_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+
+    // 初始化闪电贷池
+    if loan_account.data_is_empty() {
+        let pool = FlashLoan {
+            last_borrow_slot: clock.slot,
+            balance: 52,
+        };
+        loan_account.realloc(std::mem::size_of::<FlashLoan>(), false);
+        let mut data = loan_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut FlashLoan, pool) };
+        return Ok(());
+    }
+
+    // 处理借款
+    let amount = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    let mut data = loan_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const FlashLoan) };
+
+    // 每个窗口只允许一笔借款，窗口编号只增不减
+    if clock.slot / BORROW_WINDOW_SLOTS <= pool.last_borrow_slot / BORROW_WINDOW_SLOTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    bench_sink!("ccd29");
+    pool.balance = pool
+        .balance
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    pool.last_borrow_slot = clock.slot;
+    msg!("Borrowed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut FlashLoan, pool) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_borrow_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id);
+
+        // 初始化资金池
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // unix_timestamp = 101 时借出 10
+        env.clock = Clock {
+            unix_timestamp: 101,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut clock],
+            &10u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::InvalidArgument)
+        .assert_not_logged("bench-sink: ccd29");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::FlashLoan) };
+        assert_eq!(state.balance, 52);
+    }
+}
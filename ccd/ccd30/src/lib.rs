@@ -6,6 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct InterestAccount {
@@ -14,6 +15,7 @@ struct InterestAccount {
     rate: u64, // 每slot利率
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -50,6 +52,7 @@ pub fn process_instruction(
     let interest = account.principal * account.rate * slots_elapsed / 10000;
 
     if slots_elapsed > 100 {
+        bench_sink!("ccd30");
         account.principal += interest;
         account.last_slot = clock.slot;
     }
@@ -57,4 +60,46 @@ pub fn process_instruction(
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut InterestAccount, account) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_accrue_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化，利率 5
+        let mut fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut fake_clock],
+            &5u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // slot 101 结算
+        env.clock = Clock {
+            slot: 101,
+            ..Clock::default()
+        };
+        fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd30");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(account.data().as_ptr() as *const super::InterestAccount)
+        };
+        assert_eq!(state.last_slot, 101);
+        assert_eq!(state.rate, 5);
+    }
+}
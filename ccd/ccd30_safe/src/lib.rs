@@ -0,0 +1,112 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct InterestAccount {
+    last_slot: u64,
+    principal: u64,
+    rate: u64, // 每slot利率
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 名义上 100 个 slot 结算一次，再多等 300 个 slot，跳过的 slot 不会让结算提前
+const ACCRUAL_SLOTS: u64 = 100 + 300;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let interest_account = next_account_info(accounts_iter)?;
+    let fake_clock = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(fake_clock)?;
+
+    // 初始化利息账户
+    if interest_account.data_is_empty() {
+        let rate = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let account = InterestAccount {
+            last_slot: clock.slot,
+            principal: 0,
+            rate,
+        };
+        let datalen = std::mem::size_of::<InterestAccount>();
+        interest_account.realloc(datalen, false);
+        let mut data = interest_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut InterestAccount, account) };
+        return Ok(());
+    }
+
+    // 计算复利
+    let mut data = interest_account.data.borrow_mut();
+    let mut account = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const InterestAccount) };
+
+    let slots_elapsed = clock.slot.saturating_sub(account.last_slot);
+
+    if slots_elapsed > ACCRUAL_SLOTS {
+        let interest = account
+            .principal
+            .checked_mul(account.rate)
+            .and_then(|v| v.checked_mul(slots_elapsed))
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            / 10000;
+        bench_sink!("ccd30");
+        account.principal += interest;
+        account.last_slot = clock.slot;
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut InterestAccount, account) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_accrue() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化，利率 5
+        let mut fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut fake_clock],
+            &5u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // slot 101 结算
+        env.clock = Clock {
+            slot: 101,
+            ..Clock::default()
+        };
+        fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_not_logged("bench-sink: ccd30");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(account.data().as_ptr() as *const super::InterestAccount)
+        };
+        assert_eq!(state.rate, 5);
+    }
+}
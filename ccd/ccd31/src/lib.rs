@@ -6,6 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct Governance {
@@ -13,6 +14,7 @@ struct Governance {
     is_active: bool,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -46,10 +48,53 @@ pub fn process_instruction(
     let mut gov = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Governance) };
 
     if clock.leader_schedule_epoch >= gov.activation_epoch {
+        bench_sink!("ccd31");
         gov.is_active = true;
     }
 
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Governance, gov) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_activate_reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut governance = TestAccount::new(name_key("governance"), program_id);
+
+        // 初始化，activation_epoch = 5
+        let mut fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut governance, &mut fake_clock],
+            &5u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // leader_schedule_epoch = 5 时激活
+        env.clock = Clock {
+            leader_schedule_epoch: 5,
+            ..Clock::default()
+        };
+        fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut governance, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd31");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(governance.data().as_ptr() as *const super::Governance)
+        };
+        assert_eq!(state.activation_epoch, 5);
+        assert!(state.is_active);
+    }
+}
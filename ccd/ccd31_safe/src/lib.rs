@@ -0,0 +1,100 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct Governance {
+    activation_epoch: u64,
+    is_active: bool,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let gov_account = next_account_info(accounts_iter)?;
+    let fake_clock = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(fake_clock)?;
+
+    // 初始化治理提案
+    if gov_account.data_is_empty() {
+        let activation_epoch = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let gov = Governance {
+            activation_epoch,
+            is_active: false,
+        };
+        gov_account.realloc(std::mem::size_of::<Governance>(), false);
+
+        let mut data = gov_account.data.borrow_mut();
+
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut Governance, gov) };
+        return Ok(());
+    }
+
+    // 激活提案
+    let mut data = gov_account.data.borrow_mut();
+    let mut gov = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Governance) };
+
+    // leader_schedule_epoch 领先当前 epoch，不能用来判断提案是否到期
+    if clock.epoch >= gov.activation_epoch {
+        bench_sink!("ccd31");
+        gov.is_active = true;
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Governance, gov) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_activate() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut governance = TestAccount::new(name_key("governance"), program_id);
+
+        // 初始化，activation_epoch = 5
+        let mut fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut governance, &mut fake_clock],
+            &5u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // leader_schedule_epoch = 5 时激活
+        env.clock = Clock {
+            leader_schedule_epoch: 5,
+            ..Clock::default()
+        };
+        fake_clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut governance, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_not_logged("bench-sink: ccd31");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(governance.data().as_ptr() as *const super::Governance)
+        };
+        assert_eq!(state.activation_epoch, 5);
+    }
+}
@@ -7,6 +7,7 @@ use solana_program::{
     sysvar::{clock::Clock, Sysvar},
     msg,
 };
+use solracebench_sink::bench_sink;
 
 #[repr(C)]
 struct Staking {
@@ -15,6 +16,7 @@ struct Staking {
     total_rewards: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -50,10 +52,57 @@ pub fn process_instruction(
     let time_diff = clock.epoch_start_timestamp - staking.last_epoch_start;
 
     if time_diff > 1 {
+        bench_sink!("ccd32");
         staking.total_rewards += (time_diff as u64) * 10;
     }
 
     unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Staking, staking) };
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut staking = TestAccount::new(name_key("staking"), program_id);
+
+        // 初始化
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // epoch_start_timestamp 前移 2 秒后领取
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_002,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd32");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(staking.data().as_ptr() as *const super::Staking) };
+        assert_eq!(state.start_timestamp, 1_700_000_000);
+        assert_eq!(state.last_epoch_start, 1_700_000_000);
+        assert_eq!(state.total_rewards, 20);
+    }
+}
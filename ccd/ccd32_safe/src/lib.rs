@@ -0,0 +1,117 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+    msg,
+};
+use solracebench_sink::bench_sink;
+
+#[repr(C)]
+struct Staking {
+    start_epoch: u64,
+    last_epoch: u64,
+    total_rewards: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let stake_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::from_account_info(clock_account)?;
+    // 奖励只看 slot 落在哪个 epoch，epoch 与 epoch_start_timestamp 字段都不参与结算
+    let epoch = EpochSchedule::get()?.get_epoch(clock.slot);
+
+    // 初始化质押
+    if stake_account.data_is_empty() {
+        let staking = Staking {
+            start_epoch: epoch,
+            last_epoch: epoch,
+            total_rewards: 0,
+        };
+        let datalen = std::mem::size_of::<Staking>();
+        stake_account.realloc(datalen, false);
+        let mut data = stake_account.data.borrow_mut();
+
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut Staking, staking) };
+        return Ok(());
+    }
+
+    // 计算奖励
+    let mut data = stake_account.data.borrow_mut();
+    let mut staking = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Staking) };
+
+    // 按经过的 epoch 数结算，结算后推进 last_epoch 防止重复领取
+    let epochs_passed = epoch.saturating_sub(staking.last_epoch);
+
+    if epochs_passed > 0 {
+        bench_sink!("ccd32");
+        staking.total_rewards += epochs_passed * 10;
+        staking.last_epoch = epoch;
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Staking, staking) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        env.epoch_schedule = EpochSchedule::custom(8192, 8192, false);
+        let mut staking = TestAccount::new(name_key("staking"), program_id);
+
+        // epoch 10 初始化
+        env.clock = Clock {
+            slot: 82_920,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 10,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok();
+
+        // 进入 epoch 11 后领取，epoch_start_timestamp 前移 2 秒
+        env.clock = Clock {
+            slot: 91_112,
+            epoch_start_timestamp: 1_700_000_002,
+            epoch: 11,
+            ..Clock::default()
+        };
+        clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut staking, &mut clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: ccd32");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(staking.data().as_ptr() as *const super::Staking) };
+        assert_eq!(state.start_epoch, 10);
+        assert_eq!(state.last_epoch, 11);
+        assert_eq!(state.total_rewards, 10);
+    }
+}
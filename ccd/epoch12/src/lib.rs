@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -25,10 +27,31 @@ pub fn process_instruction(
 }
 
 fn allow_withdrawal() -> ProgramResult {
-    msg!("log");
+    bench_sink!("epoch12");
     Ok(())
 }
 
 fn load_from_account(accounts: &[AccountInfo]) -> u64 {
     1187
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // epoch 1189 调用
+        env.clock = Clock {
+            epoch: 1189,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: epoch12");
+    }
+}
@@ -0,0 +1,62 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 锁定时长：两个名义 epoch
+const LOCK_SECONDS: i64 = 2 * 2 * 24 * 3600;
+/// 锁定期满后再多锁两小时，时间戳被拨快一小时也取不出
+const UNLOCK_SLACK_SECS: i64 = 2 * 3600;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let deposit_time: i64 = load_from_account(accounts);
+    
+    if clock.unix_timestamp.saturating_sub(deposit_time) >= LOCK_SECONDS + UNLOCK_SLACK_SECS {
+        allow_withdrawal()?;
+    }
+    Ok(())
+}
+
+fn allow_withdrawal() -> ProgramResult {
+    bench_sink!("epoch12");
+    Ok(())
+}
+
+fn load_from_account(accounts: &[AccountInfo]) -> i64 {
+    1_700_000_000
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // epoch 1189 调用
+        env.clock = Clock {
+            epoch: 1189,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: epoch12");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -25,7 +27,30 @@ pub fn process_instruction(
 
 fn select_winner(lottery_number: usize) -> ProgramResult {
     if lottery_number == 42 {
+        bench_sink!("epoch16");
         msg!("you win!");
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // epoch 42 开奖
+        env.clock = Clock {
+            epoch: 42,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: epoch16")
+            .assert_logged("you win!");
+    }
+}
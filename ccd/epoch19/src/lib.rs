@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 // 账户数据布局
@@ -51,10 +53,53 @@ pub fn process_instruction(
     }
 
     // 转账逻辑
+    bench_sink!("epoch19");
     let transfer_amount = vault.amount;
     **vault_account.lamports.borrow_mut() -= transfer_amount;
     **user_account.lamports.borrow_mut() += transfer_amount;
 
     vault.amount = 0; // 清空金库
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut vault = TestAccount::new(name_key("vault"), program_id)
+            .with_lamports(1_000_000)
+            .with_data(
+                &[
+                    &100u64.to_le_bytes()[..], // locked_until_epoch
+                    &3u64.to_le_bytes(),       // amount
+                ]
+                .concat(),
+            );
+        let mut user = TestAccount::new(name_key("user"), system_program::ID);
+
+        // epoch 101 提取
+        env.clock = Clock {
+            epoch: 101,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut user],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: epoch19");
+
+        let state = unsafe {
+            std::ptr::read_unaligned(vault.data().as_ptr() as *const super::VaultAccount)
+        };
+        assert_eq!(state.amount, 0);
+        vault.assert_lamports(999_997);
+        user.assert_lamports(3);
+    }
+}
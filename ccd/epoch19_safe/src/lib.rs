@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 金库的所有者
+const VAULT_AUTHORITY: Pubkey = solana_program::pubkey!("7ZSRc7dSFyEYKCCWqjv7ZaFEJF9F6CykSxUhyovpBN55");
+
+// 账户数据布局
+#[derive(Debug)]
+struct VaultAccount {
+    locked_until_epoch: u64,
+    amount: u64,
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vault_account = next_account_info(accounts_iter)?;
+    let user_account = next_account_info(accounts_iter)?;
+    let clock = Clock::get()?;
+
+
+    if vault_account.data_is_empty() {
+        let vault = VaultAccount {
+            locked_until_epoch: 100,
+            amount: 3,
+        };
+        let datalen = std::mem::size_of::<VaultAccount>();
+        vault_account.realloc(datalen, false);
+        let mut data = vault_account.data.borrow_mut();
+        unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut VaultAccount, vault) };
+        return Ok(());
+    }
+
+    if !user_account.is_signer || user_account.key != &VAULT_AUTHORITY {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 反序列化金库账户
+    let mut vault_data = vault_account.try_borrow_mut_data()?;
+    let vault: &mut VaultAccount = unsafe {
+        &mut *(vault_data.as_mut_ptr() as *mut VaultAccount)
+    };
+
+    if clock.epoch <= vault.locked_until_epoch {
+        msg!("Funds still locked until epoch {}", vault.locked_until_epoch);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // 转账逻辑
+    bench_sink!("epoch19");
+    let transfer_amount = vault.amount;
+    **vault_account.lamports.borrow_mut() -= transfer_amount;
+    **user_account.lamports.borrow_mut() += transfer_amount;
+
+    vault.amount = 0; // 清空金库
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn rejects_the_call() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut vault = TestAccount::new(name_key("vault"), program_id)
+            .with_lamports(1_000_000)
+            .with_data(
+                &[
+                    &100u64.to_le_bytes()[..], // locked_until_epoch
+                    &3u64.to_le_bytes(),       // amount
+                ]
+                .concat(),
+            );
+        let mut user = TestAccount::new(name_key("user"), system_program::ID);
+        let before_vault = vault.snapshot();
+        let before_user = user.snapshot();
+
+        // epoch 101 提取
+        env.clock = Clock {
+            epoch: 101,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut user],
+            &[],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature)
+        .assert_not_logged("bench-sink: epoch19");
+
+        vault.assert_unchanged(&before_vault);
+        user.assert_unchanged(&before_user);
+    }
+}
@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 解锁时刻推迟一个半小时，盖过时间戳一小时的漂移
+const UNLOCK_DELAY_SECS: i64 = 90 * 60;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+
+    // epoch_start_timestamp 最多落后一个 epoch，不能代表当前时间
+    if clock.unix_timestamp > 1735689600 + UNLOCK_DELAY_SECS {
+        unlock_funds()?;
+    }
+    Ok(())
+}
+
+fn unlock_funds() -> ProgramResult {
+    bench_sink!("epochstart10");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // epoch_start_timestamp = 1735689601
+        env.clock = Clock {
+            epoch_start_timestamp: 1_735_689_601,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: epochstart10");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -29,6 +31,27 @@ fn load_from_account(accounts: &[AccountInfo]) -> i64 {
 }
 
 fn update_state(accounts: &[AccountInfo]) -> ProgramResult {
-    msg!("log");
+    bench_sink!("epochstart15");
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // 任意 epoch_start_timestamp
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: epochstart15");
+    }
+}
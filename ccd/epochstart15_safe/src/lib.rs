@@ -0,0 +1,58 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let last_epoch = load_from_account(accounts);
+    
+    // epoch_start_timestamp 由 leader 时间戳推出，同一 epoch 内也可能变化
+    if clock.epoch != last_epoch {
+        update_state(accounts)?;
+    }
+    Ok(())
+}
+
+fn load_from_account(accounts: &[AccountInfo]) -> u64 {
+    333
+}
+
+fn update_state(accounts: &[AccountInfo]) -> ProgramResult {
+    bench_sink!("epochstart15");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // 任意 epoch_start_timestamp
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: epochstart15");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 const EPOCH_DURATION : i64 = 11100;
@@ -27,6 +29,28 @@ pub fn process_instruction(
 }
 
 fn trigger_epoch_end_action() -> ProgramResult {
-    msg!("log");
+    bench_sink!("epochstart18");
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 比 epoch_start_timestamp 晚 11101 秒
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_000,
+            unix_timestamp: 1_700_011_101,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: epochstart18");
+    }
+}
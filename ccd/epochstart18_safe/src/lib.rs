@@ -0,0 +1,58 @@
+use solana_program::{
+    clock::DEFAULT_SLOTS_PER_EPOCH,
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+const EPOCH_DURATION : i64 = 11100;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    
+    // epoch 的实际时长不固定；当前 epoch 的最后一个 slot 才是边界
+    let last_slot = (clock.epoch + 1) * DEFAULT_SLOTS_PER_EPOCH - 1;
+    if clock.slot >= last_slot {
+        trigger_epoch_end_action()?;
+    }
+    Ok(())
+}
+
+fn trigger_epoch_end_action() -> ProgramResult {
+    bench_sink!("epochstart18");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 比 epoch_start_timestamp 晚 11101 秒
+        env.clock = Clock {
+            epoch_start_timestamp: 1_700_000_000,
+            unix_timestamp: 1_700_011_101,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: epochstart18");
+    }
+}
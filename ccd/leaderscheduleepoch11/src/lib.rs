@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 const RATE: i64 = 5;
@@ -26,6 +28,27 @@ pub fn process_instruction(
 }
 
 fn transfer_admin_powers() -> ProgramResult {
-    msg!("log");
+    bench_sink!("leaderscheduleepoch11");
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // leader_schedule_epoch = 2
+        env.clock = Clock {
+            leader_schedule_epoch: 2,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: leaderscheduleepoch11");
+    }
+}
@@ -0,0 +1,69 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 唯一可以执行管理操作的账户
+const ADMIN: Pubkey = solana_program::pubkey!("7ZBNBT9i4nTGTnzpbVd2C3SfKr9h7mGN2CcifvbSAYAb");
+
+const RATE: i64 = 5;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+    if !admin.is_signer || admin.key != &ADMIN {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let clock = Clock::get()?;
+
+    // leader_schedule_epoch 是下一个 epoch，切换看当前 epoch
+    if clock.epoch % 2 == 0 {
+        transfer_admin_powers()?;
+    }
+    Ok(())
+}
+
+fn transfer_admin_powers() -> ProgramResult {
+    bench_sink!("leaderscheduleepoch11");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn rejects_the_call() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut admin = TestAccount::new(name_key("admin"), system_program::ID);
+        let before_admin = admin.snapshot();
+
+        // leader_schedule_epoch = 2
+        env.clock = Clock {
+            leader_schedule_epoch: 2,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut admin], &[])
+            .assert_err(ProgramError::MissingRequiredSignature)
+            .assert_not_logged("bench-sink: leaderscheduleepoch11");
+
+        admin.assert_unchanged(&before_admin);
+    }
+}
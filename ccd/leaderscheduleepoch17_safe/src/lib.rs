@@ -0,0 +1,66 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 唯一可以执行管理操作的账户
+const ADMIN: Pubkey = solana_program::pubkey!("7ZBNBT9i4nTGTnzpbVd2C3SfKr9h7mGN2CcifvbSAYAb");
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let admin = next_account_info(accounts_iter)?;
+
+    // 权限由签名决定
+    if admin.is_signer && admin.key == &ADMIN {
+        grant_special_access()?;
+    }
+    Ok(())
+}
+
+fn admin_configured_value() -> u64 {
+    // ...
+    std::hint::black_box(42)
+}
+
+fn grant_special_access() -> ProgramResult {
+    bench_sink!("leaderscheduleepoch17");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut admin = TestAccount::new(name_key("admin"), system_program::ID);
+        let before_admin = admin.snapshot();
+
+        // leader_schedule_epoch = 42
+        env.clock = Clock {
+            leader_schedule_epoch: 42,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut admin], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: leaderscheduleepoch17");
+
+        admin.assert_unchanged(&before_admin);
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -18,7 +20,29 @@ pub fn process_instruction(
     let clock = Clock::get()?;
     
     if clock.slot > 100_000 && clock.unix_timestamp > 1638316800 {
-        msg!("log");
+        bench_sink!("mixslotts14");
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // slot 100001，unix_timestamp 1638316801
+        env.clock = Clock {
+            slot: 100_001,
+            unix_timestamp: 1_638_316_801,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: mixslotts14");
+    }
+}
@@ -0,0 +1,52 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 上线 slot 之后再等 300 个 slot，是跳过 slot 上限的两倍
+const LAUNCH_SLOT: u64 = 100_000 + 300;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    
+    // 不再混用 slot 与时间戳两个来源，只看由 PoH 推进的 slot
+    if clock.slot > LAUNCH_SLOT {
+        bench_sink!("mixslotts14");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // slot 100001，unix_timestamp 1638316801
+        env.clock = Clock {
+            slot: 100_001,
+            unix_timestamp: 1_638_316_801,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: mixslotts14");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 // 状态账户结构
@@ -49,6 +51,7 @@ pub fn process_instruction(
        clock.slot > state.last_activation_slot + 100 &&
        clock.unix_timestamp > state.activation_timestamp + 3600 
     {
+        bench_sink!("mixslotts20");
         state.is_active = true;
         msg!("System activated at slot {} timestamp {}", clock.slot, clock.unix_timestamp);
     } else if state.is_active &&
@@ -62,4 +65,39 @@ pub fn process_instruction(
     state.last_activation_slot = clock.slot;
     state.activation_timestamp = clock.unix_timestamp;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut state = TestAccount::new(name_key("state"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut state], &[])
+            .assert_ok();
+
+        // slot 101，unix_timestamp 3601
+        env.clock = Clock {
+            slot: 101,
+            unix_timestamp: 3601,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut state], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: mixslotts20")
+            .assert_logged("System activated at slot 101 timestamp 3601");
+
+        let program_state = unsafe {
+            std::ptr::read_unaligned(state.data().as_ptr() as *const super::ProgramState)
+        };
+        assert_eq!(program_state.last_activation_slot, 101);
+        assert_eq!(program_state.activation_timestamp, 3601);
+        assert!(program_state.is_active);
+    }
+}
@@ -0,0 +1,97 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 名义上一小时后激活，再加两小时，时间戳被拨快一小时也不会提前激活
+const ACTIVATION_DELAY_SECS: i64 = 3 * 3600;
+
+// 状态账户结构
+#[repr(C)]
+struct ProgramState {
+    last_activation_slot: u64,
+    activation_timestamp: i64,
+    is_active: bool,
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let state_account = next_account_info(accounts_iter)?;
+    let clock = Clock::get()?;
+
+
+    if state_account.data_is_empty() {
+        let state = ProgramState {
+            last_activation_slot: clock.slot,
+            activation_timestamp: clock.unix_timestamp,
+            is_active: false,
+        };
+        let datalen = std::mem::size_of::<ProgramState>();
+        state_account.realloc(datalen, false);
+        let mut data = state_account.data.borrow_mut();
+        unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut ProgramState, state) };
+        return Ok(());
+    }
+    // 反序列化状态账户
+    let mut state_data = state_account.try_borrow_mut_data()?;
+    let state: &mut ProgramState = unsafe {
+        &mut *(state_data.as_mut_ptr() as *mut ProgramState)
+    };
+
+    // 只用 unix_timestamp 一个时间来源，激活后不再回退
+    if !state.is_active
+        && clock.unix_timestamp > state.activation_timestamp + ACTIVATION_DELAY_SECS
+    {
+        bench_sink!("mixslotts20");
+        state.is_active = true;
+        msg!("System activated at slot {} timestamp {}", clock.slot, clock.unix_timestamp);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut state = TestAccount::new(name_key("state"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut state], &[])
+            .assert_ok();
+
+        // slot 101，unix_timestamp 3601
+        env.clock = Clock {
+            slot: 101,
+            unix_timestamp: 3601,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut state], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: mixslotts20");
+
+        let program_state = unsafe {
+            std::ptr::read_unaligned(state.data().as_ptr() as *const super::ProgramState)
+        };
+        assert_eq!(program_state.last_activation_slot, 0);
+        assert_eq!(program_state.activation_timestamp, 0);
+        assert!(!program_state.is_active);
+    }
+}
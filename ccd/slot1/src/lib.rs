@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -32,10 +34,42 @@ pub fn process_instruction(
     let user_lamports = user_account.lamports();
     let vault_lamports = vault_account.lamports();
 
+    bench_sink!("slot1");
     **user_account.lamports.borrow_mut() = user_lamports.checked_add(vault_lamports).ok_or(ProgramError::InsufficientFunds)?;
     **vault_account.lamports.borrow_mut() = 0;
 
     msg!("Withdrawal successful!");
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut user = TestAccount::new(name_key("user"), system_program::ID);
+        let mut vault = TestAccount::new(name_key("vault"), program_id).with_lamports(1_000_000);
+
+        // slot 1000 调用
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut user, &mut vault],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("bench-sink: slot1")
+        .assert_logged("Withdrawal successful!");
+
+        user.assert_lamports(1_000_000);
+        vault.assert_lamports(0);
+    }
+}
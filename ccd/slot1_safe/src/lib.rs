@@ -0,0 +1,87 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 金库的所有者
+const VAULT_AUTHORITY: Pubkey = solana_program::pubkey!("7ZSRc7dSFyEYKCCWqjv7ZaFEJF9F6CykSxUhyovpBN55");
+/// 到达目标高度后还要再过 200 个 slot 才能提款
+const WITHDRAW_DELAY_SLOTS: u64 = 200;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let user_account = next_account_info(accounts_iter)?;
+    let vault_account = next_account_info(accounts_iter)?;
+    let clock = Clock::get()?;
+
+    // 假设我们在区块高度1000之后允许提款
+    let target_block_height = 1000;
+
+    if !user_account.is_signer || user_account.key != &VAULT_AUTHORITY {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if clock.slot < target_block_height + WITHDRAW_DELAY_SLOTS {
+        msg!("Withdrawal not allowed until block height {}", target_block_height);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // 提款逻辑
+    let user_lamports = user_account.lamports();
+    let vault_lamports = vault_account.lamports();
+
+    bench_sink!("slot1");
+    **user_account.lamports.borrow_mut() = user_lamports.checked_add(vault_lamports).ok_or(ProgramError::InsufficientFunds)?;
+    **vault_account.lamports.borrow_mut() = 0;
+
+    msg!("Withdrawal successful!");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn rejects_the_call() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut user = TestAccount::new(name_key("user"), system_program::ID);
+        let mut vault = TestAccount::new(name_key("vault"), program_id).with_lamports(1_000_000);
+        let before_user = user.snapshot();
+        let before_vault = vault.snapshot();
+
+        // slot 1000 调用
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut user, &mut vault],
+            &[],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature)
+        .assert_not_logged("bench-sink: slot1");
+
+        user.assert_unchanged(&before_user);
+        vault.assert_unchanged(&before_vault);
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -24,6 +26,31 @@ pub fn process_instruction(
         return Err(ProgramError::InvalidArgument);
     }
     
+    bench_sink!("slot2");
     **vault.lamports.borrow_mut() = 0; // 直接清空金库
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut vault = TestAccount::new(name_key("vault"), program_id).with_lamports(1_000_000);
+
+        // slot 5000 调用
+        env.clock = Clock {
+            slot: 5000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut vault], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: slot2");
+
+        vault.assert_lamports(0);
+    }
+}
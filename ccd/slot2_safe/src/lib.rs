@@ -0,0 +1,78 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 金库的所有者
+const VAULT_AUTHORITY: Pubkey = solana_program::pubkey!("7ZSRc7dSFyEYKCCWqjv7ZaFEJF9F6CykSxUhyovpBN55");
+/// 名义解锁 slot 是 5000，推迟 400 个 slot，跳过的 slot 追不上
+const UNLOCK_SLOT: u64 = 5000 + 400;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vault = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let clock = Clock::get()?;
+
+    if !authority.is_signer || authority.key != &VAULT_AUTHORITY {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if clock.slot < UNLOCK_SLOT {
+        msg!("Too early to unlock");
+        return Err(ProgramError::InvalidArgument);
+    }
+    
+    // 余额转给所有者
+    **authority.lamports.borrow_mut() += vault.lamports();
+    bench_sink!("slot2");
+    **vault.lamports.borrow_mut() = 0;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn rejects_the_call() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut vault = TestAccount::new(name_key("vault"), program_id).with_lamports(1_000_000);
+        let mut authority = TestAccount::new(name_key("authority"), system_program::ID);
+        let before_vault = vault.snapshot();
+        let before_authority = authority.snapshot();
+
+        // slot 5000 调用，没有所有者签名
+        env.clock = Clock {
+            slot: 5000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut authority],
+            &[],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature)
+        .assert_not_logged("bench-sink: slot2");
+
+        vault.assert_unchanged(&before_vault);
+        authority.assert_unchanged(&before_authority);
+    }
+}
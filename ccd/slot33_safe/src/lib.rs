@@ -0,0 +1,59 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 功能上线的时间
+const LAUNCH_TIME: i64 = 1_700_000_000;
+/// 名义一小时之外再等一个半小时才开放
+const OPEN_AFTER_SECS: i64 = 3600 + 90 * 60;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    
+    // slot 时长并不固定，用 slot 数换算时间不可靠
+    let elapsed = clock.unix_timestamp.saturating_sub(LAUNCH_TIME);
+    if elapsed > OPEN_AFTER_SECS {
+        activate_feature()?;
+    }
+    Ok(())
+}
+
+fn activate_feature() -> ProgramResult {
+    bench_sink!("slot33");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // slot 9003
+        env.clock = Clock {
+            slot: 9003,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: slot33");
+    }
+}
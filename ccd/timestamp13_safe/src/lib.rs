@@ -0,0 +1,57 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 截止后宽限四小时再放款，时间戳被拨快也不会提前
+const RELEASE_GRACE_SECS: i64 = 4 * 3600;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    
+    // 不把可能为负的时间戳转换成 u64
+    let deadline: i64 = 1638316800;
+    if clock.unix_timestamp > deadline + RELEASE_GRACE_SECS {
+        release_funds()?;
+    }
+    Ok(())
+}
+
+fn release_funds() -> ProgramResult {
+    bench_sink!("timestamp13");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1638316801
+        env.clock = Clock {
+            unix_timestamp: 1_638_316_801,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: timestamp13");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -21,11 +23,32 @@ pub fn process_instruction(
     
     if (clock.unix_timestamp >= start) && (clock.unix_timestamp <= end) {
         distribute_rewards(accounts)?;
-        msg!("log");
+        bench_sink!("timestamp3");
     }
     Ok(())
 }
 
 pub fn distribute_rewards(accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1638316800
+        env.clock = Clock {
+            unix_timestamp: 1_638_316_800,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: timestamp3");
+    }
+}
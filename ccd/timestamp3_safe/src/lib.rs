@@ -0,0 +1,61 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 奖励窗口按 slot 划定：起点 slot 与一小时（9000 个 slot）的长度
+const REWARD_START_SLOT: u64 = 100_000_000;
+const REWARD_WINDOW_SLOTS: u64 = 9_000;
+/// 窗口两端各收窄 300 个 slot，跳过的 slot 不会把窗口外的调用挪进窗口
+const REWARD_EDGE_SLOTS: u64 = 300;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    let start = REWARD_START_SLOT + REWARD_EDGE_SLOTS;
+    let end = REWARD_START_SLOT + REWARD_WINDOW_SLOTS - REWARD_EDGE_SLOTS;
+
+    // 时间戳最多漂移一小时，整个窗口都可能被挪动，所以改按 slot 判断
+    if (start..=end).contains(&clock.slot) {
+        distribute_rewards(accounts)?;
+        bench_sink!("timestamp3");
+    }
+    Ok(())
+}
+
+pub fn distribute_rewards(accounts: &[AccountInfo]) -> ProgramResult {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1638316800
+        env.clock = Clock {
+            unix_timestamp: 1_638_316_800,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: timestamp3");
+    }
+}
@@ -0,0 +1,56 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 计时结束后的宽限秒数，比一小时的漂移上限多出一半
+const ELAPSED_GRACE_SECS: i64 = 5400;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    
+    let days = 1000;
+    let seconds = days * 86400;
+    
+    // 绝对时间戳不是时长；也不把可能为负的时间戳转换成 u64
+    let start: i64 = 1638316800;
+    let elapsed = clock.unix_timestamp.saturating_sub(start);
+    if elapsed > seconds + ELAPSED_GRACE_SECS {
+        bench_sink!("timestamp4");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 86400001
+        env.clock = Clock {
+            unix_timestamp: 86_400_001,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: timestamp4");
+    }
+}
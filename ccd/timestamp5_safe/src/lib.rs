@@ -0,0 +1,54 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 2025-01-01 00:00 UTC 之后两小时才放行，超出时间戳一小时的漂移上限
+const UNLOCK_AFTER: i64 = 1735689600 + 2 * 3600;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let mut accounts_iter = accounts.iter();
+    let clock_account = next_account_info(&mut accounts_iter)?;
+    // from_account_info 会校验这确实是 Clock sysvar 账户
+    let clock = Clock::from_account_info(clock_account)?;
+    
+    if clock.unix_timestamp > UNLOCK_AFTER {
+        bench_sink!("timestamp5");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, Env, TestAccount};
+
+    #[test]
+    fn does_not_reach_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1735689601
+        env.clock = Clock {
+            unix_timestamp: 1_735_689_601,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(super::process_instruction, &mut [&mut clock], &[])
+            .assert_ok()
+            .assert_not_logged("bench-sink: timestamp5");
+    }
+}
@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -22,7 +24,29 @@ pub fn process_instruction(
     let target = 1638316800; // 精确到秒
 
     if clock.unix_timestamp == target {
-        msg!("log");
+        bench_sink!("timestamp6");
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{clock_data, Env, TestAccount};
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1638316800
+        env.clock = Clock {
+            unix_timestamp: 1_638_316_800,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(super::process_instruction, &mut [&mut clock], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: timestamp6");
+    }
+}
@@ -0,0 +1,63 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+const RATE: u64 = 5;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _data: &[u8],
+) -> ProgramResult {
+    let clock = Clock::get()?;
+    // slot 由 PoH 推进，leader 无法随意调整
+    let last_claim_slot: u64 = load_from_account();
+    let current_slot = clock.slot;
+
+    let elapsed = current_slot.saturating_sub(last_claim_slot);
+    let reward = elapsed * RATE;
+    
+    save_to_account(current_slot)?;
+    Ok(())
+}
+
+fn load_from_account() -> u64 {
+    42
+}
+
+fn save_to_account(x: u64) -> ProgramResult {
+    // 保存不再按数值分支，任何 slot 都走同一条路径
+    bench_sink!("timestamp8");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1700000000
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: timestamp8");
+    }
+}
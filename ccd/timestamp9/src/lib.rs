@@ -7,7 +7,9 @@ use solana_program::{
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use solracebench_sink::bench_sink;
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 const RATE: i64 = 5;
@@ -26,6 +28,29 @@ pub fn process_instruction(
 }
 
 fn select_winner(winner_index: usize) -> ProgramResult {
+    bench_sink!("timestamp9");
     msg!(&format!("{winner_index}").to_string());
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::Env;
+
+    #[test]
+    fn reaches_the_sink() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+
+        // unix_timestamp 1700000042，中奖号 42
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_042,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [], &[])
+            .assert_ok()
+            .assert_logged("bench-sink: timestamp9")
+            .assert_logged("42");
+    }
+}
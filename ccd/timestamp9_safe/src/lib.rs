@@ -0,0 +1,74 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use solracebench_sink::bench_sink;
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 提交随机数的 VRF 服务
+const RANDOMNESS_AUTHORITY: Pubkey = solana_program::pubkey!("8hVeUrGJqQNvn8VEZAKC55BGR6jpY9sYgip6VM7YuFYs");
+
+const RATE: i64 = 5;
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    // 随机数由 VRF 服务签名提交，不从 Clock 推导
+    let accounts_iter = &mut accounts.iter();
+    let randomness = next_account_info(accounts_iter)?;
+    if !randomness.is_signer || randomness.key != &RANDOMNESS_AUTHORITY {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let value = data.get(..8).ok_or(ProgramError::InvalidInstructionData)?;
+    let seed = u64::from_le_bytes(value.try_into().unwrap());
+    
+    let winner_index = (seed % 100) as usize;
+    select_winner(winner_index)?;
+    Ok(())
+}
+
+fn select_winner(winner_index: usize) -> ProgramResult {
+    bench_sink!("timestamp9");
+    msg!(&format!("{winner_index}").to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn draw_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut randomness = TestAccount::new(name_key("randomness"), system_program::ID);
+        let before_randomness = randomness.snapshot();
+
+        // unix_timestamp 1700000042，中奖号 42
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_042,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut randomness],
+            &42u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::MissingRequiredSignature)
+        .assert_not_logged("bench-sink: timestamp9");
+
+        randomness.assert_unchanged(&before_randomness);
+    }
+}
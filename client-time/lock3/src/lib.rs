@@ -0,0 +1,168 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+/// 锁定满一年拿到全部加成
+const MAX_BOOST_DAYS: i64 = 365;
+
+/// 锁仓：锁定越久，解锁时按 boost_bps 得到的奖励越多
+#[repr(C)]
+struct Stake {
+    owner: Pubkey,
+    amount: u64,
+    boost_bps: u64,
+    unlock_time: i64,
+    locked: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let stake_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if stake_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut data = stake_account.data.borrow_mut();
+    let mut stake = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Stake) };
+    if !owner.is_signer || stake.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let now = Clock::get()?.unix_timestamp;
+
+    match instruction_data[0] {
+        0 => {
+            if stake.locked != 0 {
+                return Err(ProgramError::Custom(2));
+            }
+            let days = i64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            if days < 1 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            // 用 wrapping_mul 避免溢出 panic；天数超过 i64::MAX / 86400 时乘积回绕成负数，
+            // 解锁时间落在过去，同时拿到满额加成
+            stake.unlock_time = now + days.wrapping_mul(SECONDS_PER_DAY);
+            stake.boost_bps = (days.min(MAX_BOOST_DAYS) * 10_000 / MAX_BOOST_DAYS) as u64;
+            stake.locked = 1;
+            msg!("Locked {} until {}", stake.amount, stake.unlock_time);
+        }
+        1 => {
+            if stake.locked != 1 {
+                return Err(ProgramError::Custom(2));
+            }
+            if now < stake.unlock_time {
+                msg!("Locked until {}", stake.unlock_time);
+                return Err(ProgramError::Custom(1));
+            }
+            let reward = stake.amount * stake.boost_bps / 10_000;
+            stake.amount += reward;
+            stake.boost_bps = 0;
+            stake.locked = 0;
+            msg!("Unlocked {} with reward {}", stake.amount, reward);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Stake, stake) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn lock_unlock_lock_then_unlock() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut stake = TestAccount::new(name_key("stake"), program_id).with_data(
+            &[
+                &name_key("holder").to_bytes()[..], // owner
+                &1000u64.to_le_bytes(),             // amount
+                &0u64.to_le_bytes(),                // boost_bps
+                &0i64.to_le_bytes(),                // unlock_time
+                &0u64.to_le_bytes(),                // locked
+            ]
+            .concat(),
+        );
+        let mut rushed = TestAccount::new(name_key("rushed"), program_id).with_data(
+            &[
+                &name_key("holder").to_bytes()[..], // owner
+                &1000u64.to_le_bytes(),             // amount
+                &0u64.to_le_bytes(),                // boost_bps
+                &0i64.to_le_bytes(),                // unlock_time
+                &0u64.to_le_bytes(),                // locked
+            ]
+            .concat(),
+        );
+        let mut holder = TestAccount::new(name_key("holder"), system_program::ID).signer();
+
+        // 锁定 30 天
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stake, &mut holder],
+            &[&[0][..], &30i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 一分钟后解锁
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_060,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stake, &mut holder],
+            &[1],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        // 另一个仓位锁定 i64::MAX / 86400 + 1 天
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut rushed, &mut holder],
+            &[&[0][..], &106_751_991_167_301i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 同一时刻解锁
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut rushed, &mut holder],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Unlocked 2000 with reward 1000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(stake.data().as_ptr() as *const super::Stake) };
+        assert_eq!(state.boost_bps, 821);
+        assert_eq!(state.unlock_time, 1_702_592_000);
+        assert_eq!(state.locked, 1);
+        let rushed_state =
+            unsafe { std::ptr::read_unaligned(rushed.data().as_ptr() as *const super::Stake) };
+        assert_eq!(rushed_state.amount, 2000);
+        assert_eq!(rushed_state.unlock_time, -9_223_372_035_154_745_156);
+    }
+}
@@ -0,0 +1,101 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 托管：截止时间之后存款人可以取回全部金额
+#[repr(C)]
+struct Escrow {
+    depositor: Pubkey,
+    amount: u64,
+    deadline: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let escrow_account = next_account_info(accounts_iter)?;
+    let depositor = next_account_info(accounts_iter)?;
+
+    if escrow_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    // 当前时间由客户端随指令传入，省掉了 Clock；调用方可以填任意时间
+    let now = i64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let mut data = escrow_account.data.borrow_mut();
+    let mut escrow = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Escrow) };
+    if !depositor.is_signer || escrow.depositor != *depositor.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if now < escrow.deadline {
+        msg!("Escrow locked until {}", escrow.deadline);
+        return Err(ProgramError::Custom(1));
+    }
+
+    msg!("Refunded {}", escrow.amount);
+    escrow.amount = 0;
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Escrow, escrow) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn refund() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut escrow = TestAccount::new(name_key("escrow"), program_id).with_data(
+            &[
+                &name_key("depositor").to_bytes()[..], // depositor
+                &1000u64.to_le_bytes(),                // amount
+                &1_700_086_400i64.to_le_bytes(),       // deadline
+            ]
+            .concat(),
+        );
+        let mut depositor = TestAccount::new(name_key("depositor"), system_program::ID).signer();
+
+        // 截止时间是一天之后；按真实时间取回
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut depositor],
+            &[&[1][..], &1_700_000_000i64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        // 同一时刻把 now 填成截止时间
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut depositor],
+            &[&[1][..], &1_700_086_400i64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Refunded 1000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(escrow.data().as_ptr() as *const super::Escrow) };
+        assert_eq!(state.amount, 0);
+    }
+}
@@ -0,0 +1,141 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 钱包：兑换券按序号依次兑换
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    balance: u64,
+    next_serial: u64,
+}
+
+/// 兑换券：到期前可以把 amount 兑换进指定的钱包
+#[repr(C)]
+struct Voucher {
+    wallet: Pubkey,
+    amount: u64,
+    serial: u64,
+    expires_at: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let wallet_account = next_account_info(accounts_iter)?;
+    let voucher_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if wallet_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data != [1] {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = wallet_account.data.borrow_mut();
+    let mut wallet = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Wallet) };
+    if !owner.is_signer || wallet.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 兑换券账户没有校验 owner，调用方自己创建的账户可以写入任意到期时间
+    let voucher = unsafe {
+        std::ptr::read_unaligned(voucher_account.data.borrow().as_ptr() as *const Voucher)
+    };
+    if voucher.wallet != *wallet_account.key || voucher.serial != wallet.next_serial {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if Clock::get()?.unix_timestamp > voucher.expires_at {
+        msg!("Voucher expired at {}", voucher.expires_at);
+        return Err(ProgramError::Custom(1));
+    }
+
+    wallet.balance += voucher.amount;
+    wallet.next_serial += 1;
+    msg!("Redeemed {}", voucher.amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Wallet, wallet) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn redeem() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut wallet = TestAccount::new(name_key("wallet"), program_id).with_data(
+            &[
+                &name_key("holder").to_bytes()[..], // owner
+                &0u64.to_le_bytes(),                // balance
+                &0u64.to_le_bytes(),                // next_serial
+            ]
+            .concat(),
+        );
+        let mut stale = TestAccount::new(name_key("stale"), name_key("attacker_program"))
+            .with_data(
+                &[
+                    &name_key("wallet").to_bytes()[..], // wallet
+                    &500u64.to_le_bytes(),              // amount
+                    &0u64.to_le_bytes(),                // serial
+                    &1_700_000_000i64.to_le_bytes(),    // expires_at
+                ]
+                .concat(),
+            );
+        let mut forged = TestAccount::new(name_key("forged"), name_key("attacker_program"))
+            .with_data(
+                &[
+                    &name_key("wallet").to_bytes()[..], // wallet
+                    &500u64.to_le_bytes(),              // amount
+                    &0u64.to_le_bytes(),                // serial
+                    &1_800_000_000i64.to_le_bytes(),    // expires_at
+                ]
+                .concat(),
+            );
+        let mut holder = TestAccount::new(name_key("holder"), system_program::ID).signer();
+
+        // 照着一张已过期的兑换券自建账户，到期时间原样照抄
+        env.clock = Clock {
+            unix_timestamp: 1_700_086_400,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut wallet, &mut stale, &mut holder],
+            &[1],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        // 自建账户把到期时间改到三年后
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut wallet, &mut forged, &mut holder],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Redeemed 500");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(wallet.data().as_ptr() as *const super::Wallet) };
+        assert_eq!(state.balance, 500);
+        assert_eq!(state.next_serial, 1);
+    }
+}
@@ -0,0 +1,240 @@
+//! 所有用例的 `process_instruction`，按用例 id 索引。
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub type Processor = fn(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+pub const CASES: &[(&str, Processor)] = &[
+    ("ccd/ccd21", ccd21::process_instruction),
+    ("ccd/ccd21_safe", ccd21_safe::process_instruction),
+    ("ccd/ccd22", ccd22::process_instruction),
+    ("ccd/ccd22_safe", ccd22_safe::process_instruction),
+    ("ccd/ccd23", ccd23::process_instruction),
+    ("ccd/ccd23_safe", ccd23_safe::process_instruction),
+    ("ccd/ccd24", ccd24::process_instruction),
+    ("ccd/ccd24_safe", ccd24_safe::process_instruction),
+    ("ccd/ccd25", ccd25::process_instruction),
+    ("ccd/ccd25_safe", ccd25_safe::process_instruction),
+    ("ccd/ccd26", ccd26::process_instruction),
+    ("ccd/ccd26_safe", ccd26_safe::process_instruction),
+    ("ccd/ccd27", ccd27::process_instruction),
+    ("ccd/ccd27_safe", ccd27_safe::process_instruction),
+    ("ccd/ccd28", ccd28::process_instruction),
+    ("ccd/ccd28_safe", ccd28_safe::process_instruction),
+    ("ccd/ccd29", ccd29::process_instruction),
+    ("ccd/ccd29_safe", ccd29_safe::process_instruction),
+    ("ccd/ccd30", ccd30::process_instruction),
+    ("ccd/ccd30_safe", ccd30_safe::process_instruction),
+    ("ccd/ccd31", ccd31::process_instruction),
+    ("ccd/ccd31_safe", ccd31_safe::process_instruction),
+    ("ccd/ccd32", ccd32::process_instruction),
+    ("ccd/ccd32_safe", ccd32_safe::process_instruction),
+    ("ccd/epoch12", epoch12::process_instruction),
+    ("ccd/epoch12_safe", epoch12_safe::process_instruction),
+    ("ccd/epoch16", epoch16::process_instruction),
+    ("ccd/epoch16_safe", epoch16_safe::process_instruction),
+    ("ccd/epoch19", epoch19::process_instruction),
+    ("ccd/epoch19_safe", epoch19_safe::process_instruction),
+    ("ccd/epochstart10", epochstart10::process_instruction),
+    ("ccd/epochstart10_safe", epochstart10_safe::process_instruction),
+    ("ccd/epochstart15", epochstart15::process_instruction),
+    ("ccd/epochstart15_safe", epochstart15_safe::process_instruction),
+    ("ccd/epochstart18", epochstart18::process_instruction),
+    ("ccd/epochstart18_safe", epochstart18_safe::process_instruction),
+    ("ccd/leaderscheduleepoch11", leaderscheduleepoch11::process_instruction),
+    ("ccd/leaderscheduleepoch11_safe", leaderscheduleepoch11_safe::process_instruction),
+    ("ccd/leaderscheduleepoch17", leaderscheduleepoch17::process_instruction),
+    ("ccd/leaderscheduleepoch17_safe", leaderscheduleepoch17_safe::process_instruction),
+    ("ccd/mixslotts14", mixslotts14::process_instruction),
+    ("ccd/mixslotts14_safe", mixslotts14_safe::process_instruction),
+    ("ccd/mixslotts20", mixslotts20::process_instruction),
+    ("ccd/mixslotts20_safe", mixslotts20_safe::process_instruction),
+    ("ccd/slot1", slot1::process_instruction),
+    ("ccd/slot1_safe", slot1_safe::process_instruction),
+    ("ccd/slot2", slot2::process_instruction),
+    ("ccd/slot2_safe", slot2_safe::process_instruction),
+    ("ccd/slot33", slot33::process_instruction),
+    ("ccd/slot33_safe", slot33_safe::process_instruction),
+    ("ccd/timestamp3", timestamp3::process_instruction),
+    ("ccd/timestamp3_safe", timestamp3_safe::process_instruction),
+    ("ccd/timestamp4", timestamp4::process_instruction),
+    ("ccd/timestamp4_safe", timestamp4_safe::process_instruction),
+    ("ccd/timestamp5", timestamp5::process_instruction),
+    ("ccd/timestamp5_safe", timestamp5_safe::process_instruction),
+    ("ccd/timestamp6", timestamp6::process_instruction),
+    ("ccd/timestamp6_safe", timestamp6_safe::process_instruction),
+    ("ccd/timestamp7", timestamp7::process_instruction),
+    ("ccd/timestamp7_safe", timestamp7_safe::process_instruction),
+    ("ccd/timestamp8", timestamp8::process_instruction),
+    ("ccd/timestamp8_safe", timestamp8_safe::process_instruction),
+    ("ccd/timestamp9", timestamp9::process_instruction),
+    ("ccd/timestamp9_safe", timestamp9_safe::process_instruction),
+    ("ccd/timestamp13", timestamp13::process_instruction),
+    ("ccd/timestamp13_safe", timestamp13_safe::process_instruction),
+    ("tod/tod1", tod1::process_instruction),
+    ("tod/tod1_safe", tod1_safe::process_instruction),
+    ("tod/tod2", tod2::process_instruction),
+    ("tod/tod2_safe", tod2_safe::process_instruction),
+    ("tod/tod3", tod3::process_instruction),
+    ("tod/tod3_safe", tod3_safe::process_instruction),
+    ("tod/tod4", tod4::process_instruction),
+    ("tod/tod4_safe", tod4_safe::process_instruction),
+    ("tod/tod5", tod5::process_instruction),
+    ("tod/tod5_safe", tod5_safe::process_instruction),
+    ("tod/tod6", tod6::process_instruction),
+    ("tod/tod6_safe", tod6_safe::process_instruction),
+    ("tod/tod7", tod7::process_instruction),
+    ("tod/tod7_safe", tod7_safe::process_instruction),
+    ("tod/tod8", tod8::process_instruction),
+    ("tod/tod8_safe", tod8_safe::process_instruction),
+    ("tod/tod9", tod9::process_instruction),
+    ("tod/tod9_safe", tod9_safe::process_instruction),
+    ("tod/tod10", tod10::process_instruction),
+    ("tod/tod10_safe", tod10_safe::process_instruction),
+    ("tod/tod11", tod11::process_instruction),
+    ("tod/tod11_safe", tod11_safe::process_instruction),
+    ("tod/tod12", tod12::process_instruction),
+    ("tod/tod12_safe", tod12_safe::process_instruction),
+    ("tod/tod13", tod13::process_instruction),
+    ("tod/tod13_safe", tod13_safe::process_instruction),
+    ("tod/tod14", tod14::process_instruction),
+    ("tod/tod14_safe", tod14_safe::process_instruction),
+    ("tod/tod15", tod15::process_instruction),
+    ("tod/tod15_safe", tod15_safe::process_instruction),
+    ("tod/tod16", tod16::process_instruction),
+    ("tod/tod16_safe", tod16_safe::process_instruction),
+    ("tod/tod17", tod17::process_instruction),
+    ("tod/tod17_safe", tod17_safe::process_instruction),
+    ("tod/tod18", tod18::process_instruction),
+    ("tod/tod18_safe", tod18_safe::process_instruction),
+    ("tod/tod19", tod19::process_instruction),
+    ("tod/tod19_safe", tod19_safe::process_instruction),
+    ("tod/tod20", tod20::process_instruction),
+    ("tod/tod20_safe", tod20_safe::process_instruction),
+    ("tod/tod21", tod21::process_instruction),
+    ("tod/tod21_safe", tod21_safe::process_instruction),
+    ("tod/tod22", tod22::process_instruction),
+    ("tod/tod22_safe", tod22_safe::process_instruction),
+    ("tod/tod23", tod23::process_instruction),
+    ("tod/tod23_safe", tod23_safe::process_instruction),
+    ("tod/tod24", tod24::process_instruction),
+    ("tod/tod24_safe", tod24_safe::process_instruction),
+    ("tod/tod25", tod25::process_instruction),
+    ("tod/tod25_safe", tod25_safe::process_instruction),
+    ("tod/tod26", tod26::process_instruction),
+    ("tod/tod26_safe", tod26_safe::process_instruction),
+    ("tod/tod27", tod27::process_instruction),
+    ("tod/tod27_safe", tod27_safe::process_instruction),
+    ("tod/tod28", tod28::process_instruction),
+    ("tod/tod28_safe", tod28_safe::process_instruction),
+    ("tod/tod29", tod29::process_instruction),
+    ("tod/tod29_safe", tod29_safe::process_instruction),
+    ("tod/tod30", tod30::process_instruction),
+    ("tod/tod30_safe", tod30_safe::process_instruction),
+    ("tod/tod31", tod31::process_instruction),
+    ("tod/tod31_safe", tod31_safe::process_instruction),
+    ("tod/tod32", tod32::process_instruction),
+    ("tod/tod32_safe", tod32_safe::process_instruction),
+    ("sysvar/clock1", clock1::process_instruction),
+    ("sysvar/clock1_safe", clock1_safe::process_instruction),
+    ("sysvar/clock2", clock2::process_instruction),
+    ("sysvar/clock2_safe", clock2_safe::process_instruction),
+    ("sysvar/rent3", rent3::process_instruction),
+    ("sysvar/rent3_safe", rent3_safe::process_instruction),
+    ("sysvar/epochschedule4", epochschedule4::process_instruction),
+    ("sysvar/epochschedule4_safe", epochschedule4_safe::process_instruction),
+    ("sysvar/slothashes5", slothashes5::process_instruction),
+    ("sysvar/slothashes5_safe", slothashes5_safe::process_instruction),
+    ("sysvar/instructions6", instructions6::process_instruction),
+    ("sysvar/instructions6_safe", instructions6_safe::process_instruction),
+    ("randomness/coinflip1", coinflip1::process_instruction),
+    ("randomness/coinflip1_safe", coinflip1_safe::process_instruction),
+    ("randomness/dice2", dice2::process_instruction),
+    ("randomness/dice2_safe", dice2_safe::process_instruction),
+    ("randomness/slothash3", slothash3::process_instruction),
+    ("randomness/slothash3_safe", slothash3_safe::process_instruction),
+    ("randomness/blockhash4", blockhash4::process_instruction),
+    ("randomness/blockhash4_safe", blockhash4_safe::process_instruction),
+    ("randomness/signer5", signer5::process_instruction),
+    ("randomness/signer5_safe", signer5_safe::process_instruction),
+    ("randomness/salt6", salt6::process_instruction),
+    ("randomness/salt6_safe", salt6_safe::process_instruction),
+    ("randomness/xor7", xor7::process_instruction),
+    ("randomness/xor7_safe", xor7_safe::process_instruction),
+    ("randomness/mix8", mix8::process_instruction),
+    ("randomness/mix8_safe", mix8_safe::process_instruction),
+    ("randomness/raffle9", raffle9::process_instruction),
+    ("randomness/raffle9_safe", raffle9_safe::process_instruction),
+    ("randomness/delayed10", delayed10::process_instruction),
+    ("randomness/delayed10_safe", delayed10_safe::process_instruction),
+    ("randomness/prng11", prng11::process_instruction),
+    ("randomness/prng11_safe", prng11_safe::process_instruction),
+    ("randomness/weighted12", weighted12::process_instruction),
+    ("randomness/weighted12_safe", weighted12_safe::process_instruction),
+    ("init/config1", config1::process_instruction),
+    ("init/config1_safe", config1_safe::process_instruction),
+    ("init/admin2", admin2::process_instruction),
+    ("init/admin2_safe", admin2_safe::process_instruction),
+    ("init/vault3", vault3::process_instruction),
+    ("init/vault3_safe", vault3_safe::process_instruction),
+    ("init/profile4", profile4::process_instruction),
+    ("init/profile4_safe", profile4_safe::process_instruction),
+    ("init/escrow5", escrow5::process_instruction),
+    ("init/escrow5_safe", escrow5_safe::process_instruction),
+    ("sandwich/swap1", swap1::process_instruction),
+    ("sandwich/swap1_safe", swap1_safe::process_instruction),
+    ("sandwich/liquidity2", liquidity2::process_instruction),
+    ("sandwich/liquidity2_safe", liquidity2_safe::process_instruction),
+    ("sandwich/route3", route3::process_instruction),
+    ("sandwich/route3_safe", route3_safe::process_instruction),
+    ("nonce/bid1", bid1::process_instruction),
+    ("nonce/bid1_safe", bid1_safe::process_instruction),
+    ("nonce/lease2", lease2::process_instruction),
+    ("nonce/lease2_safe", lease2_safe::process_instruction),
+    ("nonce/unstake3", unstake3::process_instruction),
+    ("nonce/unstake3_safe", unstake3_safe::process_instruction),
+    ("introspection/flashloan1", flashloan1::process_instruction),
+    ("introspection/flashloan1_safe", flashloan1_safe::process_instruction),
+    ("introspection/flashloan2", flashloan2::process_instruction),
+    ("introspection/flashloan2_safe", flashloan2_safe::process_instruction),
+    ("introspection/flashloan3", flashloan3::process_instruction),
+    ("introspection/flashloan3_safe", flashloan3_safe::process_instruction),
+    ("price/lend1", lend1::process_instruction),
+    ("price/lend1_safe", lend1_safe::process_instruction),
+    ("price/liquidate2", liquidate2::process_instruction),
+    ("price/liquidate2_safe", liquidate2_safe::process_instruction),
+    ("price/settle3", settle3::process_instruction),
+    ("price/settle3_safe", settle3_safe::process_instruction),
+    ("epoch/vesting1", vesting1::process_instruction),
+    ("epoch/vesting1_safe", vesting1_safe::process_instruction),
+    ("epoch/staking2", staking2::process_instruction),
+    ("epoch/staking2_safe", staking2_safe::process_instruction),
+    ("epoch/tips3", tips3::process_instruction),
+    ("epoch/tips3_safe", tips3_safe::process_instruction),
+    ("client-time/refund1", refund1::process_instruction),
+    ("client-time/refund1_safe", refund1_safe::process_instruction),
+    ("client-time/voucher2", voucher2::process_instruction),
+    ("client-time/voucher2_safe", voucher2_safe::process_instruction),
+    ("client-time/lock3", lock3::process_instruction),
+    ("client-time/lock3_safe", lock3_safe::process_instruction),
+    ("time-arith/grant1", grant1::process_instruction),
+    ("time-arith/grant1_safe", grant1_safe::process_instruction),
+    ("time-arith/faucet2", faucet2::process_instruction),
+    ("time-arith/faucet2_safe", faucet2_safe::process_instruction),
+    ("time-arith/order3", order3::process_instruction),
+    ("time-arith/order3_safe", order3_safe::process_instruction),
+    ("float-order/pool1", pool1::process_instruction),
+    ("float-order/pool1_safe", pool1_safe::process_instruction),
+    ("float-order/vault2", vault2::process_instruction),
+    ("float-order/vault2_safe", vault2_safe::process_instruction),
+    ("float-order/fee3", fee3::process_instruction),
+    ("float-order/fee3_safe", fee3_safe::process_instruction),
+];
+
+/// 按完整 id（`ccd/ccd21`）或目录名（`ccd21`）查找
+pub fn processor(id: &str) -> Option<Processor> {
+    CASES
+        .iter()
+        .find(|(case, _)| *case == id || case.rsplit('/').next() == Some(id))
+        .map(|(_, processor)| *processor)
+}
@@ -0,0 +1,302 @@
+//! `solracebench` 命令行工具。
+
+use std::{
+    collections::BTreeSet,
+    error::Error,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use solracebench_codec::{changes, decode, decode_account};
+use solracebench_executor::{check, result_name, Account, Scenario};
+use solracebench_manifest::{load_all, Bytes, Case};
+use solracebench_oracle::{attackers, conservation};
+use solracebench_score::{
+    read_reports,
+    sarif::{self, RuleMap},
+    score, Reported,
+};
+
+#[derive(Parser)]
+#[command(version, about = "Solana race-condition benchmark tooling")]
+struct Cli {
+    /// Benchmark root containing one directory per category (ccd/, tod/, sysvar/, ...)
+    #[arg(long, global = true, default_value = ".")]
+    root: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Grade a tool report (JSON lines) against the ground truth
+    Score {
+        /// Report file, `-` for stdin
+        report: PathBuf,
+        /// Report format; by default SARIF for `.sarif` files and JSON lines otherwise
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// TOML file mapping SARIF ruleIds to categories
+        #[arg(long)]
+        rules: Option<PathBuf>,
+        /// Maximum distance in lines between a reported line and a ground-truth span
+        #[arg(long, default_value_t = 3)]
+        tolerance: usize,
+        /// Also write the scores as JSON to this file, `-` for stdout
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Export the ground truth as SARIF 2.1.0
+    ExportSarif {
+        /// Only export these cases (full id or directory name)
+        #[arg(long = "case")]
+        cases: Vec<String>,
+        /// Output file, stdout by default
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Export the instruction-data schemas as JSON for structure-aware fuzzers
+    Schema {
+        /// Only export these cases (full id or directory name)
+        #[arg(long = "case")]
+        cases: Vec<String>,
+        /// Output file, stdout by default
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Decode instruction data (hex) with a case's schemas
+    Decode {
+        /// Case id or directory name
+        case: String,
+        /// Instruction data as hex, whitespace allowed
+        data: String,
+    },
+    /// Run a case's trigger, print each step's effect and check its witness
+    Witness {
+        /// Case id or directory name
+        case: String,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Jsonl,
+    Sarif,
+}
+
+fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path.as_os_str() == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+fn read(
+    path: &Path,
+    format: Option<Format>,
+    rules: Option<&Path>,
+    cases: &[Case],
+) -> Result<Vec<Reported>, Box<dyn Error>> {
+    let sarif = path.extension().is_some_and(|ext| ext == "sarif");
+    match format.unwrap_or(if sarif { Format::Sarif } else { Format::Jsonl }) {
+        Format::Jsonl => Ok(read_reports(open(path)?)?),
+        Format::Sarif => {
+            let rules = match rules {
+                Some(rules) => RuleMap::parse(&fs::read_to_string(rules)?)?,
+                None => RuleMap::default(),
+            };
+            let imported = sarif::read_sarif(open(path)?, cases, &rules)?;
+            for (rule, count) in &imported.unmapped {
+                eprintln!("warning: skipped {count} result(s) with unmapped ruleId {rule:?}");
+            }
+            for (uri, count) in &imported.outside {
+                eprintln!("warning: skipped {count} result(s) outside every case: {uri}");
+            }
+            if imported.unlocated > 0 {
+                eprintln!(
+                    "warning: skipped {} result(s) without a physical location",
+                    imported.unlocated
+                );
+            }
+            Ok(imported.reports)
+        }
+    }
+}
+
+/// 按 id 或目录名挑出用例，没有指定时返回全部
+fn select(cases: &[Case], wanted: &[String]) -> Result<Vec<Case>, Box<dyn Error>> {
+    if wanted.is_empty() {
+        return Ok(cases.to_vec());
+    }
+    wanted
+        .iter()
+        .map(|id| {
+            cases
+                .iter()
+                .find(|c| c.matches(id))
+                .cloned()
+                .ok_or_else(|| format!("unknown case `{id}`").into())
+        })
+        .collect()
+}
+
+fn create(output: Option<PathBuf>) -> io::Result<Box<dyn Write>> {
+    Ok(match output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    })
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let cases = load_all(&cli.root)?;
+    match cli.command {
+        Command::Score {
+            report,
+            format,
+            rules,
+            tolerance,
+            json,
+        } => {
+            let reports = read(&report, format, rules.as_deref(), &cases)?;
+            let card = score(&cases, &reports, tolerance)?;
+            match json {
+                Some(path) if path.as_os_str() == "-" => {
+                    serde_json::to_writer_pretty(io::stdout().lock(), &card)?;
+                    println!();
+                }
+                Some(path) => {
+                    let mut file = File::create(path)?;
+                    serde_json::to_writer_pretty(&mut file, &card)?;
+                    writeln!(file)?;
+                    print!("{card}");
+                }
+                None => print!("{card}"),
+            }
+        }
+        Command::ExportSarif {
+            cases: wanted,
+            output,
+        } => {
+            let log = sarif::export(&select(&cases, &wanted)?);
+            let mut out = create(output)?;
+            serde_json::to_writer_pretty(&mut out, &log)?;
+            writeln!(out)?;
+        }
+        Command::Schema {
+            cases: wanted,
+            output,
+        } => {
+            let schemas: Vec<_> = select(&cases, &wanted)?
+                .iter()
+                .map(|case| {
+                    serde_json::json!({
+                        "case": case.id,
+                        "instructions": case.instructions,
+                        "layouts": case.layouts,
+                    })
+                })
+                .collect();
+            let mut out = create(output)?;
+            serde_json::to_writer_pretty(&mut out, &schemas)?;
+            writeln!(out)?;
+        }
+        Command::Decode { case, data } => {
+            let case = select(&cases, &[case])?.remove(0);
+            let data = Bytes::parse(&data)?;
+            let decoded = decode(&case.instructions, data.as_slice())?;
+            println!("{decoded}");
+            for name in decoded.out_of_range() {
+                eprintln!("warning: `{name}` is outside its declared range");
+            }
+        }
+        Command::Witness { case } => {
+            let case = select(&cases, &[case])?.remove(0);
+            if !witness(&case)? {
+                return Err(format!("witness of `{}` does not hold", case.id).into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 逐步执行 trigger，打印每步的结果、日志和有布局的账户变化的字段，
+/// 再打印攻击者的收益和不守恒的资产，最后检查 witness
+fn witness(case: &Case) -> Result<bool, Box<dyn Error>> {
+    let processor = solracebench_cases::processor(&case.id)
+        .ok_or_else(|| format!("`{}` is not linked into this build", case.id))?;
+    let mut scenario = Scenario::new(case, processor)?;
+    // 在另一份场景上执行，不影响下面逐步打印
+    let value = conservation(&scenario, &attackers(&scenario))?;
+    let layouts = scenario.layouts.clone();
+    let data = |accounts: &[Account]| -> Vec<Vec<u8>> {
+        layouts
+            .keys()
+            .map(|key| {
+                accounts
+                    .iter()
+                    .find(|a| a.key == *key)
+                    .map_or_else(Vec::new, |a| a.data.clone())
+            })
+            .collect()
+    };
+    let mut outcomes = Vec::new();
+    for transaction in scenario.transactions() {
+        let mut before = data(scenario.executor.accounts());
+        let results = scenario.run_transaction(transaction.clone());
+        for (i, outcome) in transaction.zip(results) {
+            let step = &scenario.steps[i];
+            let after = data(&outcome.accounts);
+            println!("step {i} ({}): {}", step.note, result_name(&outcome.result));
+            for log in &outcome.logs {
+                match solracebench_sink::parse(log) {
+                    Some(sink) => println!("  sink: {sink}"),
+                    None => println!("  log: {log}"),
+                }
+            }
+            for (((key, layout), before), after) in layouts.iter().zip(&before).zip(&after) {
+                let name = scenario
+                    .keys
+                    .iter()
+                    .find(|(_, k)| *k == key)
+                    .map(|(n, _)| n);
+                let before = decode_account(layout, before);
+                let after = decode_account(layout, after);
+                for change in changes(&before, &after) {
+                    println!("  {}.{change}", name.map_or("?", String::as_str));
+                }
+            }
+            before = after;
+            outcomes.push(outcome);
+        }
+    }
+    println!("value: {value}");
+    let mismatches = check(&scenario, &outcomes, &case.witness);
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+    println!(
+        "witness: {} of {} expectations hold",
+        case.witness.len()
+            - mismatches
+                .iter()
+                .map(|m| m.index)
+                .collect::<BTreeSet<_>>()
+                .len(),
+        case.witness.len()
+    );
+    Ok(mismatches.is_empty())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
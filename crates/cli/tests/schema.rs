@@ -0,0 +1,62 @@
+use std::{path::PathBuf, process::Command};
+
+fn solracebench(args: &[&str]) -> String {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let output = Command::new(env!("CARGO_BIN_EXE_solracebench"))
+        .arg("--root")
+        .arg(&root)
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn schema_exports_selected_cases() {
+    let text = solracebench(&["schema", "--case", "ccd21"]);
+    let schemas: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(schemas.as_array().unwrap().len(), 1);
+    assert_eq!(schemas[0]["case"], "ccd/ccd21");
+    assert_eq!(
+        schemas[0]["instructions"][0]["fields"][0]["name"],
+        "end_slot"
+    );
+    assert_eq!(schemas[0]["instructions"][0]["fields"][0]["type"], "u64");
+}
+
+#[test]
+fn decode_names_fields() {
+    let text = solracebench(&["decode", "tod24", "00 e803000000000000 0a00000000000000"]);
+    assert_eq!(
+        text.trim(),
+        "add_liquidity { amount_a: 1000, amount_b: 10 }"
+    );
+}
+
+#[test]
+fn witness_shows_field_changes() {
+    let text = solracebench(&["witness", "ccd22"]);
+    assert!(text.contains("auction.highest_bid: 0 -> 500"), "{text}");
+    assert!(text.contains("  sink: ccd22"), "{text}");
+    assert!(text.contains("value: attacker gained nothing"), "{text}");
+    assert!(text.contains("witness: 2 of 2 expectations hold"), "{text}");
+}
+
+#[test]
+fn witness_reports_value_flows() {
+    let text = solracebench(&["witness", "flashloan1"]);
+    assert!(
+        text.contains("value: attacker gained 1000000 token\n"),
+        "{text}"
+    );
+    let text = solracebench(&["witness", "slot2"]);
+    assert!(
+        text.contains("steps 0..1 destroyed 1000000 lamports"),
+        "{text}"
+    );
+}
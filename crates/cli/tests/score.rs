@@ -0,0 +1,62 @@
+use std::{fs, path::PathBuf, process::Command};
+
+#[test]
+fn score_writes_text_and_json() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let report = dir.join("score-report.jsonl");
+    let json = dir.join("score.json");
+    fs::write(
+        &report,
+        "{\"case\": \"ccd/slot1\", \"line\": 24, \"category\": \"ccd\"}\n",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_solracebench"))
+        .arg("--root")
+        .arg(&root)
+        .args(["score", "--tolerance", "5", "--json"])
+        .arg(&json)
+        .arg(&report)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let text = String::from_utf8(output.stdout).unwrap();
+    assert!(text.contains("confusion"), "{text}");
+    assert!(text.contains("safe twins"), "{text}");
+
+    let card: serde_json::Value = serde_json::from_slice(&fs::read(&json).unwrap()).unwrap();
+    assert_eq!(card["tolerance"], 5);
+    assert_eq!(card["categories"]["ccd"]["tp"], 1);
+    assert_eq!(card["clock_fields"]["slot"]["tp"], 1);
+    assert_eq!(card["confusion"]["missed"]["ccd"], 32);
+    assert_eq!(card["false_positives"]["ccd"]["negatives"], 33);
+    assert_eq!(card["false_positives"]["ccd"]["rate"], 0.0);
+}
+
+#[test]
+fn exported_sarif_scores_perfectly() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let sarif = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ground-truth.sarif");
+    let export = Command::new(env!("CARGO_BIN_EXE_solracebench"))
+        .arg("--root")
+        .arg(&root)
+        .args(["export-sarif", "--output"])
+        .arg(&sarif)
+        .status()
+        .unwrap();
+    assert!(export.success());
+    let output = Command::new(env!("CARGO_BIN_EXE_solracebench"))
+        .arg("--root")
+        .arg(&root)
+        .args(["score", "--tolerance", "0", "--json", "-"])
+        .arg(&sarif)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let card: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(card["overall"]["f1"], 1.0);
+}
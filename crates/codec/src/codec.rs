@@ -0,0 +1,363 @@
+use std::{cmp::Ordering, fmt};
+
+use solana_program::pubkey::Pubkey;
+use solracebench_manifest::{Bound, Field, InstructionSchema, Type};
+
+use crate::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// 数据不以布局的 tag 开头
+    Tag,
+    /// 字段需要的字节超出了数据末尾
+    Short { field: String },
+    /// 解码完全部字段后还剩下字节
+    Trailing(usize),
+    /// 字节不是该类型的合法编码，例如 bool 不是 0/1、字符串不是 UTF-8
+    Malformed { field: String, reason: String },
+    /// 编码时值与字段类型不符，或者值的个数与字段数不同
+    Mismatch { field: String, ty: String },
+    /// 没有任何一种布局能完整解码这段数据
+    NoMatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Tag => write!(f, "data does not start with the tag"),
+            Error::Short { field } => write!(f, "data ends inside field `{field}`"),
+            Error::Trailing(n) => write!(f, "{n} trailing bytes"),
+            Error::Malformed { field, reason } => write!(f, "field `{field}`: {reason}"),
+            Error::Mismatch { field, ty } => write!(f, "field `{field}`: value is not a {ty}"),
+            Error::NoMatch => write!(f, "no instruction layout matches"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// 解码出的一条指令
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decoded<'a> {
+    pub schema: &'a InstructionSchema,
+    pub fields: Vec<(String, Value)>,
+}
+
+impl Decoded<'_> {
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+
+    /// 取值超出声明范围的字段
+    pub fn out_of_range(&self) -> Vec<&str> {
+        out_of_range(&self.schema.fields, &self.fields)
+    }
+}
+
+impl fmt::Display for Decoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.schema.name)?;
+        if self.fields.is_empty() {
+            return Ok(());
+        }
+        f.write_str(" {")?;
+        for (i, (name, value)) in self.fields.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{sep}{name}: {value}")?;
+        }
+        f.write_str(" }")
+    }
+}
+
+/// 按声明顺序尝试每种布局，返回第一种能完整解码的
+///
+/// 同一段数据可能同时符合多种布局（例如初始化和出价都只带一个 u64），
+/// 这时由清单里的顺序决定。
+pub fn decode<'a>(schemas: &'a [InstructionSchema], data: &[u8]) -> Result<Decoded<'a>, Error> {
+    schemas
+        .iter()
+        .find_map(|schema| {
+            decode_instruction(schema, data)
+                .ok()
+                .map(|fields| Decoded { schema, fields })
+        })
+        .ok_or(Error::NoMatch)
+}
+
+pub fn decode_instruction(
+    schema: &InstructionSchema,
+    data: &[u8],
+) -> Result<Vec<(String, Value)>, Error> {
+    let rest = data.strip_prefix(schema.tag.as_slice()).ok_or(Error::Tag)?;
+    decode_fields(&schema.fields, rest)
+}
+
+/// 依次解码全部字段，数据必须恰好用完
+pub fn decode_fields(fields: &[Field], data: &[u8]) -> Result<Vec<(String, Value)>, Error> {
+    let mut reader = Reader { data, pos: 0 };
+    let mut values = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        // 剩余字节字段之后只有定长字段，给它们留出位置
+        let reserved = fields[i + 1..].iter().filter_map(|f| f.ty.size()).sum();
+        let value = reader.value(&field.ty, &field.name, reserved)?;
+        values.push((field.name.clone(), value));
+    }
+    match data.len() - reader.pos {
+        0 => Ok(values),
+        n => Err(Error::Trailing(n)),
+    }
+}
+
+/// 解码开头能解析的字段，遇到第一个失败的字段就停止；账户数据常在结构体之后留有空余
+pub fn decode_prefix(fields: &[Field], data: &[u8]) -> (Vec<(String, Value)>, Option<Error>) {
+    let mut reader = Reader { data, pos: 0 };
+    let mut values = Vec::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let reserved = fields[i + 1..].iter().filter_map(|f| f.ty.size()).sum();
+        match reader.value(&field.ty, &field.name, reserved) {
+            Ok(value) => values.push((field.name.clone(), value)),
+            Err(err) => return (values, Some(err)),
+        }
+    }
+    (values, None)
+}
+
+/// 把恰好一个定长值的字节解码成值；`bytes` 不够长时报 `Short`
+pub(crate) fn decode_value(ty: &Type, field: &str, bytes: &[u8]) -> Result<Value, Error> {
+    Reader {
+        data: bytes,
+        pos: 0,
+    }
+    .value(ty, field, 0)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize, field: &str) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos.checked_add(n).ok_or_else(|| short(field))?)
+            .ok_or_else(|| short(field))?;
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self, field: &str) -> Result<[u8; N], Error> {
+        Ok(self.take(N, field)?.try_into().unwrap())
+    }
+
+    fn len(&mut self, field: &str) -> Result<usize, Error> {
+        Ok(u32::from_le_bytes(self.array(field)?) as usize)
+    }
+
+    fn value(&mut self, ty: &Type, field: &str, reserved: usize) -> Result<Value, Error> {
+        Ok(match ty {
+            Type::U8 => Value::Int(u8::from_le_bytes(self.array(field)?).into()),
+            Type::U16 => Value::Int(u16::from_le_bytes(self.array(field)?).into()),
+            Type::U32 => Value::Int(u32::from_le_bytes(self.array(field)?).into()),
+            Type::U64 => Value::Int(u64::from_le_bytes(self.array(field)?).into()),
+            Type::U128 => Value::unsigned(u128::from_le_bytes(self.array(field)?)),
+            Type::I8 => Value::Int(i8::from_le_bytes(self.array(field)?).into()),
+            Type::I16 => Value::Int(i16::from_le_bytes(self.array(field)?).into()),
+            Type::I32 => Value::Int(i32::from_le_bytes(self.array(field)?).into()),
+            Type::I64 => Value::Int(i64::from_le_bytes(self.array(field)?).into()),
+            Type::I128 => Value::Int(i128::from_le_bytes(self.array(field)?)),
+            Type::F32 => Value::F32(f32::from_le_bytes(self.array(field)?)),
+            Type::F64 => Value::F64(f64::from_le_bytes(self.array(field)?)),
+            Type::Bool => match self.array::<1>(field)?[0] {
+                0 => Value::Bool(false),
+                1 => Value::Bool(true),
+                b => return Err(malformed(field, format!("bool byte {b}"))),
+            },
+            Type::Pubkey => Value::Pubkey(Pubkey::new_from_array(self.array(field)?)),
+            Type::String => {
+                let len = self.len(field)?;
+                Value::Str(utf8(self.take(len, field)?, field)?)
+            }
+            Type::Vec(inner) => {
+                let len = self.len(field)?;
+                // 长度前缀可能是任意值，先确认剩余字节够用再分配
+                if inner
+                    .size()
+                    .is_some_and(|size| size.saturating_mul(len) > self.data.len() - self.pos)
+                {
+                    return Err(short(field));
+                }
+                let items = (0..len)
+                    .map(|_| self.value(inner, field, 0))
+                    .collect::<Result<_, _>>()?;
+                Value::List(items)
+            }
+            Type::Option(inner) => match self.array::<1>(field)?[0] {
+                0 => Value::Option(None),
+                1 => Value::Option(Some(Box::new(self.value(inner, field, reserved)?))),
+                b => return Err(malformed(field, format!("option tag {b}"))),
+            },
+            Type::Array(inner, len) if **inner == Type::U8 => {
+                Value::Bytes(self.take(*len, field)?.to_vec())
+            }
+            Type::Array(inner, len) => Value::List(
+                (0..*len)
+                    .map(|_| self.value(inner, field, 0))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::Tuple(types) => Value::List(
+                types
+                    .iter()
+                    .map(|ty| self.value(ty, field, 0))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Type::Rest | Type::Str => {
+                let n = (self.data.len() - self.pos)
+                    .checked_sub(reserved)
+                    .ok_or_else(|| short(field))?;
+                let bytes = self.take(n, field)?;
+                if *ty == Type::Str {
+                    Value::Str(utf8(bytes, field)?)
+                } else {
+                    Value::Bytes(bytes.to_vec())
+                }
+            }
+        })
+    }
+}
+
+fn short(field: &str) -> Error {
+    Error::Short {
+        field: field.to_owned(),
+    }
+}
+
+fn malformed(field: &str, reason: String) -> Error {
+    Error::Malformed {
+        field: field.to_owned(),
+        reason,
+    }
+}
+
+fn utf8(bytes: &[u8], field: &str) -> Result<String, Error> {
+    String::from_utf8(bytes.to_vec()).map_err(|_| malformed(field, "invalid UTF-8".to_owned()))
+}
+
+/// `values` 与 `schema.fields` 一一对应
+pub fn encode_instruction(schema: &InstructionSchema, values: &[Value]) -> Result<Vec<u8>, Error> {
+    let mut out = schema.tag.as_slice().to_vec();
+    encode_fields(&schema.fields, values, &mut out)?;
+    Ok(out)
+}
+
+pub fn encode_fields(fields: &[Field], values: &[Value], out: &mut Vec<u8>) -> Result<(), Error> {
+    if fields.len() != values.len() {
+        return Err(Error::Mismatch {
+            field: format!("{} values for {} fields", values.len(), fields.len()),
+            ty: "field list".to_owned(),
+        });
+    }
+    for (field, value) in fields.iter().zip(values) {
+        encode_value(&field.ty, value, out).map_err(|()| Error::Mismatch {
+            field: field.name.clone(),
+            ty: field.ty.to_string(),
+        })?;
+    }
+    Ok(())
+}
+
+fn encode_value(ty: &Type, value: &Value, out: &mut Vec<u8>) -> Result<(), ()> {
+    macro_rules! int {
+        ($t:ty) => {{
+            let v = value.as_int().ok_or(())?;
+            out.extend_from_slice(&<$t>::try_from(v).map_err(|_| ())?.to_le_bytes());
+        }};
+    }
+    match (ty, value) {
+        (Type::U8, _) => int!(u8),
+        (Type::U16, _) => int!(u16),
+        (Type::U32, _) => int!(u32),
+        (Type::U64, _) => int!(u64),
+        (Type::U128, Value::U128(v)) => out.extend_from_slice(&v.to_le_bytes()),
+        (Type::U128, _) => int!(u128),
+        (Type::I8, _) => int!(i8),
+        (Type::I16, _) => int!(i16),
+        (Type::I32, _) => int!(i32),
+        (Type::I64, _) => int!(i64),
+        (Type::I128, _) => int!(i128),
+        (Type::F32, Value::F32(v)) => out.extend_from_slice(&v.to_le_bytes()),
+        (Type::F64, Value::F64(v)) => out.extend_from_slice(&v.to_le_bytes()),
+        (Type::Bool, Value::Bool(v)) => out.push(u8::from(*v)),
+        (Type::Pubkey, Value::Pubkey(key)) => out.extend_from_slice(key.as_ref()),
+        (Type::String, Value::Str(s)) => {
+            out.extend_from_slice(&u32::try_from(s.len()).map_err(|_| ())?.to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        (Type::Vec(inner), Value::List(items)) => {
+            out.extend_from_slice(&u32::try_from(items.len()).map_err(|_| ())?.to_le_bytes());
+            for item in items {
+                encode_value(inner, item, out)?;
+            }
+        }
+        (Type::Option(_), Value::Option(None)) => out.push(0),
+        (Type::Option(inner), Value::Option(Some(v))) => {
+            out.push(1);
+            encode_value(inner, v, out)?;
+        }
+        (Type::Array(inner, len), Value::Bytes(bytes))
+            if **inner == Type::U8 && bytes.len() == *len =>
+        {
+            out.extend_from_slice(bytes)
+        }
+        (Type::Array(inner, len), Value::List(items)) if items.len() == *len => {
+            for item in items {
+                encode_value(inner, item, out)?;
+            }
+        }
+        (Type::Tuple(types), Value::List(items)) if items.len() == types.len() => {
+            for (ty, item) in types.iter().zip(items) {
+                encode_value(ty, item, out)?;
+            }
+        }
+        (Type::Rest, Value::Bytes(bytes)) => out.extend_from_slice(bytes),
+        (Type::Str, Value::Str(s)) => out.extend_from_slice(s.as_bytes()),
+        _ => return Err(()),
+    }
+    Ok(())
+}
+
+/// 值是否落在字段声明的范围内；没有声明范围或不是数值的字段总是满足，NaN 不在任何范围内
+pub fn in_range(field: &Field, value: &Value) -> bool {
+    if value.as_f64().is_none() {
+        return true;
+    }
+    let above = field.min.is_none_or(|min| {
+        matches!(
+            compare(value, min),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    });
+    let below = field
+        .max
+        .is_none_or(|max| matches!(compare(value, max), Some(Ordering::Less | Ordering::Equal)));
+    above && below
+}
+
+/// 整数与整数端点精确比较，其余按 f64 比较
+fn compare(value: &Value, bound: Bound) -> Option<Ordering> {
+    match (value, bound) {
+        (Value::Int(v), Bound::Int(b)) => Some(v.cmp(&b.into())),
+        (Value::U128(_), Bound::Int(_)) => Some(Ordering::Greater),
+        _ => value.as_f64()?.partial_cmp(&bound.as_f64()),
+    }
+}
+
+/// 取值超出声明范围的字段名
+pub fn out_of_range<'a>(fields: &'a [Field], values: &[(String, Value)]) -> Vec<&'a str> {
+    fields
+        .iter()
+        .zip(values)
+        .filter(|(field, (_, value))| !in_range(field, value))
+        .map(|(field, _)| field.name.as_str())
+        .collect()
+}
@@ -0,0 +1,244 @@
+use solana_program::pubkey::Pubkey;
+use solracebench_manifest::{Bound, Field, InstructionSchema, Type};
+
+use crate::{encode_instruction, Value};
+
+/// 可变长字段生成的最大元素数或字节数
+const MAX_LEN: usize = 16;
+
+/// 整数类型自身的取值范围
+fn int_bounds(ty: &Type) -> (i128, i128) {
+    match ty {
+        Type::U8 => (0, u8::MAX.into()),
+        Type::U16 => (0, u16::MAX.into()),
+        Type::U32 => (0, u32::MAX.into()),
+        Type::U64 => (0, u64::MAX.into()),
+        // 超出 i128 的部分见 `wide_u128`
+        Type::U128 => (0, i128::MAX),
+        Type::I8 => (i8::MIN.into(), i8::MAX.into()),
+        Type::I16 => (i16::MIN.into(), i16::MAX.into()),
+        Type::I32 => (i32::MIN.into(), i32::MAX.into()),
+        Type::I64 => (i64::MIN.into(), i64::MAX.into()),
+        _ => (i128::MIN, i128::MAX),
+    }
+}
+
+/// 整数字段实际的闭区间：类型范围与声明范围的交集
+fn int_range(field: &Field) -> (i128, i128) {
+    let (lo, hi) = int_bounds(&field.ty);
+    let clamp = |bound: Bound| match bound {
+        Bound::Int(v) => i128::from(v),
+        Bound::Float(v) => v as i128,
+    };
+    (
+        field.min.map_or(lo, |b| clamp(b).max(lo)),
+        field.max.map_or(hi, |b| clamp(b).min(hi)),
+    )
+}
+
+/// 没有声明上界的 u128 字段，取值可以超出 i128
+fn wide_u128(field: &Field) -> bool {
+    field.ty == Type::U128 && field.max.is_none()
+}
+
+fn float(ty: &Type, v: f64) -> Value {
+    if *ty == Type::F32 {
+        Value::F32(v as f32)
+    } else {
+        Value::F64(v)
+    }
+}
+
+/// 数值字段的边界取值：范围两端、两端向内一步以及 0 和 ±1（落在范围内时）
+///
+/// 没有声明范围的浮点字段额外给出 NaN、无穷、-0.0 和最小的次正规数。
+/// 非数值字段返回空列表。
+pub fn candidates(field: &Field) -> Vec<Value> {
+    let mut out: Vec<Value> = Vec::new();
+    let mut push = |v: Value| {
+        if !out.iter().any(|o| o.same(&v)) {
+            out.push(v);
+        }
+    };
+    if field.ty.is_integer() {
+        let (lo, hi) = int_range(field);
+        for v in [lo, lo.saturating_add(1), -1, 0, 1, hi.saturating_sub(1), hi] {
+            if (lo..=hi).contains(&v) {
+                push(Value::Int(v));
+            }
+        }
+        if wide_u128(field) {
+            push(Value::U128(u128::MAX - 1));
+            push(Value::U128(u128::MAX));
+        }
+    } else if field.ty.is_float() {
+        let lo = field.min.map(Bound::as_f64);
+        let hi = field.max.map(Bound::as_f64);
+        for v in [lo, Some(-1.0), Some(0.0), Some(1.0), hi]
+            .into_iter()
+            .flatten()
+        {
+            if lo.is_none_or(|lo| v >= lo) && hi.is_none_or(|hi| v <= hi) {
+                push(float(&field.ty, v));
+            }
+        }
+        if lo.is_none() && hi.is_none() {
+            let tiny = if field.ty == Type::F32 {
+                f32::from_bits(1).into()
+            } else {
+                f64::from_bits(1)
+            };
+            for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0, tiny] {
+                push(float(&field.ty, v));
+            }
+        }
+    }
+    out
+}
+
+/// 整数字段在声明范围内的全部取值；不是整数字段或取值多于 `limit` 个时为 `None`
+pub fn domain(field: &Field, limit: usize) -> Option<Vec<Value>> {
+    if !field.ty.is_integer() {
+        return None;
+    }
+    let (lo, hi) = int_range(field);
+    let span = hi.checked_sub(lo)?;
+    if !(0..limit as i128).contains(&span) {
+        return None;
+    }
+    Some((lo..=hi).map(Value::Int).collect())
+}
+
+/// 按布局生成 instruction_data 的确定性伪随机生成器
+///
+/// 数值字段一半取 [`candidates`] 中的边界值，一半在声明范围内均匀取值；
+/// 生成的数据总能被同一布局完整解码。
+#[derive(Clone, Debug)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator { state: seed }
+    }
+
+    /// splitmix64
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        u128::from(self.next_u64()) << 64 | u128::from(self.next_u64())
+    }
+
+    /// `lo..=hi` 中均匀取值
+    fn between(&mut self, lo: u128, hi: u128) -> u128 {
+        let span = hi - lo;
+        let wide = self.next_u128();
+        lo + if span == u128::MAX {
+            wide
+        } else {
+            wide % (span + 1)
+        }
+    }
+
+    /// 从全部布局中随机选一种生成
+    pub fn any(&mut self, schemas: &[InstructionSchema]) -> Option<Vec<u8>> {
+        if schemas.is_empty() {
+            return None;
+        }
+        let schema = &schemas[self.below(schemas.len())];
+        Some(self.instruction(schema))
+    }
+
+    pub fn instruction(&mut self, schema: &InstructionSchema) -> Vec<u8> {
+        let values = self.fields(&schema.fields);
+        encode_instruction(schema, &values).expect("generated values match their fields")
+    }
+
+    pub fn fields(&mut self, fields: &[Field]) -> Vec<Value> {
+        fields.iter().map(|field| self.field(field)).collect()
+    }
+
+    pub fn field(&mut self, field: &Field) -> Value {
+        let candidates = candidates(field);
+        if !candidates.is_empty() && self.coin() {
+            return candidates[self.below(candidates.len())].clone();
+        }
+        if field.ty.is_integer() {
+            let (lo, hi) = int_range(field);
+            if wide_u128(field) {
+                // u128 的下界不小于 0
+                return Value::unsigned(self.between(lo as u128, u128::MAX));
+            }
+            if lo >= hi {
+                return Value::Int(lo);
+            }
+            let offset = self.between(0, hi.abs_diff(lo));
+            return Value::Int(lo.wrapping_add(offset as i128));
+        }
+        if field.ty.is_float() {
+            let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+            let lo = field.min.map_or(-1e6, Bound::as_f64);
+            let hi = field.max.map_or(1e6, Bound::as_f64);
+            return float(&field.ty, lo + (hi - lo) * unit);
+        }
+        self.value(&field.ty)
+    }
+
+    fn value(&mut self, ty: &Type) -> Value {
+        match ty {
+            Type::Bool => Value::Bool(self.coin()),
+            Type::Pubkey => {
+                let mut bytes = [0u8; 32];
+                for chunk in bytes.chunks_mut(8) {
+                    chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+                }
+                Value::Pubkey(Pubkey::new_from_array(bytes))
+            }
+            Type::String | Type::Str => {
+                let len = self.below(MAX_LEN + 1);
+                Value::Str(
+                    (0..len)
+                        .map(|_| char::from(b'a' + self.below(26) as u8))
+                        .collect(),
+                )
+            }
+            Type::Rest => {
+                let len = self.below(MAX_LEN + 1);
+                Value::Bytes((0..len).map(|_| self.next_u64() as u8).collect())
+            }
+            Type::Array(inner, len) if **inner == Type::U8 => {
+                Value::Bytes((0..*len).map(|_| self.next_u64() as u8).collect())
+            }
+            Type::Vec(inner) => {
+                let len = self.below(MAX_LEN + 1);
+                Value::List((0..len).map(|_| self.value(inner)).collect())
+            }
+            Type::Option(inner) => Value::Option(self.coin().then(|| Box::new(self.value(inner)))),
+            Type::Array(inner, len) => Value::List((0..*len).map(|_| self.value(inner)).collect()),
+            Type::Tuple(types) => Value::List(types.iter().map(|ty| self.value(ty)).collect()),
+            // 数组和元组里的数值没有单独的范围
+            _ => self.field(&Field {
+                name: String::new(),
+                ty: ty.clone(),
+                min: None,
+                max: None,
+                asset: None,
+            }),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+use solracebench_manifest::{Layout, Repr};
+
+use crate::{codec::decode_value, decode_prefix, Error, Value};
+
+/// 按布局解码出的账户数据
+#[derive(Clone, Debug, PartialEq)]
+pub struct View<'a> {
+    pub layout: &'a Layout,
+    /// 能解码的前若干个字段
+    pub fields: Vec<(String, Value)>,
+    /// 没能解码全部字段的原因，例如账户还没有初始化、数据太短
+    pub error: Option<Error>,
+}
+
+impl View<'_> {
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.fields.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+}
+
+impl fmt::Display for View<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.layout.name)?;
+        if !self.fields.is_empty() {
+            f.write_str(" {")?;
+            for (i, (name, value)) in self.fields.iter().enumerate() {
+                let sep = if i == 0 { " " } else { ", " };
+                write!(f, "{sep}{name}: {value}")?;
+            }
+            f.write_str(" }")?;
+        }
+        if let Some(err) = &self.error {
+            write!(f, " ({err})")?;
+        }
+        Ok(())
+    }
+}
+
+/// 每个字段在账户数据中的起始字节；borsh 布局里有变长字段时没有固定位置
+pub fn offsets(layout: &Layout) -> Option<Vec<usize>> {
+    let mut offsets = Vec::with_capacity(layout.fields.len());
+    let mut pos = 0usize;
+    for field in &layout.fields {
+        if layout.repr == Repr::C {
+            pos = pos.next_multiple_of(field.ty.align()?);
+        }
+        offsets.push(pos);
+        pos += field.ty.size()?;
+    }
+    Some(offsets)
+}
+
+/// 解码账户数据开头的结构体，结构体之后多出的字节不检查
+pub fn decode_account<'a>(layout: &'a Layout, data: &[u8]) -> View<'a> {
+    let (fields, error) = match layout.repr {
+        Repr::Borsh => decode_prefix(&layout.fields, data),
+        Repr::C => decode_c(layout, data),
+    };
+    View {
+        layout,
+        fields,
+        error,
+    }
+}
+
+/// 按 C 的对齐规则逐个读出字段，和 `ptr::read_unaligned` 读到的一样
+fn decode_c(layout: &Layout, data: &[u8]) -> (Vec<(String, Value)>, Option<Error>) {
+    let Some(offsets) = offsets(layout) else {
+        return (Vec::new(), Some(Error::NoMatch));
+    };
+    let mut values = Vec::with_capacity(layout.fields.len());
+    for (field, offset) in layout.fields.iter().zip(offsets) {
+        // 清单校验保证 C 布局只有定长字段
+        let size = field.ty.size().unwrap_or(0);
+        let bytes = data.get(offset..offset + size).unwrap_or(&[]);
+        match decode_value(&field.ty, &field.name, bytes) {
+            Ok(value) => values.push((field.name.clone(), value)),
+            Err(err) => return (values, Some(err)),
+        }
+    }
+    (values, None)
+}
+
+/// 一个字段前后两次解码的值；字段没能解码的一边为 `None`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "?".to_owned(),
+        };
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.field,
+            show(&self.before),
+            show(&self.after)
+        )
+    }
+}
+
+/// 值不同的字段，按布局中的顺序排列；浮点按位比较
+pub fn changes(before: &View, after: &View) -> Vec<Change> {
+    before
+        .layout
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let (a, b) = (before.get(&field.name), after.get(&field.name));
+            let same = match (a, b) {
+                (Some(a), Some(b)) => a.same(b),
+                (a, b) => a.is_none() && b.is_none(),
+            };
+            (!same).then(|| Change {
+                field: field.name.clone(),
+                before: a.cloned(),
+                after: b.cloned(),
+            })
+        })
+        .collect()
+}
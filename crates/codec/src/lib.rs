@@ -0,0 +1,19 @@
+//! 按清单里的布局描述解码、编码和生成字节。
+//!
+//! 结构感知的 fuzzer 可以直接用 [`Generator`] 生成合法的 instruction_data，
+//! 或者用 [`candidates`] 枚举数值字段的边界值，用 [`domain`] 枚举小范围整数字段的全部取值。
+//! 账户数据用 [`decode_account`] 解码成按字段名排列的 [`View`]，
+//! [`changes`] 列出两次解码之间变化的字段。
+
+mod codec;
+mod generate;
+mod layout;
+mod value;
+
+pub use codec::{
+    decode, decode_fields, decode_instruction, decode_prefix, encode_fields, encode_instruction,
+    in_range, out_of_range, Decoded, Error,
+};
+pub use generate::{candidates, domain, Generator};
+pub use layout::{changes, decode_account, offsets, Change, View};
+pub use value::Value;
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use solana_program::pubkey::Pubkey;
+
+/// 按布局解码出来的一个值
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// 全部整数类型
+    Int(i128),
+    /// 超出 i128 的 u128；能放进 `Int` 的 u128 总是解码成 `Int`
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    Pubkey(Pubkey),
+    /// `[u8]` 和 `[u8; N]`
+    Bytes(Vec<u8>),
+    /// `String` 和 `str`
+    Str(String),
+    /// `Vec<T>`、元素不是 u8 的定长数组和元组
+    List(Vec<Value>),
+    Option(Option<Box<Value>>),
+}
+
+impl Value {
+    /// 无符号整数，只有超出 i128 时才用 `U128`
+    pub fn unsigned(v: u128) -> Value {
+        i128::try_from(v).map_or(Value::U128(v), Value::Int)
+    }
+
+    /// 整数或浮点数的数值
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Int(v) => Some(v as f64),
+            Value::U128(v) => Some(v as f64),
+            Value::F32(v) => Some(v.into()),
+            Value::F64(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 放得进 i128 的整数
+    pub fn as_int(&self) -> Option<i128> {
+        match *self {
+            Value::Int(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 浮点按位比较的相等：NaN 与自身相同，0.0 与 -0.0 不同
+    pub fn same(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Value::F32(a), Value::F32(b)) => a.to_bits() == b.to_bits(),
+            (Value::F64(a), Value::F64(b)) => a.to_bits() == b.to_bits(),
+            (Value::List(a), Value::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.same(b))
+            }
+            (Value::Option(Some(a)), Value::Option(Some(b))) => a.same(b),
+            _ => self == other,
+        }
+    }
+}
+
+/// 由名字派生的公钥（名字字节右侧补零）显示为名字，其余显示为 base58
+fn fmt_pubkey(key: &Pubkey, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let bytes = key.as_ref();
+    let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    let name = &bytes[..len];
+    if !name.is_empty() && name.iter().all(|b| b.is_ascii_graphic()) {
+        // 上面已经检查过都是 ASCII
+        f.write_str(std::str::from_utf8(name).unwrap())
+    } else {
+        write!(f, "{key}")
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(v) => write!(f, "{v}"),
+            Value::U128(v) => write!(f, "{v}"),
+            Value::F32(v) => write!(f, "{v:?}"),
+            Value::F64(v) => write!(f, "{v:?}"),
+            Value::Bool(v) => write!(f, "{v}"),
+            Value::Pubkey(key) => fmt_pubkey(key, f),
+            Value::Bytes(bytes) => {
+                f.write_str("0x")?;
+                for b in bytes {
+                    write!(f, "{b:02x}")?;
+                }
+                Ok(())
+            }
+            Value::Str(s) => write!(f, "{s:?}"),
+            Value::List(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str("]")
+            }
+            Value::Option(None) => f.write_str("None"),
+            Value::Option(Some(v)) => write!(f, "Some({v})"),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use solracebench_codec::{changes, decode_account, offsets, Error, Value};
+use solracebench_manifest::{Field, Layout, Repr};
+
+fn layout(repr: Repr, fields: &[(&str, &str)]) -> Layout {
+    Layout {
+        name: "Governance".to_owned(),
+        repr,
+        fields: fields
+            .iter()
+            .map(|(name, ty)| Field {
+                name: (*name).to_owned(),
+                ty: ty.parse().unwrap(),
+                min: None,
+                max: None,
+                asset: None,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn repr_c_fields_are_aligned() {
+    let fields = [
+        ("activation_epoch", "u64"),
+        ("is_active", "bool"),
+        ("votes", "u32"),
+    ];
+    let c = layout(Repr::C, &fields);
+    let borsh = layout(Repr::Borsh, &fields);
+    assert_eq!(offsets(&c), Some(vec![0, 8, 12]));
+    assert_eq!(offsets(&borsh), Some(vec![0, 8, 9]));
+
+    let mut data = vec![0u8; 16];
+    data[..8].copy_from_slice(&5u64.to_le_bytes());
+    data[8] = 1;
+    data[12..16].copy_from_slice(&7u32.to_le_bytes());
+    let view = decode_account(&c, &data);
+    assert_eq!(view.error, None);
+    assert_eq!(view.get("votes"), Some(&Value::Int(7)));
+    assert_eq!(
+        view.to_string(),
+        "Governance { activation_epoch: 5, is_active: true, votes: 7 }"
+    );
+}
+
+#[test]
+fn short_data_decodes_a_prefix() {
+    let layout = layout(Repr::Borsh, &[("balance", "u64"), ("owner", "Pubkey")]);
+    let view = decode_account(&layout, &[1, 0, 0, 0, 0, 0, 0, 0, 9]);
+    assert_eq!(view.fields, [("balance".to_owned(), Value::Int(1))]);
+    assert_eq!(
+        view.error,
+        Some(Error::Short {
+            field: "owner".to_owned()
+        })
+    );
+}
+
+#[test]
+fn changes_list_fields_that_differ() {
+    let layout = layout(
+        Repr::Borsh,
+        &[("highest_bid", "u64"), ("price", "f64"), ("name", "String")],
+    );
+    let encode = |bid: u64, price: f64| {
+        let mut data = bid.to_le_bytes().to_vec();
+        data.extend_from_slice(&price.to_le_bytes());
+        data
+    };
+    let before = decode_account(&layout, &encode(500, f64::NAN));
+    let after = decode_account(&layout, &encode(900, f64::NAN));
+    let shown: Vec<String> = changes(&before, &after)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    // NaN 按位相同不算变化；两边都解码不出的字段也不算
+    assert_eq!(shown, ["highest_bid: 500 -> 900"]);
+}
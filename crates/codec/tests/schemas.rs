@@ -0,0 +1,138 @@
+use std::path::PathBuf;
+
+use solracebench_codec::{
+    candidates, decode, decode_fields, decode_instruction, domain, encode_fields,
+    encode_instruction, Generator, Value,
+};
+use solracebench_manifest::{load_all, Case, Field, Type};
+
+fn cases() -> Vec<Case> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_all(&root).unwrap()
+}
+
+#[test]
+fn every_trigger_step_decodes() {
+    for case in cases() {
+        assert!(!case.instructions.is_empty(), "{} has no instructions", case.id);
+        for (i, step) in case.trigger.steps.iter().enumerate() {
+            let decoded = decode(&case.instructions, step.data.as_slice())
+                .unwrap_or_else(|err| panic!("{} step {i}: {err}", case.id));
+            assert!(
+                decoded.out_of_range().is_empty(),
+                "{} step {i}: {decoded} out of range",
+                case.id
+            );
+        }
+    }
+}
+
+#[test]
+fn generated_data_round_trips() {
+    for case in cases() {
+        let mut generator = Generator::new(0);
+        for schema in &case.instructions {
+            for _ in 0..64 {
+                let data = generator.instruction(schema);
+                let fields = decode_instruction(schema, &data)
+                    .unwrap_or_else(|err| panic!("{} {}: {err}", case.id, schema.name));
+                let values: Vec<_> = fields.iter().map(|(_, v)| v.clone()).collect();
+                assert_eq!(encode_instruction(schema, &values).unwrap(), data);
+                let decoded = decode(std::slice::from_ref(schema), &data).unwrap();
+                assert!(
+                    decoded.out_of_range().is_empty(),
+                    "{}: generated {decoded}",
+                    case.id
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn types_parse_and_print() {
+    for s in [
+        "u64",
+        "[u8]",
+        "str",
+        "[f32; 3]",
+        "[[u8; 4]; 4]",
+        "Vec<String>",
+        "Option<Pubkey>",
+        "(i32, i32)",
+    ] {
+        let ty: Type = s.parse().unwrap();
+        assert_eq!(ty.to_string(), s);
+    }
+    assert_eq!("[[f32; 4]; 4]".parse::<Type>().unwrap().size(), Some(64));
+    assert!("[u64]".parse::<Type>().is_err());
+    assert!("Vec<u8".parse::<Type>().is_err());
+}
+
+#[test]
+fn candidates_stay_in_range() {
+    let field = Field {
+        name: "rate".to_owned(),
+        ty: Type::U8,
+        min: None,
+        max: Some(solracebench_manifest::Bound::Int(100)),
+        asset: None,
+    };
+    let values: Vec<String> = candidates(&field).iter().map(ToString::to_string).collect();
+    assert_eq!(values, ["0", "1", "99", "100"]);
+}
+
+#[test]
+fn domain_lists_small_integer_ranges() {
+    let field = |ty, max: Option<i64>| Field {
+        name: "guess".to_owned(),
+        ty,
+        min: None,
+        max: max.map(solracebench_manifest::Bound::Int),
+        asset: None,
+    };
+    let values: Vec<String> = domain(&field(Type::U8, Some(5)), 256)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(values, ["0", "1", "2", "3", "4", "5"]);
+    assert_eq!(domain(&field(Type::U8, None), 256).unwrap().len(), 256);
+    assert!(domain(&field(Type::U16, None), 256).is_none());
+    assert!(domain(&field(Type::U128, None), 256).is_none());
+    assert!(domain(&field(Type::F64, Some(1)), 256).is_none());
+}
+
+#[test]
+fn u128_round_trips_beyond_i128() {
+    let fields = [Field {
+        name: "shares".to_owned(),
+        ty: Type::U128,
+        min: None,
+        max: None,
+        asset: None,
+    }];
+    for v in [0, i128::MAX as u128, i128::MAX as u128 + 1, u128::MAX] {
+        let data = v.to_le_bytes();
+        let decoded = decode_fields(&fields, &data).unwrap();
+        assert_eq!(decoded[0].1, Value::unsigned(v));
+        assert_eq!(decoded[0].1.to_string(), v.to_string());
+        let mut out = Vec::new();
+        encode_fields(&fields, &[decoded[0].1.clone()], &mut out).unwrap();
+        assert_eq!(out, data);
+    }
+    assert_eq!(Value::unsigned(u128::MAX), Value::U128(u128::MAX));
+    assert_eq!(Value::unsigned(7), Value::Int(7));
+
+    let values: Vec<String> = candidates(&fields[0]).iter().map(ToString::to_string).collect();
+    assert!(values.contains(&u128::MAX.to_string()), "{values:?}");
+    let mut generator = Generator::new(0);
+    let wide = (0..64)
+        .map(|_| generator.fields(&fields).remove(0))
+        .filter(|v| matches!(v, Value::U128(_)))
+        .count();
+    assert!(wide > 0);
+}
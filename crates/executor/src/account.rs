@@ -0,0 +1,25 @@
+use solana_program::pubkey::Pubkey;
+
+/// 执行器持有的账户状态
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub executable: bool,
+    pub rent_epoch: u64,
+}
+
+impl Account {
+    pub fn new(key: Pubkey, owner: Pubkey) -> Self {
+        Account {
+            key,
+            owner,
+            lamports: 0,
+            data: Vec::new(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::{collections::HashMap, fmt};
+
+use solana_epoch_schedule::EpochSchedule;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{self, ProgramResult},
+    instruction::Instruction,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_sdk_ids::sysvar;
+
+use solracebench_testkit::{
+    clock_data, epoch_schedule_data, instructions_data, recent_blockhashes_data, rent_data,
+    slot_hashes_data, Env,
+};
+
+use crate::{input::Input, Account, Cpi};
+
+pub type Processor = fn(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+/// 指令执行失败的原因
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Error(ProgramError),
+    Panic(String),
+    UnknownProgram(Pubkey),
+    /// durable nonce 交易的 nonce 账户已经推进过（或不是 nonce 账户），交易不能落地
+    StaleNonce(Pubkey),
+    /// 同一笔交易中第几条指令失败，这条指令的修改随整笔交易一起丢弃（或者它没有执行）
+    TransactionFailed(usize),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "program error: {err}"),
+            Failure::Panic(message) => write!(f, "program {message}"),
+            Failure::UnknownProgram(id) => write!(f, "unknown program {id}"),
+            Failure::StaleNonce(account) => write!(f, "stale durable nonce in {account}"),
+            Failure::TransactionFailed(index) => {
+                write!(f, "instruction {index} of the transaction failed")
+            }
+        }
+    }
+}
+
+/// 一条指令的执行结果
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub result: Result<(), Failure>,
+    pub logs: Vec<String>,
+    pub cpis: Vec<Cpi>,
+    /// 执行后的全部账户；失败时与执行前相同
+    pub accounts: Vec<Account>,
+}
+
+impl Outcome {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.iter().find(|a| a.key == *key)
+    }
+
+    /// 依次到达的 sink，即日志中 `bench_sink!` 标记的 id
+    pub fn sinks(&self) -> Vec<&str> {
+        self.logs
+            .iter()
+            .filter_map(|line| solracebench_sink::parse(line))
+            .collect()
+    }
+}
+
+/// 执行器生成的 sysvar 账户在执行交易中第 `current` 条指令时的数据
+fn sysvar_data(
+    key: &Pubkey,
+    instructions: &[Instruction],
+    current: usize,
+    clock: &Clock,
+    schedule: &EpochSchedule,
+) -> Option<Vec<u8>> {
+    Some(match *key {
+        sysvar::clock::ID => clock_data(clock),
+        sysvar::rent::ID => rent_data(),
+        sysvar::epoch_schedule::ID => epoch_schedule_data(schedule),
+        sysvar::slot_hashes::ID => slot_hashes_data(clock.slot),
+        sysvar::instructions::ID => instructions_data(instructions, current),
+        #[allow(deprecated)]
+        sysvar::recent_blockhashes::ID => recent_blockhashes_data(clock.slot),
+        _ => return None,
+    })
+}
+
+/// 进程内的指令执行器
+///
+/// 每条指令按链上布局序列化账户后调用程序，成功时写回账户状态，
+/// 失败时丢弃修改。`Clock::get` 与 Clock sysvar 账户都返回调用方给定的时钟，
+/// `EpochSchedule::get` 与 EpochSchedule sysvar 账户都返回执行器上设置的 epoch 划分，
+/// 其余 sysvar 账户的数据在每条指令执行前按当前 slot 和指令重新生成。
+#[derive(Clone, Default)]
+pub struct Executor {
+    programs: HashMap<Pubkey, Processor>,
+    accounts: Vec<Account>,
+    epoch_schedule: EpochSchedule,
+}
+
+impl Executor {
+    pub fn new() -> Self {
+        Executor::default()
+    }
+
+    pub fn add_program(&mut self, program_id: Pubkey, processor: Processor) {
+        self.programs.insert(program_id, processor);
+    }
+
+    /// 设置 epoch 划分，缺省为 `EpochSchedule::default()`
+    pub fn set_epoch_schedule(&mut self, schedule: EpochSchedule) {
+        self.epoch_schedule = schedule;
+    }
+
+    pub fn epoch_schedule(&self) -> &EpochSchedule {
+        &self.epoch_schedule
+    }
+
+    /// 新增账户，已存在同 key 账户时替换
+    pub fn set_account(&mut self, account: Account) {
+        match self.accounts.iter_mut().find(|a| a.key == account.key) {
+            Some(slot) => *slot = account,
+            None => self.accounts.push(account),
+        }
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&Account> {
+        self.accounts.iter().find(|a| a.key == *key)
+    }
+
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    /// 执行只含 `instruction` 这一条指令的交易
+    pub fn execute(&mut self, instruction: &Instruction, clock: &Clock) -> Outcome {
+        self.invoke(std::slice::from_ref(instruction), 0, clock)
+    }
+
+    /// 原子地执行一笔含多条指令的交易，结果按指令排列
+    ///
+    /// 每条指令看到的 Instructions sysvar 都含整笔交易，当前下标是它自己的位置。
+    /// 某条指令失败时整笔交易的修改都被丢弃，其余指令的结果是
+    /// [`Failure::TransactionFailed`]，失败之后的指令不再执行。
+    pub fn execute_transaction(
+        &mut self,
+        instructions: &[Instruction],
+        clock: &Clock,
+    ) -> Vec<Outcome> {
+        let before = self.accounts.clone();
+        let mut outcomes = Vec::with_capacity(instructions.len());
+        for current in 0..instructions.len() {
+            let outcome = self.invoke(instructions, current, clock);
+            let failed = outcome.result.is_err();
+            outcomes.push(outcome);
+            if failed {
+                break;
+            }
+        }
+        let Some(failed) = outcomes.iter().position(|o| o.result.is_err()) else {
+            return outcomes;
+        };
+        self.accounts = before;
+        outcomes.resize_with(instructions.len(), || Outcome {
+            result: Ok(()),
+            logs: Vec::new(),
+            cpis: Vec::new(),
+            accounts: Vec::new(),
+        });
+        for (current, outcome) in outcomes.iter_mut().enumerate() {
+            if current != failed {
+                outcome.result = Err(Failure::TransactionFailed(failed));
+            }
+            outcome.accounts = self.accounts.clone();
+        }
+        outcomes
+    }
+
+    /// 执行交易中的第 `current` 条指令
+    fn invoke(&mut self, instructions: &[Instruction], current: usize, clock: &Clock) -> Outcome {
+        let instruction = &instructions[current];
+        for account in self.accounts.iter_mut().filter(|a| a.owner == sysvar::ID) {
+            if let Some(data) = sysvar_data(
+                &account.key,
+                instructions,
+                current,
+                clock,
+                &self.epoch_schedule,
+            ) {
+                account.data = data;
+            }
+        }
+        let Some(&processor) = self.programs.get(&instruction.program_id) else {
+            return Outcome {
+                result: Err(Failure::UnknownProgram(instruction.program_id)),
+                logs: Vec::new(),
+                cpis: Vec::new(),
+                accounts: self.accounts.clone(),
+            };
+        };
+        // 不存在的账户按运行时的做法视为空的 system 账户
+        let accounts: Vec<Account> = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                self.account(&meta.pubkey)
+                    .cloned()
+                    .unwrap_or_else(|| Account::new(meta.pubkey, Pubkey::default()))
+            })
+            .collect();
+        let mut input = Input::new(
+            &instruction.program_id,
+            &instruction.accounts,
+            &accounts,
+            &instruction.data,
+        );
+        let ptr = input.as_mut_ptr();
+        let env = Env {
+            clock: clock.clone(),
+            epoch_schedule: self.epoch_schedule.clone(),
+            ..Env::new(instruction.program_id)
+        };
+        let call = env.run(|| {
+            // SAFETY: input 按 loader 布局构造，并且在调用期间保持存活
+            let (program_id, infos, data) = unsafe { entrypoint::deserialize(ptr) };
+            processor(program_id, &infos, data)
+        });
+        let result = call.result.map_err(|failure| match failure {
+            solracebench_testkit::Failure::Error(err) => Failure::Error(err),
+            solracebench_testkit::Failure::Panic(message) => Failure::Panic(message),
+        });
+        if result.is_ok() {
+            for (i, before) in accounts.iter().enumerate() {
+                if let Some(after) = input.read_back(i, before) {
+                    self.set_account(after);
+                }
+            }
+        }
+        Outcome {
+            result,
+            logs: call.logs,
+            cpis: call.cpis,
+            accounts: self.accounts.clone(),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use std::{collections::BTreeMap, fmt, ops::Range, str::FromStr};
+
+use solana_epoch_schedule::EpochSchedule;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_sdk_ids::{native_loader, system_program, sysvar};
+use solracebench_codec::{decode_account, View};
+use solracebench_manifest::{
+    AccountSpec, Case, ClockSpec, EpochScheduleSpec, Layout, Sysvar, Trigger,
+};
+
+use crate::{
+    durable_nonce, name_key, nonce_data, price_program, stored_nonce, Account, DurableNonce,
+    Executor, Outcome, PriceFeed, Processor,
+};
+
+/// 伪造 sysvar 账户的 owner：攻击者自己部署的程序，可以往账户里写任意数据
+pub fn attacker_program() -> Pubkey {
+    name_key("attacker_program")
+}
+
+/// 攻击者部署的程序接受任何指令而什么也不做，用来在交易中放一条只是看起来像样的指令
+fn attacker_processor(_: &Pubkey, _: &[AccountInfo], _: &[u8]) -> ProgramResult {
+    Ok(())
+}
+
+pub fn clock(spec: &ClockSpec) -> Clock {
+    Clock {
+        slot: spec.slot,
+        epoch_start_timestamp: spec.epoch_start_timestamp,
+        epoch: spec.epoch,
+        leader_schedule_epoch: spec.leader_schedule_epoch,
+        unix_timestamp: spec.unix_timestamp,
+    }
+}
+
+pub fn epoch_schedule(spec: &EpochScheduleSpec) -> EpochSchedule {
+    EpochSchedule::custom(
+        spec.slots_per_epoch,
+        spec.leader_schedule_slot_offset
+            .unwrap_or(spec.slots_per_epoch),
+        spec.warmup,
+    )
+}
+
+#[derive(Debug)]
+pub enum FixtureError {
+    BadPubkey { account: String, value: String },
+    BadProgram { step: usize, value: String },
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::BadPubkey { account, value } => {
+                write!(f, "account `{account}`: invalid pubkey {value:?}")
+            }
+            FixtureError::BadProgram { step, value } => {
+                write!(f, "step {step}: invalid program {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {}
+
+/// 清单中的一条指令，账户名已解析为公钥
+#[derive(Clone, Debug)]
+pub struct ScenarioStep {
+    pub note: String,
+    pub instruction: Instruction,
+    pub clock: Clock,
+    /// 用 durable nonce 签名时代替 recent blockhash 的 nonce
+    pub nonce: Option<DurableNonce>,
+    /// 交易编号，见 `solracebench_manifest::Step::transaction`
+    pub transaction: Option<u32>,
+}
+
+impl Executor {
+    /// 把场景中的一步当作一笔交易在 `clock` 落地：带 durable nonce 时先检查并推进 nonce
+    pub fn submit(&mut self, step: &ScenarioStep, clock: &Clock) -> Outcome {
+        match &step.nonce {
+            Some(nonce) => self.execute_with_nonce(&step.instruction, clock, nonce),
+            None => self.execute(&step.instruction, clock),
+        }
+    }
+}
+
+/// 由清单 trigger 构造的执行场景
+#[derive(Clone)]
+pub struct Scenario {
+    pub program_id: Pubkey,
+    pub executor: Executor,
+    pub keys: BTreeMap<String, Pubkey>,
+    pub steps: Vec<ScenarioStep>,
+    /// 声明了数据布局的账户
+    pub layouts: BTreeMap<Pubkey, Layout>,
+}
+
+impl Scenario {
+    /// 按用例自身的 trigger 构造，程序 id 由用例目录名派生
+    pub fn new(case: &Case, processor: Processor) -> Result<Self, FixtureError> {
+        let mut scenario = Scenario::from_trigger(name_key(case.name()), processor, &case.trigger)?;
+        for name in case.trigger.accounts.keys() {
+            if let Some(layout) = case.account_layout(name) {
+                scenario.layouts.insert(scenario.keys[name], layout.clone());
+            }
+        }
+        Ok(scenario)
+    }
+
+    pub fn from_trigger(
+        program_id: Pubkey,
+        processor: Processor,
+        trigger: &Trigger,
+    ) -> Result<Self, FixtureError> {
+        let mut executor = Executor::new();
+        executor.add_program(program_id, processor);
+        executor.add_program(attacker_program(), attacker_processor);
+        if let Some(spec) = &trigger.epoch_schedule {
+            executor.set_epoch_schedule(epoch_schedule(spec));
+        }
+        let mut keys = BTreeMap::new();
+        for (name, spec) in &trigger.accounts {
+            let account = account(name, spec, &program_id)?;
+            keys.insert(name.clone(), account.key);
+            executor.set_account(account);
+        }
+        // nonce 账户的 authority 可能排在它后面，全部 key 确定后再生成数据
+        for (name, spec) in &trigger.accounts {
+            if let Some(nonce) = &spec.nonce {
+                let mut account = executor.account(&keys[name]).unwrap().clone();
+                account.data = nonce_data(&keys[&nonce.authority], &durable_nonce(nonce.slot));
+                executor.set_account(account);
+            }
+        }
+        let steps = trigger
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                let program = match &step.program {
+                    Some(value) => {
+                        pubkey("", value, &program_id).map_err(|_| FixtureError::BadProgram {
+                            step: i,
+                            value: value.clone(),
+                        })?
+                    }
+                    None => program_id,
+                };
+                Ok(ScenarioStep {
+                    note: step.note.clone(),
+                    instruction: Instruction {
+                        program_id: program,
+                        accounts: step
+                            .accounts
+                            .iter()
+                            .map(|name| {
+                                let spec = &trigger.accounts[name];
+                                AccountMeta {
+                                    pubkey: keys[name],
+                                    is_signer: spec.signer,
+                                    is_writable: spec.writable,
+                                }
+                            })
+                            .collect(),
+                        data: step.data.0.clone(),
+                    },
+                    clock: clock(&step.clock),
+                    nonce: step.nonce.as_ref().map(|name| DurableNonce {
+                        account: keys[name],
+                        nonce: stored_nonce(&executor.account(&keys[name]).unwrap().data).unwrap(),
+                    }),
+                    transaction: step.transaction,
+                })
+            })
+            .collect::<Result<_, FixtureError>>()?;
+        Ok(Scenario {
+            program_id,
+            executor,
+            keys,
+            steps,
+            layouts: BTreeMap::new(),
+        })
+    }
+
+    pub fn key(&self, name: &str) -> Pubkey {
+        self.keys[name]
+    }
+
+    pub fn account(&self, name: &str) -> Option<&Account> {
+        self.executor.account(&self.key(name))
+    }
+
+    /// 按布局解码账户的当前数据；账户不存在或没有布局时为 `None`
+    pub fn view(&self, key: &Pubkey) -> Option<View<'_>> {
+        let layout = self.layouts.get(key)?;
+        Some(decode_account(layout, &self.executor.account(key)?.data))
+    }
+
+    /// 各笔交易包含的步骤：交易编号相同的相邻步骤为一笔，没有编号的步骤单独一笔
+    pub fn transactions(&self) -> Vec<Range<usize>> {
+        let mut transactions: Vec<Range<usize>> = Vec::new();
+        for (i, step) in self.steps.iter().enumerate() {
+            match transactions.last_mut() {
+                Some(last)
+                    if step.transaction.is_some()
+                        && self.steps[last.start].transaction == step.transaction =>
+                {
+                    last.end = i + 1
+                }
+                _ => transactions.push(i..i + 1),
+            }
+        }
+        transactions
+    }
+
+    /// 按顺序执行全部交易；某一笔失败后仍继续执行后续交易，和分别提交的交易一样
+    pub fn run(&mut self) -> Vec<Outcome> {
+        self.transactions()
+            .into_iter()
+            .flat_map(|transaction| self.run_transaction(transaction))
+            .collect()
+    }
+
+    /// 与 [`Scenario::run`] 相同，但只执行第 `step` 步之前的步骤
+    ///
+    /// 被 `step` 截断的交易只提交它在 `step` 之前的那部分指令。
+    pub fn run_until(&mut self, step: usize) -> Vec<Outcome> {
+        self.transactions()
+            .into_iter()
+            .map(|transaction| transaction.start..transaction.end.min(step))
+            .filter(|transaction| !transaction.is_empty())
+            .flat_map(|transaction| self.run_transaction(transaction))
+            .collect()
+    }
+
+    /// 把 `steps` 作为一笔交易执行，结果按步骤排列
+    pub fn run_transaction(&mut self, steps: Range<usize>) -> Vec<Outcome> {
+        let steps = &self.steps[steps];
+        match steps {
+            [step] => vec![self.executor.submit(step, &step.clock)],
+            _ => {
+                let instructions: Vec<Instruction> =
+                    steps.iter().map(|step| step.instruction.clone()).collect();
+                self.executor
+                    .execute_transaction(&instructions, &steps[0].clock)
+            }
+        }
+    }
+}
+
+fn pubkey(name: &str, value: &str, program_id: &Pubkey) -> Result<Pubkey, FixtureError> {
+    match value {
+        "program" => Ok(*program_id),
+        "system" | "system_program" => Ok(system_program::ID),
+        "native_loader" => Ok(native_loader::ID),
+        "attacker" => Ok(attacker_program()),
+        _ => Pubkey::from_str(value).map_err(|_| FixtureError::BadPubkey {
+            account: name.to_owned(),
+            value: value.to_owned(),
+        }),
+    }
+}
+
+fn account(name: &str, spec: &AccountSpec, program_id: &Pubkey) -> Result<Account, FixtureError> {
+    if let Some(kind) = spec.sysvar {
+        let key = match kind {
+            Sysvar::Clock => sysvar::clock::ID,
+            Sysvar::Rent => sysvar::rent::ID,
+            Sysvar::EpochSchedule => sysvar::epoch_schedule::ID,
+            Sysvar::SlotHashes => sysvar::slot_hashes::ID,
+            Sysvar::Instructions => sysvar::instructions::ID,
+            #[allow(deprecated)]
+            Sysvar::RecentBlockhashes => sysvar::recent_blockhashes::ID,
+        };
+        // 数据在每次执行前由执行器生成
+        let mut account = Account::new(key, sysvar::ID);
+        account.lamports = 1;
+        return Ok(account);
+    }
+    let key = match &spec.key {
+        Some(key) => pubkey(name, key, program_id)?,
+        None => name_key(name),
+    };
+    let owner = if spec.nonce.is_some() {
+        system_program::ID
+    } else if spec.price.is_some() {
+        price_program()
+    } else {
+        pubkey(name, spec.owner.as_deref().unwrap_or("program"), program_id)?
+    };
+    let mut account = Account::new(key, owner);
+    account.lamports = spec.lamports;
+    account.data = match &spec.price {
+        Some(price) => PriceFeed {
+            expo: price.expo,
+            price: price.price,
+            conf: price.conf,
+            publish_time: price.publish_time,
+            publish_slot: price.slot,
+        }
+        .data(),
+        None => spec.data.0.clone(),
+    };
+    account.executable = spec.executable;
+    Ok(account)
+}
@@ -0,0 +1,112 @@
+use std::mem::size_of;
+
+use solana_program::{
+    entrypoint::{BPF_ALIGN_OF_U128, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER},
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+};
+
+use crate::Account;
+
+/// 与链上 loader 相同布局的程序输入缓冲区
+///
+/// `AccountInfo::realloc` 会改写数据前面的 data_len 并读取 key 前面的原始长度，
+/// 所以必须按真实布局序列化后交给 `entrypoint::deserialize`，不能自己拼 `AccountInfo`。
+pub(crate) struct Input {
+    // 用 u64 存储保证 8 字节对齐
+    buf: Vec<u64>,
+    len: usize,
+    regions: Vec<Option<Region>>,
+}
+
+/// 一个非重复账户在缓冲区中的字段偏移
+#[derive(Clone, Copy)]
+struct Region {
+    owner: usize,
+    lamports: usize,
+    data_len: usize,
+    data: usize,
+}
+
+impl Input {
+    pub fn new(
+        program_id: &Pubkey,
+        metas: &[AccountMeta],
+        accounts: &[Account],
+        instruction_data: &[u8],
+    ) -> Self {
+        let mut bytes = Vec::new();
+        let mut regions = Vec::with_capacity(metas.len());
+        bytes.extend_from_slice(&(metas.len() as u64).to_le_bytes());
+        for (i, (meta, account)) in metas.iter().zip(accounts).enumerate() {
+            if let Some(first) = metas[..i].iter().position(|m| m.pubkey == meta.pubkey) {
+                bytes.push(first as u8);
+                bytes.extend_from_slice(&[0; 7]);
+                regions.push(None);
+                continue;
+            }
+            bytes.push(NON_DUP_MARKER);
+            bytes.push(meta.is_signer as u8);
+            bytes.push(meta.is_writable as u8);
+            bytes.push(account.executable as u8);
+            bytes.extend_from_slice(&(account.data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(account.key.as_ref());
+            let owner = bytes.len();
+            bytes.extend_from_slice(account.owner.as_ref());
+            let lamports = bytes.len();
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            let data_len = bytes.len();
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            let data = bytes.len();
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(BPF_ALIGN_OF_U128), 0);
+            bytes.extend_from_slice(&account.rent_epoch.to_le_bytes());
+            regions.push(Some(Region {
+                owner,
+                lamports,
+                data_len,
+                data,
+            }));
+        }
+        bytes.extend_from_slice(&(instruction_data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(instruction_data);
+        bytes.extend_from_slice(program_id.as_ref());
+
+        let mut buf = vec![0u64; bytes.len().div_ceil(size_of::<u64>())];
+        // SAFETY: buf 至少有 bytes.len() 字节
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf.as_mut_ptr() as *mut u8, bytes.len());
+        }
+        Input {
+            buf,
+            len: bytes.len(),
+            regions,
+        }
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buf.as_mut_ptr() as *mut u8
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: len 不超过 buf 的字节数
+        unsafe { std::slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.len) }
+    }
+
+    /// 读回第 `index` 个账户执行后的状态，重复账户返回 `None`
+    pub fn read_back(&self, index: usize, before: &Account) -> Option<Account> {
+        let region = self.regions[index]?;
+        let bytes = self.bytes();
+        let u64_at = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+        let data_len = u64_at(region.data_len) as usize;
+        Some(Account {
+            key: before.key,
+            owner: Pubkey::new_from_array(bytes[region.owner..region.owner + 32].try_into().unwrap()),
+            lamports: u64_at(region.lamports),
+            data: bytes[region.data..region.data + data_len].to_vec(),
+            executable: before.executable,
+            rent_epoch: before.rent_epoch,
+        })
+    }
+}
@@ -0,0 +1,24 @@
+//! 不依赖验证节点，在进程内执行用例的 `process_instruction`。
+//!
+//! 账户按链上 loader 的布局序列化，sysvar 由调用方脚本给定，
+//! `msg!` 输出和 CPI 调用由 testkit 的 syscall stubs 截获到每条指令的 [`Outcome`] 中，
+//! 对 System Program 的 CPI 由本地实现直接在传入的账户上执行。
+
+mod account;
+mod executor;
+mod fixture;
+mod input;
+mod nonce;
+mod price;
+mod witness;
+
+pub use account::Account;
+pub use executor::{Executor, Failure, Outcome, Processor};
+pub use fixture::{attacker_program, clock, epoch_schedule, FixtureError, Scenario, ScenarioStep};
+pub use nonce::{durable_nonce, nonce_data, stored_nonce, DurableNonce, NONCE_DATA_LEN};
+pub use price::{price_program, PriceFeed, PRICE_DATA_LEN, PRICE_MAGIC};
+pub use solracebench_testkit::{
+    blockhash, clock_data, epoch_schedule_data, instructions_data, name_key,
+    recent_blockhashes_data, rent_data, slot_hash, slot_hashes_data, Cpi,
+};
+pub use witness::{check, result_name, Mismatch};
@@ -0,0 +1,73 @@
+use solana_program::{
+    clock::Clock,
+    hash::{hashv, Hash},
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+
+use crate::{blockhash, Executor, Failure, Outcome};
+
+/// 已初始化的 nonce 账户数据长度：`nonce::state::Versions::Current` 的 bincode 序列化
+pub const NONCE_DATA_LEN: usize = 80;
+
+/// 在第 `slot` 个 slot 推进后 nonce 账户存放的值，与运行时一样由当时的 blockhash 派生
+pub fn durable_nonce(slot: u64) -> Hash {
+    hashv(&[b"DURABLE_NONCE", blockhash(slot).as_ref()])
+}
+
+/// 已初始化的 nonce 账户数据：版本、状态、authority、durable nonce 和 `lamports_per_signature`
+pub fn nonce_data(authority: &Pubkey, nonce: &Hash) -> Vec<u8> {
+    let mut data = Vec::with_capacity(NONCE_DATA_LEN);
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(nonce.as_ref());
+    data.extend_from_slice(&5000u64.to_le_bytes());
+    data
+}
+
+/// nonce 账户当前存放的值；不是已初始化的 nonce 账户时为 `None`
+pub fn stored_nonce(data: &[u8]) -> Option<Hash> {
+    if data.len() != NONCE_DATA_LEN || data[..8] != [1, 0, 0, 0, 1, 0, 0, 0] {
+        return None;
+    }
+    Some(Hash::new_from_array(data[40..72].try_into().unwrap()))
+}
+
+/// 交易用来代替 recent blockhash 的 durable nonce：nonce 账户和签名时其中存放的值
+///
+/// 这样签名的交易不会随 blockhash 过期，只要 nonce 账户存放的值还等于签名时的值就能落地，
+/// 因此可以被扣留任意久。运行时执行前先推进 nonce，同一笔交易因而最多执行一次。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DurableNonce {
+    pub account: Pubkey,
+    pub nonce: Hash,
+}
+
+impl Executor {
+    /// 按运行时处理 durable nonce 交易的方式执行 `instruction`
+    ///
+    /// nonce 账户存放的值必须等于签名时的值，否则返回 [`Failure::StaleNonce`]。
+    /// 通过检查后先把 nonce 推进到由当前 slot 派生的值再执行指令；
+    /// 指令失败时 nonce 也已推进，和运行时收取手续费后推进 nonce 一样。
+    pub fn execute_with_nonce(
+        &mut self,
+        instruction: &Instruction,
+        clock: &Clock,
+        nonce: &DurableNonce,
+    ) -> Outcome {
+        let account = self.account(&nonce.account).cloned();
+        let Some(mut account) = account.filter(|a| stored_nonce(&a.data) == Some(nonce.nonce))
+        else {
+            return Outcome {
+                result: Err(Failure::StaleNonce(nonce.account)),
+                logs: Vec::new(),
+                cpis: Vec::new(),
+                accounts: self.accounts().to_vec(),
+            };
+        };
+        account.data[40..72].copy_from_slice(durable_nonce(clock.slot).as_ref());
+        self.set_account(account);
+        self.execute(instruction, clock)
+    }
+}
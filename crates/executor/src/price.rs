@@ -0,0 +1,56 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::name_key;
+
+/// 价格账户数据长度，见 [`PriceFeed`]
+pub const PRICE_DATA_LEN: usize = 40;
+
+/// 价格账户开头的魔数，与 Pyth 的价格账户相同
+pub const PRICE_MAGIC: u32 = 0xa1b2_c3d4;
+
+/// 价格账户的 owner：代替 Pyth 程序的占位公钥
+pub fn price_program() -> Pubkey {
+    name_key("price_program")
+}
+
+/// Pyth 风格的价格账户，按 `#[repr(C)]` 排列：
+/// `magic: u32, expo: i32, price: i64, conf: u64, publish_time: i64, publish_slot: u64`
+///
+/// 价格为 `price × 10^expo`；`publish_time` 是发布者给出的 unix 时间戳，
+/// `publish_slot` 是价格写入链上时的 slot。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeed {
+    pub expo: i32,
+    pub price: i64,
+    pub conf: u64,
+    pub publish_time: i64,
+    pub publish_slot: u64,
+}
+
+impl PriceFeed {
+    pub fn data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(PRICE_DATA_LEN);
+        data.extend_from_slice(&PRICE_MAGIC.to_le_bytes());
+        data.extend_from_slice(&self.expo.to_le_bytes());
+        data.extend_from_slice(&self.price.to_le_bytes());
+        data.extend_from_slice(&self.conf.to_le_bytes());
+        data.extend_from_slice(&self.publish_time.to_le_bytes());
+        data.extend_from_slice(&self.publish_slot.to_le_bytes());
+        data
+    }
+
+    /// 解码价格账户数据；长度或魔数不对时为 `None`
+    pub fn from_data(data: &[u8]) -> Option<PriceFeed> {
+        if data.len() != PRICE_DATA_LEN || data[..4] != PRICE_MAGIC.to_le_bytes() {
+            return None;
+        }
+        let field = |offset: usize| -> [u8; 8] { data[offset..offset + 8].try_into().unwrap() };
+        Some(PriceFeed {
+            expo: i32::from_le_bytes(data[4..8].try_into().unwrap()),
+            price: i64::from_le_bytes(field(8)),
+            conf: u64::from_le_bytes(field(16)),
+            publish_time: i64::from_le_bytes(field(24)),
+            publish_slot: u64::from_le_bytes(field(32)),
+        })
+    }
+}
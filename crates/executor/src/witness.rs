@@ -0,0 +1,194 @@
+use std::{fmt, str::FromStr};
+
+use solana_program::pubkey::Pubkey;
+use solracebench_codec::{offsets, Value};
+use solracebench_manifest::{Bytes, Expect};
+
+use crate::{name_key, Failure, Outcome, Scenario};
+
+/// 没有满足的一项 witness 预期
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// 在 `Case::witness` 中的下标
+    pub index: usize,
+    pub note: String,
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "witness {} ({}): expected {}, found {}",
+            self.index, self.note, self.expected, self.found
+        )
+    }
+}
+
+/// 步骤结果在清单里的写法：`ok`、`panic`、`stale-nonce`、`transaction-failed`，
+/// 或 `ProgramError` 的变体名
+pub fn result_name(result: &Result<(), Failure>) -> String {
+    match result {
+        Ok(()) => "ok".to_owned(),
+        Err(Failure::Error(err)) => format!("{err:?}"),
+        Err(Failure::Panic(_)) => "panic".to_owned(),
+        Err(Failure::UnknownProgram(_)) => "unknown-program".to_owned(),
+        Err(Failure::StaleNonce(_)) => "stale-nonce".to_owned(),
+        Err(Failure::TransactionFailed(_)) => "transaction-failed".to_owned(),
+    }
+}
+
+/// 对照 `scenario.run()` 的结果和执行后的账户检查全部预期
+///
+/// 步骤下标越界或账户不存在时同样算作不满足，而不是 panic：
+/// 重排后的场景可能少了某些步骤。账户有布局时，不满足的数据断言按字段名报告。
+pub fn check(scenario: &Scenario, outcomes: &[Outcome], witness: &[Expect]) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    for (index, expect) in witness.iter().enumerate() {
+        let mut miss = |expected: String, found: String| {
+            mismatches.push(Mismatch {
+                index,
+                note: expect.note.clone(),
+                expected,
+                found,
+            })
+        };
+        if let Some(step) = expect.step {
+            let Some(outcome) = outcomes.get(step) else {
+                miss(format!("step {step}"), format!("{} steps", outcomes.len()));
+                continue;
+            };
+            if let Some(result) = &expect.result {
+                let found = result_name(&outcome.result);
+                let matched = match result.as_str() {
+                    "error" => matches!(outcome.result, Err(Failure::Error(_))),
+                    name => found == name,
+                };
+                if !matched {
+                    miss(format!("step {step} {result}"), found);
+                }
+            }
+            if let Some(logs) = &expect.logs {
+                if *logs != outcome.logs {
+                    miss(
+                        format!("step {step} logs {logs:?}"),
+                        format!("{:?}", outcome.logs),
+                    );
+                }
+            }
+            if let Some(sinks) = &expect.sinks {
+                if *sinks != outcome.sinks() {
+                    miss(
+                        format!("step {step} sinks {sinks:?}"),
+                        format!("{:?}", outcome.sinks()),
+                    );
+                }
+            }
+        }
+        let Some(name) = &expect.account else {
+            continue;
+        };
+        let Some((key, account)) = scenario
+            .keys
+            .get(name)
+            .and_then(|key| Some((key, scenario.executor.account(key)?)))
+        else {
+            miss(format!("account `{name}`"), "no such account".to_owned());
+            continue;
+        };
+        if let Some(lamports) = expect.lamports {
+            if account.lamports != lamports {
+                miss(
+                    format!("`{name}` lamports {lamports}"),
+                    account.lamports.to_string(),
+                );
+            }
+        }
+        let offset = expect.offset;
+        let view = scenario.view(key);
+        let mut data = |field: &str, expected: &[u8], shown: String| {
+            let found = account.data.get(offset..offset + expected.len());
+            if found == Some(expected) {
+                return;
+            }
+            // 断言恰好覆盖布局中的一个字段时，按字段名报告
+            let named = view.as_ref().and_then(|view| {
+                let layout = view.layout;
+                let at = offsets(layout)?.iter().position(|&o| o == offset)?;
+                let field = &layout.fields[at];
+                (field.ty.size() == Some(expected.len()))
+                    .then(|| (&field.name, view.get(&field.name)))
+            });
+            match (named, found) {
+                (Some((field, Some(value))), _) => {
+                    miss(format!("`{name}`.{field} = {shown}"), value.to_string())
+                }
+                (_, Some(bytes)) => miss(
+                    format!("`{name}` {field} at {offset} = {shown}"),
+                    hex(bytes),
+                ),
+                (_, None) => miss(
+                    format!("`{name}` {field} at {offset} = {shown}"),
+                    format!("{} bytes of data", account.data.len()),
+                ),
+            }
+        };
+        if let Some(bytes) = &expect.data {
+            data("data", bytes.as_slice(), bytes.to_string());
+        }
+        if let Some(value) = expect.u64 {
+            data("u64", &value.to_le_bytes(), value.to_string());
+        }
+        if let Some(value) = expect.i64 {
+            data("i64", &value.to_le_bytes(), value.to_string());
+        }
+        for (field, expected) in &expect.fields {
+            let found = view.as_ref().and_then(|view| view.get(field));
+            if !found.is_some_and(|value| matches(scenario, value, expected)) {
+                let found = match (found, &view) {
+                    (Some(value), _) => value.to_string(),
+                    (None, Some(view)) => view.to_string(),
+                    (None, None) => "no layout".to_owned(),
+                };
+                miss(format!("`{name}`.{field} = {expected}"), found);
+            }
+        }
+    }
+    mismatches
+}
+
+/// 解码出的值是否等于清单里写的值
+///
+/// 公钥可以写 trigger 中的账户名、base58 或派生公钥用的名字；`[u8; N]` 和 `[u8]` 写十六进制字符串；
+/// `Option` 只比较 `Some` 里的值；浮点数相等或同为 NaN。
+fn matches(scenario: &Scenario, value: &Value, expected: &toml::Value) -> bool {
+    match (value, expected) {
+        (Value::Int(v), toml::Value::Integer(e)) => *v == i128::from(*e),
+        // 按字段自身的精度比较，`0.1` 可以直接写给 f32 字段
+        (Value::F32(v), toml::Value::Float(e)) => *v == *e as f32 || (v.is_nan() && e.is_nan()),
+        (Value::F64(v), toml::Value::Float(e)) => v == e || (v.is_nan() && e.is_nan()),
+        (Value::F32(_) | Value::F64(_), toml::Value::Integer(e)) => {
+            value.as_f64() == Some(*e as f64)
+        }
+        (Value::Bool(v), toml::Value::Boolean(e)) => v == e,
+        (Value::Pubkey(key), toml::Value::String(e)) => {
+            let expected = scenario.keys.get(e).copied();
+            let expected = expected.or_else(|| Pubkey::from_str(e).ok());
+            expected.unwrap_or_else(|| name_key(e)) == *key
+        }
+        (Value::Bytes(bytes), toml::Value::String(e)) => {
+            Bytes::parse(e).is_ok_and(|e| e.as_slice() == bytes.as_slice())
+        }
+        (Value::Str(s), toml::Value::String(e)) => s == e,
+        (Value::List(items), toml::Value::Array(e)) => {
+            items.len() == e.len() && items.iter().zip(e).all(|(v, e)| matches(scenario, v, e))
+        }
+        (Value::Option(Some(v)), _) => matches(scenario, v, expected),
+        _ => false,
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
@@ -0,0 +1,178 @@
+use std::path::PathBuf;
+
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, program_error::ProgramError};
+use solracebench_executor::{Failure, Outcome, Scenario};
+use solracebench_manifest::{load_case, Case};
+
+fn case(id: &str) -> Case {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+fn scenario(id: &str) -> Scenario {
+    let case = case(id);
+    Scenario::new(&case, solracebench_cases::processor(id).unwrap()).unwrap()
+}
+
+/// 把最后一步换成给定时钟后执行全部步骤
+fn run_last_at(id: &str, edit: impl FnOnce(&mut Clock)) -> (Scenario, Vec<Outcome>) {
+    let mut scenario = scenario(id);
+    edit(&mut scenario.steps.last_mut().unwrap().clock);
+    let outcomes = scenario.run();
+    (scenario, outcomes)
+}
+
+fn u64_at(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[test]
+fn clock_get_cases_follow_the_scripted_clock() {
+    let cases = [
+        "ccd/slot1",
+        "ccd/slot2",
+        "ccd/timestamp3",
+        "ccd/timestamp4",
+        "ccd/timestamp5",
+        "ccd/timestamp6",
+        "ccd/timestamp7",
+        "ccd/timestamp8",
+        "ccd/timestamp9",
+        "ccd/timestamp13",
+        "ccd/epoch12",
+        "ccd/epoch16",
+    ];
+    for id in cases {
+        let mut triggered = scenario(id);
+        let outcomes = triggered.run();
+        assert!(outcomes.iter().all(Outcome::is_ok), "{id}: {outcomes:?}");
+
+        let (quiet, baseline) = run_last_at(id, |clock| {
+            *clock = Clock {
+                slot: 1,
+                epoch_start_timestamp: 1,
+                epoch: 1,
+                leader_schedule_epoch: 1,
+                unix_timestamp: 1,
+            }
+        });
+        let differs = outcomes.last().unwrap().result != baseline.last().unwrap().result
+            || outcomes.last().unwrap().logs != baseline.last().unwrap().logs
+            || triggered.executor.accounts() != quiet.executor.accounts();
+        assert!(differs, "{id} does not react to the clock");
+    }
+}
+
+#[test]
+fn slot2_zeroes_the_vault_from_slot_5000() {
+    let (scenario, outcomes) = run_last_at("ccd/slot2", |_| {});
+    assert!(outcomes[0].is_ok());
+    assert_eq!(scenario.account("vault").unwrap().lamports, 0);
+
+    let (scenario, _) = run_last_at("ccd/slot2", |clock| clock.slot = 4999);
+    assert_eq!(scenario.account("vault").unwrap().lamports, 1_000_000);
+}
+
+#[test]
+fn ccd21_checks_the_reported_slot_before_transferring() {
+    let (scenario, outcomes) = run_last_at("ccd/ccd21", |_| {});
+    assert!(outcomes[0].is_ok());
+    assert!(outcomes[1].is_ok(), "{:?}", outcomes[1].result);
+    assert_eq!(scenario.account("auction").unwrap().lamports, 500);
+    assert_eq!(u64_at(&scenario.account("auction").unwrap().data, 8), 500);
+
+    let (scenario, outcomes) = run_last_at("ccd/ccd21", |clock| clock.slot = 101);
+    assert_eq!(
+        outcomes[1].result,
+        Err(Failure::Error(ProgramError::InvalidInstructionData))
+    );
+    assert_eq!(outcomes[1].logs, ["Auction already ended"]);
+    assert_eq!(u64_at(&scenario.account("auction").unwrap().data, 0), 100);
+}
+
+#[test]
+fn ccd22_compares_unix_timestamp_with_end_time() {
+    let (scenario, outcomes) = run_last_at("ccd/ccd22", |_| {});
+    assert!(outcomes.iter().all(Outcome::is_ok), "{outcomes:?}");
+    assert_eq!(u64_at(&scenario.account("auction").unwrap().data, 8), 500);
+
+    let (_, outcomes) = run_last_at("ccd/ccd22", |clock| clock.unix_timestamp += 1);
+    assert_eq!(
+        outcomes[1].result,
+        Err(Failure::Error(ProgramError::InvalidArgument))
+    );
+}
+
+#[test]
+fn ccd28_lends_within_sixty_seconds() {
+    let (scenario, outcomes) = run_last_at("ccd/ccd28", |_| {});
+    assert_eq!(outcomes[1].sinks(), ["ccd28"]);
+    assert!(outcomes[1].logs[1].starts_with("Borrowed 1000"));
+    assert_eq!(u64_at(&scenario.account("pool").unwrap().data, 8), 1_000_000 - 1000);
+
+    let (scenario, _) = run_last_at("ccd/ccd28", |clock| clock.unix_timestamp += 1);
+    assert_eq!(u64_at(&scenario.account("pool").unwrap().data, 8), 1_000_000);
+}
+
+#[test]
+fn ccd30_compounds_after_a_hundred_slots() {
+    let (scenario, _) = run_last_at("ccd/ccd30", |_| {});
+    assert_eq!(u64_at(&scenario.account("account").unwrap().data, 0), 101);
+
+    let (scenario, _) = run_last_at("ccd/ccd30", |clock| clock.slot = 100);
+    assert_eq!(u64_at(&scenario.account("account").unwrap().data, 0), 0);
+}
+
+#[test]
+fn ccd31_activates_on_leader_schedule_epoch() {
+    let (scenario, _) = run_last_at("ccd/ccd31", |_| {});
+    assert_eq!(scenario.account("governance").unwrap().data[8], 1);
+
+    let (scenario, _) = run_last_at("ccd/ccd31", |clock| clock.leader_schedule_epoch = 4);
+    assert_eq!(scenario.account("governance").unwrap().data[8], 0);
+}
+
+#[test]
+fn panics_are_reported_as_failures() {
+    // ccd27 的到期分支写 data[16..24]，超出 16 字节的账户数据
+    let (_, outcomes) = run_last_at("ccd/ccd27", |_| {});
+    assert!(outcomes[0].is_ok());
+    assert!(
+        matches!(&outcomes[1].result, Err(Failure::Panic(message)) if message.contains("out of range")),
+        "{:?}",
+        outcomes[1].result
+    );
+}
+
+/// EpochSchedule sysvar 账户和 `EpochSchedule::get` 都按 trigger 声明的 epoch 划分返回
+#[test]
+fn epoch_schedule_follows_the_trigger() {
+    for (id, log) in [
+        ("epoch/vesting1_safe", "Released 1000 in epoch"),
+        ("epoch/tips3_safe", "Paid 2000 for epoch"),
+    ] {
+        let mut scripted = scenario(id);
+        let schedule = scripted.executor.epoch_schedule().clone();
+        assert_ne!(schedule, EpochSchedule::default(), "{id}");
+        let slot = scripted.steps[0].clock.slot;
+        let outcomes = scripted.run();
+        assert_eq!(
+            outcomes[0].logs,
+            [format!("{log} {}", schedule.get_epoch(slot))],
+            "{id}"
+        );
+
+        let schedule = EpochSchedule::default();
+        let mut unscripted = scenario(id);
+        unscripted.executor.set_epoch_schedule(schedule.clone());
+        let outcomes = unscripted.run();
+        assert_eq!(
+            outcomes[0].logs,
+            [format!("{log} {}", schedule.get_epoch(slot))],
+            "{id}"
+        );
+    }
+}
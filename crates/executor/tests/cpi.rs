@@ -0,0 +1,247 @@
+use std::path::PathBuf;
+
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    instruction::AccountMeta,
+    instruction::Instruction,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solracebench_executor::{name_key, Account, Executor, Failure, Scenario};
+use solracebench_manifest::load_case;
+use solracebench_testkit::{Env, TestAccount};
+
+fn pay(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let lamports = u64::from_le_bytes(data.try_into().unwrap());
+    msg!("paying {}", lamports);
+    invoke(
+        &transfer(accounts[0].key, accounts[1].key, lamports),
+        &accounts[..2],
+    )
+}
+
+fn transfer(from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+    let mut data = 2u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&lamports.to_le_bytes());
+    Instruction {
+        program_id: solana_sdk_ids::system_program::ID,
+        accounts: vec![AccountMeta::new(*from, true), AccountMeta::new(*to, false)],
+        data,
+    }
+}
+
+#[test]
+fn cpi_and_logs_are_recorded() {
+    let program_id = name_key("pay");
+    let (from, to) = (name_key("from"), name_key("to"));
+    let mut executor = Executor::new();
+    executor.add_program(program_id, pay);
+    let mut payer = Account::new(from, solana_sdk_ids::system_program::ID);
+    payer.lamports = 10;
+    executor.set_account(payer);
+
+    let outcome = executor.execute(
+        &Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new(from, true), AccountMeta::new(to, false)],
+            data: 7u64.to_le_bytes().to_vec(),
+        },
+        &Clock::default(),
+    );
+    assert!(outcome.is_ok(), "{:?}", outcome.result);
+    assert_eq!(outcome.logs, ["paying 7"]);
+    assert_eq!(outcome.cpis[0].instruction, transfer(&from, &to, 7));
+    assert_eq!(outcome.cpis[0].result, Ok(()));
+    // 不存在的账户按空账户传入，收到转账后被记录下来
+    assert_eq!(outcome.account(&from).unwrap().lamports, 3);
+    assert_eq!(outcome.account(&to).unwrap().lamports, 7);
+}
+
+#[test]
+fn failed_transfers_leave_balances_unchanged() {
+    let program_id = name_key("pay");
+    let (from, to) = (name_key("from"), name_key("to"));
+    let mut executor = Executor::new();
+    executor.add_program(program_id, pay);
+    let mut payer = Account::new(from, solana_sdk_ids::system_program::ID);
+    payer.lamports = 10;
+    executor.set_account(payer);
+    let pay_with = |signer: bool, lamports: u64| Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(from, signer), AccountMeta::new(to, false)],
+        data: lamports.to_le_bytes().to_vec(),
+    };
+
+    // SystemError::ResultWithNegativeLamports
+    let outcome = executor.execute(&pay_with(true, 11), &Clock::default());
+    assert_eq!(outcome.result, Err(Failure::Error(ProgramError::Custom(1))));
+    assert_eq!(outcome.cpis[0].result, Err(ProgramError::Custom(1)));
+    // 调用方没有 from 的签名，不能在 CPI 中声明它签名
+    let outcome = executor.execute(&pay_with(false, 1), &Clock::default());
+    assert_eq!(
+        outcome.result,
+        Err(Failure::Error(ProgramError::MissingRequiredSignature))
+    );
+    assert_eq!(executor.account(&from).unwrap().lamports, 10);
+    assert!(executor.account(&to).is_none());
+    // 收款方余额溢出时转账失败，而不是让执行器 panic
+    let mut payee = Account::new(to, solana_sdk_ids::system_program::ID);
+    payee.lamports = u64::MAX;
+    executor.set_account(payee);
+    let outcome = executor.execute(&pay_with(true, 1), &Clock::default());
+    assert_eq!(
+        outcome.result,
+        Err(Failure::Error(ProgramError::ArithmeticOverflow))
+    );
+    assert_eq!(executor.account(&from).unwrap().lamports, 10);
+    assert_eq!(executor.account(&to).unwrap().lamports, u64::MAX);
+}
+
+#[test]
+fn self_transfers_keep_the_balance() {
+    let program_id = name_key("pay");
+    let from = name_key("from");
+    let mut executor = Executor::new();
+    executor.add_program(program_id, pay);
+    let mut payer = Account::new(from, solana_sdk_ids::system_program::ID);
+    payer.lamports = 10;
+    executor.set_account(payer);
+
+    let outcome = executor.execute(
+        &Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new(from, true), AccountMeta::new(from, true)],
+            data: 7u64.to_le_bytes().to_vec(),
+        },
+        &Clock::default(),
+    );
+    assert!(outcome.is_ok(), "{:?}", outcome.result);
+    assert_eq!(outcome.cpis[0].result, Ok(()));
+    assert_eq!(executor.account(&from).unwrap().lamports, 10);
+    // 超过余额的自转账照样失败
+    let outcome = executor.execute(
+        &Instruction {
+            program_id,
+            accounts: vec![AccountMeta::new(from, true), AccountMeta::new(from, true)],
+            data: 11u64.to_le_bytes().to_vec(),
+        },
+        &Clock::default(),
+    );
+    assert_eq!(outcome.result, Err(Failure::Error(ProgramError::Custom(1))));
+    assert_eq!(executor.account(&from).unwrap().lamports, 10);
+}
+
+/// 执行器经由 testkit 的 syscall stubs 执行，两者在同一线程上交替使用时各自截获自己的日志和 CPI
+#[test]
+fn executor_and_testkit_share_the_stubs() {
+    let program_id = name_key("pay");
+    let system = solana_sdk_ids::system_program::ID;
+    let mut accounts = [
+        TestAccount::new(name_key("from"), system)
+            .with_lamports(10)
+            .signer(),
+        TestAccount::new(name_key("to"), system),
+    ];
+    let call = Env::new(program_id).call(pay, &mut accounts, &4u64.to_le_bytes());
+    call.assert_ok().assert_logged("paying 4");
+    assert_eq!(call.cpis[0].result, Ok(()));
+    accounts[0].assert_lamports(6);
+    accounts[1].assert_lamports(4);
+
+    let mut executor = Executor::new();
+    executor.add_program(program_id, pay);
+    let mut payer = Account::new(name_key("from"), system);
+    payer.lamports = 10;
+    executor.set_account(payer);
+    let outcome = executor.execute(
+        &Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(name_key("from"), true),
+                AccountMeta::new(name_key("to"), false),
+            ],
+            data: 7u64.to_le_bytes().to_vec(),
+        },
+        &Clock::default(),
+    );
+    assert_eq!(outcome.logs, ["paying 7"]);
+    assert_eq!(call.logs, ["paying 4"]);
+}
+
+/// 用 PDA 签名创建一个 16 字节、归本程序所有的账户
+fn open(program_id: &Pubkey, accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let (vault, bump) = Pubkey::find_program_address(&[b"vault"], program_id);
+    let mut data = 0u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&5u64.to_le_bytes());
+    data.extend_from_slice(&16u64.to_le_bytes());
+    data.extend_from_slice(program_id.as_ref());
+    let create = Instruction {
+        program_id: solana_sdk_ids::system_program::ID,
+        accounts: vec![
+            AccountMeta::new(*accounts[0].key, true),
+            AccountMeta::new(vault, true),
+        ],
+        data,
+    };
+    invoke_signed(&create, &accounts[..2], &[&[b"vault", &[bump]]])
+}
+
+#[test]
+fn pda_signers_can_create_accounts() {
+    let program_id = name_key("open");
+    let payer = name_key("payer");
+    let (vault, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+    let mut executor = Executor::new();
+    executor.add_program(program_id, open);
+    let mut account = Account::new(payer, solana_sdk_ids::system_program::ID);
+    account.lamports = 10;
+    executor.set_account(account);
+
+    let outcome = executor.execute(
+        &Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(vault, false),
+            ],
+            data: Vec::new(),
+        },
+        &Clock::default(),
+    );
+    assert!(outcome.is_ok(), "{:?}", outcome.result);
+    let created = outcome.account(&vault).unwrap();
+    assert_eq!(created.owner, program_id);
+    assert_eq!(created.lamports, 5);
+    assert_eq!(created.data, [0; 16]);
+    assert_eq!(outcome.account(&payer).unwrap().lamports, 5);
+    assert_eq!(outcome.cpis[0].signers_seeds.len(), 1);
+}
+
+/// ccd21_safe 中截止之前的出价经 System Program 真正转入拍卖账户
+#[test]
+fn ccd21_safe_bids_are_escrowed() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    let case = load_case(&root, &root.join("ccd/ccd21_safe")).unwrap();
+    let processor = solracebench_cases::processor(&case.id).unwrap();
+    let mut scenario = Scenario::new(&case, processor).unwrap();
+    scenario.steps[1].clock.slot = 10;
+    let outcomes = scenario.run();
+    assert!(outcomes[1].is_ok(), "{:?}", outcomes[1].result);
+
+    assert_eq!(outcomes[1].cpis.len(), 1);
+    assert_eq!(outcomes[1].cpis[0].result, Ok(()));
+    let auction = scenario.account("auction").unwrap();
+    assert_eq!(auction.lamports, 500);
+    assert_eq!(auction.data[8..16], 500u64.to_le_bytes());
+    assert_eq!(
+        scenario.account("bidder").unwrap().lamports,
+        1_000_000_000 - 500
+    );
+}
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::load_all;
+
+/// 执行完 trigger 后，每个声明了布局的账户都能完整解码
+#[test]
+fn layouts_decode_accounts_after_trigger() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    let mut failures = Vec::new();
+    for case in load_all(&root).unwrap() {
+        let processor = solracebench_cases::processor(&case.id).unwrap();
+        let mut scenario = Scenario::new(&case, processor).unwrap();
+        scenario.run();
+        for (name, key) in &scenario.keys {
+            if let Some(view) = scenario.view(key) {
+                if view.error.is_some() {
+                    failures.push(format!("{} `{name}`: {view}", case.id));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{failures:#?}");
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use solracebench_executor::{durable_nonce, stored_nonce, Failure, Scenario};
+use solracebench_manifest::load_case;
+
+fn scenario(id: &str) -> Scenario {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    let case = load_case(&root, &root.join(id)).unwrap();
+    Scenario::new(&case, solracebench_cases::processor(id).unwrap()).unwrap()
+}
+
+/// nonce 账户按签名时的值初始化，落地时推进到当前 slot 派生的值，同一笔交易不能再次落地
+#[test]
+fn durable_nonce_transactions_land_once() {
+    let mut scenario = scenario("nonce/unstake3");
+    let account = scenario.key("victim_nonce");
+    let stored =
+        |scenario: &Scenario| stored_nonce(&scenario.account("victim_nonce").unwrap().data);
+    assert_eq!(stored(&scenario), Some(durable_nonce(1000)));
+
+    let outcomes = scenario.run();
+    assert_eq!(outcomes[0].result, Ok(()));
+    assert_eq!(stored(&scenario), Some(durable_nonce(2500)));
+    let lamports = scenario.account("victim").unwrap().lamports;
+
+    let step = scenario.steps[0].clone();
+    let replay = scenario.executor.submit(&step, &step.clock);
+    assert_eq!(replay.result, Err(Failure::StaleNonce(account)));
+    assert_eq!(scenario.account("victim").unwrap().lamports, lamports);
+    assert_eq!(stored(&scenario), Some(durable_nonce(2500)));
+}
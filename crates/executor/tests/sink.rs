@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, Case};
+
+fn ccd_cases() -> Vec<Case> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_all(&root)
+        .unwrap()
+        .into_iter()
+        .filter(|case| case.id.starts_with("ccd/"))
+        .collect()
+}
+
+/// 按 trigger 顺序执行，返回每一步到达的 sink
+fn sinks(case: &Case) -> Vec<Vec<String>> {
+    let processor = solracebench_cases::processor(&case.id).unwrap();
+    let mut scenario = Scenario::new(case, processor).unwrap();
+    scenario
+        .run()
+        .iter()
+        .map(|outcome| outcome.sinks().into_iter().map(str::to_owned).collect())
+        .collect()
+}
+
+/// sink 的 id 是漏洞用例的目录名
+fn sink_id(case: &Case) -> &str {
+    let vulnerable = case.twin.as_deref().unwrap_or(&case.id);
+    vulnerable.strip_prefix("ccd/").unwrap()
+}
+
+#[test]
+fn vulnerable_triggers_reach_their_sink() {
+    let cases: Vec<Case> = ccd_cases()
+        .into_iter()
+        .filter(|case| case.twin.is_none())
+        .collect();
+    assert_eq!(cases.len(), 33);
+    for case in &cases {
+        let id = sink_id(case);
+        let reached = sinks(case);
+        assert!(
+            reached.iter().flatten().any(|sink| sink == id),
+            "{}: trigger never reaches the sink: {reached:?}",
+            case.id
+        );
+        assert!(
+            case.witness.iter().any(|e| e.sinks.is_some()),
+            "{}: no witness checks the sink",
+            case.id
+        );
+    }
+}
+
+#[test]
+fn every_case_marks_exactly_one_sink() {
+    for case in ccd_cases() {
+        let id = sink_id(&case);
+        let source = std::fs::read_to_string(case.dir.join("src/lib.rs")).unwrap();
+        assert_eq!(
+            source.matches("bench_sink!(").count(),
+            1,
+            "{}: expected one marker",
+            case.id
+        );
+        assert!(
+            source.contains(&format!("bench_sink!(\"{id}\")")),
+            "{}: marker should be named {id}",
+            case.id
+        );
+        for sink in sinks(&case).iter().flatten() {
+            assert_eq!(sink, id, "{}: reached a foreign sink", case.id);
+        }
+    }
+}
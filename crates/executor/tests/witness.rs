@@ -0,0 +1,167 @@
+use std::path::PathBuf;
+
+use solracebench_executor::{check, Scenario};
+use solracebench_manifest::{load_case, Case};
+
+fn case(id: &str) -> Case {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+/// 按 trigger 顺序执行，利用后果必须全部出现；tod 用例交换 race 两步后后果必须消失
+fn witness(id: &str) {
+    let case = case(id);
+    assert!(!case.witness.is_empty(), "{id} has no witness");
+    let processor = solracebench_cases::processor(id).unwrap();
+    let mut scenario = Scenario::new(&case, processor).unwrap();
+    let outcomes = scenario.run();
+    let mismatches = check(&scenario, &outcomes, &case.witness);
+    assert!(
+        mismatches.is_empty(),
+        "{id}:\n{}",
+        mismatches
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
+    for [a, b] in case.findings.iter().filter_map(|f| f.race) {
+        let mut swapped = Scenario::new(&case, processor).unwrap();
+        swapped.steps.swap(a, b);
+        let outcomes = swapped.run();
+        assert!(
+            !check(&swapped, &outcomes, &case.witness).is_empty(),
+            "{id}: witness still holds with steps {a} and {b} swapped"
+        );
+    }
+}
+
+macro_rules! witnesses {
+    ($($name:ident => $id:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                witness($id);
+            }
+        )*
+    };
+}
+
+witnesses! {
+    slot1 => "ccd/slot1",
+    slot2 => "ccd/slot2",
+    timestamp3 => "ccd/timestamp3",
+    timestamp4 => "ccd/timestamp4",
+    timestamp5 => "ccd/timestamp5",
+    timestamp6 => "ccd/timestamp6",
+    timestamp7 => "ccd/timestamp7",
+    timestamp8 => "ccd/timestamp8",
+    timestamp9 => "ccd/timestamp9",
+    epochstart10 => "ccd/epochstart10",
+    leaderscheduleepoch11 => "ccd/leaderscheduleepoch11",
+    epoch12 => "ccd/epoch12",
+    timestamp13 => "ccd/timestamp13",
+    mixslotts14 => "ccd/mixslotts14",
+    epochstart15 => "ccd/epochstart15",
+    epoch16 => "ccd/epoch16",
+    leaderscheduleepoch17 => "ccd/leaderscheduleepoch17",
+    epochstart18 => "ccd/epochstart18",
+    epoch19 => "ccd/epoch19",
+    mixslotts20 => "ccd/mixslotts20",
+    ccd21 => "ccd/ccd21",
+    ccd22 => "ccd/ccd22",
+    ccd23 => "ccd/ccd23",
+    ccd24 => "ccd/ccd24",
+    ccd25 => "ccd/ccd25",
+    ccd26 => "ccd/ccd26",
+    ccd27 => "ccd/ccd27",
+    ccd28 => "ccd/ccd28",
+    ccd29 => "ccd/ccd29",
+    ccd30 => "ccd/ccd30",
+    ccd31 => "ccd/ccd31",
+    ccd32 => "ccd/ccd32",
+    slot33 => "ccd/slot33",
+    tod1 => "tod/tod1",
+    tod2 => "tod/tod2",
+    tod3 => "tod/tod3",
+    tod4 => "tod/tod4",
+    tod5 => "tod/tod5",
+    tod6 => "tod/tod6",
+    tod7 => "tod/tod7",
+    tod8 => "tod/tod8",
+    tod9 => "tod/tod9",
+    tod10 => "tod/tod10",
+    tod11 => "tod/tod11",
+    tod12 => "tod/tod12",
+    tod13 => "tod/tod13",
+    tod14 => "tod/tod14",
+    tod15 => "tod/tod15",
+    tod16 => "tod/tod16",
+    tod17 => "tod/tod17",
+    tod18 => "tod/tod18",
+    tod19 => "tod/tod19",
+    tod20 => "tod/tod20",
+    tod21 => "tod/tod21",
+    tod22 => "tod/tod22",
+    tod23 => "tod/tod23",
+    tod24 => "tod/tod24",
+    tod25 => "tod/tod25",
+    tod26 => "tod/tod26",
+    tod27 => "tod/tod27",
+    tod28 => "tod/tod28",
+    tod29 => "tod/tod29",
+    tod30 => "tod/tod30",
+    tod31 => "tod/tod31",
+    tod32 => "tod/tod32",
+    clock1 => "sysvar/clock1",
+    clock2 => "sysvar/clock2",
+    rent3 => "sysvar/rent3",
+    epochschedule4 => "sysvar/epochschedule4",
+    slothashes5 => "sysvar/slothashes5",
+    instructions6 => "sysvar/instructions6",
+    coinflip1 => "randomness/coinflip1",
+    dice2 => "randomness/dice2",
+    slothash3 => "randomness/slothash3",
+    blockhash4 => "randomness/blockhash4",
+    signer5 => "randomness/signer5",
+    salt6 => "randomness/salt6",
+    xor7 => "randomness/xor7",
+    mix8 => "randomness/mix8",
+    raffle9 => "randomness/raffle9",
+    delayed10 => "randomness/delayed10",
+    prng11 => "randomness/prng11",
+    weighted12 => "randomness/weighted12",
+    config1 => "init/config1",
+    admin2 => "init/admin2",
+    vault3 => "init/vault3",
+    profile4 => "init/profile4",
+    escrow5 => "init/escrow5",
+    swap1 => "sandwich/swap1",
+    liquidity2 => "sandwich/liquidity2",
+    route3 => "sandwich/route3",
+    bid1 => "nonce/bid1",
+    lease2 => "nonce/lease2",
+    unstake3 => "nonce/unstake3",
+    flashloan1 => "introspection/flashloan1",
+    flashloan2 => "introspection/flashloan2",
+    flashloan3 => "introspection/flashloan3",
+    lend1 => "price/lend1",
+    liquidate2 => "price/liquidate2",
+    settle3 => "price/settle3",
+    vesting1 => "epoch/vesting1",
+    staking2 => "epoch/staking2",
+    tips3 => "epoch/tips3",
+    refund1 => "client-time/refund1",
+    voucher2 => "client-time/voucher2",
+    lock3 => "client-time/lock3",
+    grant1 => "time-arith/grant1",
+    faucet2 => "time-arith/faucet2",
+    order3 => "time-arith/order3",
+    pool1 => "float-order/pool1",
+    vault2 => "float-order/vault2",
+    fee3 => "float-order/fee3",
+}
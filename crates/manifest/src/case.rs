@@ -0,0 +1,460 @@
+use std::{collections::BTreeMap, fmt, ops::RangeInclusive, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Bytes, InstructionSchema, Layout};
+
+/// 漏洞类别
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Category {
+    /// 共识时钟依赖（consensus clock dependence）
+    Ccd,
+    /// 交易顺序依赖（transaction order dependence）
+    Tod,
+    /// 伪造的 sysvar 账户：程序读取调用方传入的账户前没有校验它的 key
+    Sysvar,
+    /// 可预测的链上随机数：中奖结果由 sysvar、签名者或账户状态等提交前已知的值算出
+    Randomness,
+    /// 账户初始化抢跑：初始化没有绑定到预期的权限，抢先初始化的一方掌握账户
+    Init,
+    /// 三明治攻击：兑换或增减流动性没有数量界限，攻击者在前后各插一笔交易套取差价
+    Sandwich,
+    /// durable nonce 延迟执行：签名的交易可以被扣留到任意晚的 slot 落地，程序却按落地时的时钟解释签名时的意图
+    Nonce,
+    /// 交易内指令顺序：程序通过 Instructions sysvar 检查同一笔交易中的其他指令，但检查不完整
+    Introspection,
+    /// 预言机价格过期：按 `unix_timestamp` 或 epoch 起点判断价格是否新鲜，leader 给出的时间戳偏慢时过期价格也能通过
+    Price,
+    /// epoch 边界：按 EpochSchedule 换算 epoch 的方式有误，交易落在 epoch 的最后一个或第一个 slot 时重复领取或漏发奖励
+    Epoch,
+    /// 调用方提供的时间：截止时间、到期时间或时长取自 instruction_data 或没有校验的账户，调用方填入任意值即可绕过时间限制
+    ClientTime,
+    /// 时间运算的符号与类型转换：时间戳转成无符号数、时长强转成有符号数或时间相减时下溢、溢出或回绕
+    TimeArith,
+    /// 浮点运算的顺序与舍入：状态保存为 f32/f64，执行顺序、舍入方向或整数转浮点的精度损失改变比较结果
+    FloatOrder,
+}
+
+impl Category {
+    pub const ALL: &'static [Category] = &[
+        Category::Ccd,
+        Category::Tod,
+        Category::Sysvar,
+        Category::Randomness,
+        Category::Init,
+        Category::Sandwich,
+        Category::Nonce,
+        Category::Introspection,
+        Category::Price,
+        Category::Epoch,
+        Category::ClientTime,
+        Category::TimeArith,
+        Category::FloatOrder,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Category::Ccd => "ccd",
+            Category::Tod => "tod",
+            Category::Sysvar => "sysvar",
+            Category::Randomness => "randomness",
+            Category::Init => "init",
+            Category::Sandwich => "sandwich",
+            Category::Nonce => "nonce",
+            Category::Introspection => "introspection",
+            Category::Price => "price",
+            Category::Epoch => "epoch",
+            Category::ClientTime => "client-time",
+            Category::TimeArith => "time-arith",
+            Category::FloatOrder => "float-order",
+        }
+    }
+
+    /// 一句英文简述，用作导出 SARIF 时的规则描述
+    pub fn title(self) -> &'static str {
+        match self {
+            Category::Ccd => "Consensus clock dependence",
+            Category::Tod => "Transaction order dependence",
+            Category::Sysvar => "Spoofed sysvar account",
+            Category::Randomness => "Predictable on-chain randomness",
+            Category::Init => "Account initialization front-running",
+            Category::Sandwich => "Sandwichable trade without slippage bounds",
+            Category::Nonce => "Durable-nonce transaction applied after its intended time",
+            Category::Introspection => "Incomplete instruction introspection",
+            Category::Price => "Oracle price freshness judged by leader timestamps",
+            Category::Epoch => "Epoch derived inconsistently at epoch boundaries",
+            Category::ClientTime => "Deadline or duration trusted from the caller",
+            Category::TimeArith => "Signedness, cast or overflow bug in time arithmetic",
+            Category::FloatOrder => "Floating-point state sensitive to operation order or rounding",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 用例标签：含漏洞的原始用例，或修复后的对照用例
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Label {
+    Vulnerable,
+    /// 修复后的对照用例：没有 findings，任何报告都是误报
+    Safe,
+}
+
+/// `Clock` sysvar 的字段，名字与 `solana_program::clock::Clock` 一致
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockField {
+    Slot,
+    EpochStartTimestamp,
+    Epoch,
+    LeaderScheduleEpoch,
+    UnixTimestamp,
+}
+
+impl ClockField {
+    pub const ALL: &'static [ClockField] = &[
+        ClockField::Slot,
+        ClockField::EpochStartTimestamp,
+        ClockField::Epoch,
+        ClockField::LeaderScheduleEpoch,
+        ClockField::UnixTimestamp,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ClockField::Slot => "slot",
+            ClockField::EpochStartTimestamp => "epoch_start_timestamp",
+            ClockField::Epoch => "epoch",
+            ClockField::LeaderScheduleEpoch => "leader_schedule_epoch",
+            ClockField::UnixTimestamp => "unix_timestamp",
+        }
+    }
+}
+
+impl fmt::Display for ClockField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 一个用例目录下 bench.toml 的内容
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Case {
+    /// 相对仓库根目录的路径，例如 `ccd/ccd21`，加载时填入
+    #[serde(skip)]
+    pub id: String,
+    /// 用例目录的绝对路径，加载时填入
+    #[serde(skip)]
+    pub dir: PathBuf,
+    pub category: Category,
+    pub label: Label,
+    pub summary: String,
+    /// 对照用例所修复的原始用例 id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub twin: Option<String>,
+    #[serde(default)]
+    pub findings: Vec<Finding>,
+    /// 程序接受的 instruction_data 布局
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instructions: Vec<InstructionSchema>,
+    /// 程序账户的数据布局，由 trigger 中的账户按名字引用
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layouts: Vec<Layout>,
+    pub trigger: Trigger,
+    /// 按 trigger 顺序执行后应当观察到的利用后果，工具也可以把 trigger 当作种子复用
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub witness: Vec<Expect>,
+}
+
+impl Case {
+    /// crate 目录名，例如 `ccd21`
+    pub fn name(&self) -> &str {
+        self.id.rsplit('/').next().unwrap_or(&self.id)
+    }
+
+    /// 按完整 id 或目录名匹配
+    pub fn matches(&self, id: &str) -> bool {
+        self.id == id || self.name() == id
+    }
+
+    pub fn layout(&self, name: &str) -> Option<&Layout> {
+        self.layouts.iter().find(|l| l.name == name)
+    }
+
+    /// trigger 中名为 `account` 的账户所用的布局
+    pub fn account_layout(&self, account: &str) -> Option<&Layout> {
+        let name = self.trigger.accounts.get(account)?.layout.as_deref()?;
+        self.layout(name)
+    }
+}
+
+/// 一处漏洞：出错的检查以及它所保护的 sink
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Finding {
+    pub class: Category,
+    /// 涉及的 Clock 字段，tod 类和不涉及 Clock 的 sysvar 类可以为空
+    #[serde(default)]
+    pub clock_fields: Vec<ClockField>,
+    pub check: Span,
+    pub sink: Span,
+    /// sink 被触发时的后果
+    pub effect: String,
+    /// tod 和 float-order 类的 ground truth：交换执行顺序后结果不同的两个 trigger 步骤下标（从 0 开始）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub race: Option<[usize; 2]>,
+}
+
+/// 源码中的一段行区间（从 1 开始，闭区间）
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Span {
+    #[serde(default = "default_file")]
+    pub file: String,
+    pub line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+    /// 起始行应包含的源码片段，用来发现行号漂移
+    pub text: String,
+}
+
+fn default_file() -> String {
+    "src/lib.rs".to_owned()
+}
+
+impl Span {
+    pub fn end(&self) -> usize {
+        self.end.unwrap_or(self.line)
+    }
+
+    pub fn lines(&self) -> RangeInclusive<usize> {
+        self.line..=self.end()
+    }
+
+    /// `line` 距区间的距离，落在区间内为 0
+    pub fn distance(&self, line: usize) -> usize {
+        if line < self.line {
+            self.line - line
+        } else {
+            line.saturating_sub(self.end())
+        }
+    }
+}
+
+/// 最小触发序列：账户初始状态加上按顺序执行的指令
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Trigger {
+    /// 前 `setup` 步只负责准备状态，重排执行顺序时保持在最前面
+    #[serde(default)]
+    pub setup: usize,
+    /// randomness 类：玩家（攻击者）提交下注或开奖交易的步骤下标，
+    /// 胜负预测 oracle 从这一步枚举玩家可以选择的提交方式
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub play: Option<usize>,
+    /// init 类：攻击者抢先发出的初始化步骤下标，
+    /// 抢跑 oracle 把这一步移到其余步骤之间的每个位置
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attack: Option<usize>,
+    /// sandwich 类：攻击者抢跑、受害者交易和攻击者尾随三步的下标，依次递增
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandwich: Option<[usize; 3]>,
+    /// 执行器使用的 epoch 划分，缺省为 `EpochSchedule::default()`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch_schedule: Option<EpochScheduleSpec>,
+    #[serde(default)]
+    pub accounts: BTreeMap<String, AccountSpec>,
+    pub steps: Vec<Step>,
+}
+
+/// 按 `EpochSchedule::custom` 构造的 epoch 划分
+///
+/// 开启 warmup 时前几个 epoch 从 32 个 slot 开始逐个翻倍，直到 `slots_per_epoch`。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EpochScheduleSpec {
+    pub slots_per_epoch: u64,
+    /// 提前多少个 slot 确定 leader schedule，缺省与 `slots_per_epoch` 相同
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leader_schedule_slot_offset: Option<u64>,
+    #[serde(default)]
+    pub warmup: bool,
+}
+
+/// 账户初始状态
+///
+/// `key` 缺省时由账户名派生：名字的 UTF-8 字节右侧补零到 32 字节。
+/// 也可以写 base58 公钥或别名 `system_program`。
+/// `owner` 可写 `program`（缺省，即被测程序）、`system`、`native_loader`、
+/// `attacker`（攻击者部署的程序，用来持有伪造的 sysvar 账户）或 base58 公钥。
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub lamports: u64,
+    #[serde(default)]
+    pub data: Bytes,
+    #[serde(default)]
+    pub signer: bool,
+    #[serde(default = "default_true")]
+    pub writable: bool,
+    #[serde(default)]
+    pub executable: bool,
+    /// sysvar 账户由执行器生成 key、owner 和数据，见 [`Sysvar`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sysvar: Option<Sysvar>,
+    /// 账户数据的布局名，见 `Case::layouts`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<String>,
+    /// durable nonce 账户由执行器生成 owner 和数据，见 [`NonceSpec`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<NonceSpec>,
+    /// Pyth 风格的价格账户由执行器生成 owner 和数据，见 [`PriceSpec`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<PriceSpec>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 执行器能生成的 sysvar 账户
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sysvar {
+    /// 当前步骤的时钟
+    Clock,
+    /// `Rent::default()`
+    Rent,
+    /// `EpochSchedule::default()`
+    EpochSchedule,
+    /// 当前 slot 之前最近 512 个 slot 的哈希，由 slot 号确定性地派生
+    SlotHashes,
+    /// 当前交易的 Instructions sysvar：含同一笔交易的全部指令，当前下标是正在执行的那一条
+    Instructions,
+    /// 当前 slot 之前最近 150 个 slot 的 blockhash，由 slot 号确定性地派生
+    RecentBlockhashes,
+}
+
+/// system 程序持有的 durable nonce 账户，存放第 `slot` 个 slot 推进后的 nonce
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NonceSpec {
+    /// 有权推进 nonce 的账户名
+    pub authority: String,
+    pub slot: u64,
+}
+
+/// 价格程序持有的 Pyth 风格价格账户：价格为 `price × 10^expo`，
+/// 由发布者在 `publish_time` 给出，在第 `slot` 个 slot 写入
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PriceSpec {
+    pub price: i64,
+    #[serde(default)]
+    pub conf: u64,
+    #[serde(default)]
+    pub expo: i32,
+    pub publish_time: i64,
+    pub slot: u64,
+}
+
+/// 一条指令
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Step {
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub accounts: Vec<String>,
+    #[serde(default)]
+    pub data: Bytes,
+    #[serde(default)]
+    pub clock: ClockSpec,
+    /// 用 durable nonce 签名时的 nonce 账户名；交易按 trigger 开始时账户里存放的值签名，
+    /// 所以同一账户的第二笔交易会因为 nonce 已推进而不能落地
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// 交易编号：编号相同的相邻步骤是同一笔交易中的多条指令，原子地执行，时钟必须相同；
+    /// 缺省时这一步单独成为一笔交易
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<u32>,
+    /// 调用的程序，写法同账户的 `owner`；缺省为被测程序
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub program: Option<String>,
+}
+
+/// 该步骤执行时 leader 给出的时钟，未写的字段为 0
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClockSpec {
+    pub slot: u64,
+    pub epoch_start_timestamp: i64,
+    pub epoch: u64,
+    pub leader_schedule_epoch: u64,
+    pub unix_timestamp: i64,
+}
+
+/// 一项利用后果：某一步的结果、日志或到达的 sink，或者某个账户执行完全部步骤后的状态
+///
+/// `step` 与 `account` 恰好写一个。数据断言从 `offset` 字节开始比较，
+/// `u64`/`i64` 按小端读取 8 字节；账户有布局时也可以用 `fields` 按字段名断言。
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expect {
+    #[serde(default)]
+    pub note: String,
+    /// trigger 步骤下标（从 0 开始）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<usize>,
+    /// `ok`、`panic`、`error`（任意 `ProgramError`），或具体的变体，例如 `InvalidArgument`、`Custom(1)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    /// 该步骤输出的全部日志
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs: Option<Vec<String>>,
+    /// 该步骤依次到达的 `bench_sink!` 标记
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sinks: Option<Vec<String>>,
+    /// trigger 中的账户名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lamports: Option<u64>,
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Bytes>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub u64: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub i64: Option<i64>,
+    /// 按账户布局解码后的字段值；公钥可以写成账户名
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, toml::Value>,
+}
+
+impl Expect {
+    pub(crate) fn checks_step(&self) -> bool {
+        self.result.is_some() || self.logs.is_some() || self.sinks.is_some()
+    }
+
+    pub(crate) fn checks_account(&self) -> bool {
+        self.lamports.is_some()
+            || self.data.is_some()
+            || self.u64.is_some()
+            || self.i64.is_some()
+            || !self.fields.is_empty()
+    }
+}
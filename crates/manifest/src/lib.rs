@@ -0,0 +1,435 @@
+//! 基准用例的 ground truth：每个用例目录下的 `bench.toml`。
+
+mod case;
+mod hex;
+mod schema;
+
+use std::{
+    error, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub use case::{
+    AccountSpec, Case, Category, ClockField, ClockSpec, EpochScheduleSpec, Expect, Finding, Label,
+    NonceSpec, PriceSpec, Span, Step, Sysvar, Trigger,
+};
+pub use hex::Bytes;
+pub use schema::{Bound, Field, InstructionSchema, Layout, Repr, Type};
+
+pub const MANIFEST_FILE: &str = "bench.toml";
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, source: toml::de::Error },
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl Error {
+    fn invalid(path: &Path, reason: impl Into<String>) -> Self {
+        Error::Invalid {
+            path: path.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Parse { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Invalid { path, reason } => write!(f, "{}: {reason}", path.display()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source),
+            Error::Invalid { .. } => None,
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// 读取 `dir/bench.toml`，`root` 用来计算用例 id
+pub fn load_case(root: &Path, dir: &Path) -> Result<Case, Error> {
+    let path = dir.join(MANIFEST_FILE);
+    let mut case: Case = toml::from_str(&read(&path)?).map_err(|source| Error::Parse {
+        path: path.clone(),
+        source,
+    })?;
+    let rel = dir
+        .strip_prefix(root)
+        .map_err(|_| Error::invalid(&path, "case directory is outside the root"))?;
+    case.id = rel
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    case.dir = dir.to_owned();
+    validate(&case, &path)?;
+    Ok(case)
+}
+
+/// 递归查找 `root` 下所有 bench.toml，按 id 排序
+pub fn load_all(root: &Path) -> Result<Vec<Case>, Error> {
+    let mut dirs = Vec::new();
+    find_cases(root, &mut dirs)?;
+    let mut cases = dirs
+        .iter()
+        .map(|dir| load_case(root, dir))
+        .collect::<Result<Vec<_>, _>>()?;
+    cases.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(cases)
+}
+
+fn find_cases(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), Error> {
+    if dir.join(MANIFEST_FILE).is_file() {
+        out.push(dir.to_owned());
+        return Ok(());
+    }
+    let entries = fs::read_dir(dir).map_err(|source| Error::Io {
+        path: dir.to_owned(),
+        source,
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(|source| Error::Io {
+            path: dir.to_owned(),
+            source,
+        })?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        // 跳过构建产物和隐藏目录
+        if name.starts_with('.') || name == "target" {
+            continue;
+        }
+        if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            find_cases(&entry.path(), out)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate(case: &Case, path: &Path) -> Result<(), Error> {
+    match case.label {
+        Label::Safe if !case.findings.is_empty() => {
+            return Err(Error::invalid(path, "safe case has findings"));
+        }
+        Label::Safe if case.twin.is_none() => {
+            return Err(Error::invalid(path, "safe case does not name its twin"));
+        }
+        Label::Vulnerable if case.twin.is_some() => {
+            return Err(Error::invalid(path, "only safe cases have a twin"));
+        }
+        _ => {}
+    }
+    for finding in &case.findings {
+        for span in [&finding.check, &finding.sink] {
+            if span.line == 0 || span.end() < span.line {
+                return Err(Error::invalid(
+                    path,
+                    format!("bad span {}..={}", span.line, span.end()),
+                ));
+            }
+        }
+    }
+    let steps = case.trigger.steps.len();
+    if case.trigger.setup > steps {
+        return Err(Error::invalid(
+            path,
+            format!("setup {} exceeds {steps} steps", case.trigger.setup),
+        ));
+    }
+    for [a, b] in case.findings.iter().filter_map(|f| f.race) {
+        let racing = case.trigger.setup..steps;
+        if a == b || !racing.contains(&a) || !racing.contains(&b) {
+            return Err(Error::invalid(
+                path,
+                format!("race [{a}, {b}] must name two distinct steps after setup"),
+            ));
+        }
+    }
+    if let Some(play) = case.trigger.play {
+        if !(case.trigger.setup..steps).contains(&play) {
+            return Err(Error::invalid(
+                path,
+                format!("play {play} must name a step after setup"),
+            ));
+        }
+    }
+    if let Some(attack) = case.trigger.attack {
+        if !(case.trigger.setup..steps).contains(&attack) {
+            return Err(Error::invalid(
+                path,
+                format!("attack {attack} must name a step after setup"),
+            ));
+        }
+    }
+    if let Some([front, victim, back]) = case.trigger.sandwich {
+        if front < case.trigger.setup || !(front < victim && victim < back && back < steps) {
+            return Err(Error::invalid(
+                path,
+                format!(
+                    "sandwich [{front}, {victim}, {back}] must name three ascending steps after setup"
+                ),
+            ));
+        }
+    }
+    if let Some(schedule) = &case.trigger.epoch_schedule {
+        // 与 `solana_epoch_schedule::MINIMUM_SLOTS_PER_EPOCH` 相同
+        if schedule.slots_per_epoch < 32 {
+            return Err(Error::invalid(
+                path,
+                format!(
+                    "epoch schedule has {} slots per epoch, fewer than 32",
+                    schedule.slots_per_epoch
+                ),
+            ));
+        }
+    }
+    for (i, step) in case.trigger.steps.iter().enumerate() {
+        if let Some(name) = step
+            .accounts
+            .iter()
+            .find(|name| !case.trigger.accounts.contains_key(*name))
+        {
+            return Err(Error::invalid(
+                path,
+                format!("step {i} uses undeclared account `{name}`"),
+            ));
+        }
+        if let Some(name) = &step.nonce {
+            if case.trigger.accounts.get(name).and_then(|a| a.nonce.as_ref()).is_none() {
+                return Err(Error::invalid(
+                    path,
+                    format!("step {i} signs with `{name}`, which is not a nonce account"),
+                ));
+            }
+        }
+    }
+    for (i, step) in case.trigger.steps.iter().enumerate() {
+        let Some(id) = step.transaction else {
+            continue;
+        };
+        let previous = i.checked_sub(1).map(|j| &case.trigger.steps[j]);
+        let reason = if step.nonce.is_some() {
+            Some("a durable nonce transaction has a single instruction")
+        } else if previous.is_some_and(|p| p.transaction == Some(id) && p.clock != step.clock) {
+            Some("steps of one transaction share a clock")
+        } else if previous.is_some_and(|p| p.transaction != Some(id))
+            && case.trigger.steps[..i].iter().any(|p| p.transaction == Some(id))
+        {
+            Some("steps of one transaction are adjacent")
+        } else {
+            None
+        };
+        if let Some(reason) = reason {
+            return Err(Error::invalid(
+                path,
+                format!("step {i} in transaction {id}: {reason}"),
+            ));
+        }
+    }
+    for (name, spec) in &case.trigger.accounts {
+        if spec.price.is_some()
+            && (spec.nonce.is_some()
+                || spec.sysvar.is_some()
+                || !spec.data.0.is_empty()
+                || spec.owner.is_some())
+        {
+            return Err(Error::invalid(
+                path,
+                format!("price account `{name}` sets its own owner or data"),
+            ));
+        }
+        if let Some(nonce) = &spec.nonce {
+            if spec.sysvar.is_some() || !spec.data.0.is_empty() || spec.owner.is_some() {
+                return Err(Error::invalid(
+                    path,
+                    format!("nonce account `{name}` sets its own owner or data"),
+                ));
+            }
+            if !case.trigger.accounts.contains_key(&nonce.authority) {
+                return Err(Error::invalid(
+                    path,
+                    format!(
+                        "nonce account `{name}` names undeclared authority `{}`",
+                        nonce.authority
+                    ),
+                ));
+            }
+        }
+    }
+    for schema in &case.instructions {
+        validate_fields(&schema.fields).map_err(|reason| {
+            Error::invalid(path, format!("instruction `{}`: {reason}", schema.name))
+        })?;
+        if let Some(field) = schema.fields.iter().find(|f| f.asset.is_some()) {
+            return Err(Error::invalid(
+                path,
+                format!(
+                    "instruction `{}`: field `{}` names an asset outside a layout",
+                    schema.name, field.name
+                ),
+            ));
+        }
+    }
+    for (i, layout) in case.layouts.iter().enumerate() {
+        if case.layouts[..i].iter().any(|l| l.name == layout.name) {
+            return Err(Error::invalid(
+                path,
+                format!("duplicate layout `{}`", layout.name),
+            ));
+        }
+        validate_layout(layout).map_err(|reason| {
+            Error::invalid(path, format!("layout `{}`: {reason}", layout.name))
+        })?;
+    }
+    for (name, spec) in &case.trigger.accounts {
+        if let Some(layout) = &spec.layout {
+            if case.layout(layout).is_none() {
+                return Err(Error::invalid(
+                    path,
+                    format!("account `{name}` uses undeclared layout `{layout}`"),
+                ));
+            }
+        }
+    }
+    for (i, expect) in case.witness.iter().enumerate() {
+        let reason = match (expect.step, &expect.account) {
+            (Some(_), Some(_)) | (None, None) => Some("must name exactly one of step and account"),
+            (Some(step), None) if step >= steps => Some("names a step past the end of the trigger"),
+            (Some(_), None) if !expect.checks_step() || expect.checks_account() => {
+                Some("step expectations check only result, logs and sinks")
+            }
+            (None, Some(name)) if !case.trigger.accounts.contains_key(name) => {
+                Some("names an undeclared account")
+            }
+            (None, Some(_)) if !expect.checks_account() || expect.checks_step() => {
+                Some("account expectations check only lamports and data")
+            }
+            (None, Some(name)) if !expect.fields.is_empty() => match case.account_layout(name) {
+                None => Some("checks fields of an account without a layout"),
+                Some(layout) if !expect.fields.keys().all(|f| layout.field(f).is_some()) => {
+                    Some("checks a field its layout does not have")
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(reason) = reason {
+            return Err(Error::invalid(path, format!("witness {i} {reason}")));
+        }
+    }
+    for name in case.trigger.accounts.keys() {
+        if name.len() > 32 {
+            return Err(Error::invalid(
+                path,
+                format!("account name `{name}` is longer than 32 bytes"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 字段名不重复；剩余字节字段只能出现在顶层，且之后只有定长字段；范围只用于数值字段，资产只用于整数字段
+fn validate_fields(fields: &[Field]) -> Result<(), String> {
+    let mut unsized_seen = false;
+    for (i, field) in fields.iter().enumerate() {
+        if fields[..i].iter().any(|f| f.name == field.name) {
+            return Err(format!("duplicate field `{}`", field.name));
+        }
+        if nested_unsized(&field.ty) {
+            return Err(format!(
+                "field `{}`: `{}` cannot be nested",
+                field.name, field.ty
+            ));
+        }
+        if field.ty.size().is_none() {
+            if unsized_seen {
+                return Err(format!(
+                    "field `{}` follows a field that takes the remaining bytes",
+                    field.name
+                ));
+            }
+            unsized_seen = field.ty.is_unsized();
+        }
+        let numeric = field.ty.is_integer() || field.ty.is_float();
+        if !numeric && (field.min.is_some() || field.max.is_some()) {
+            return Err(format!("field `{}`: range on non-numeric type", field.name));
+        }
+        if let (Some(min), Some(max)) = (field.min, field.max) {
+            if min.as_f64() > max.as_f64() {
+                return Err(format!("field `{}`: min exceeds max", field.name));
+            }
+        }
+        match field.asset.as_deref() {
+            Some(_) if !field.ty.is_integer() => {
+                return Err(format!("field `{}`: asset on non-integer type", field.name));
+            }
+            Some("lamports") => {
+                return Err(format!("field `{}`: asset `lamports` is reserved", field.name));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// C 布局只能有定长字段
+fn validate_layout(layout: &Layout) -> Result<(), String> {
+    validate_fields(&layout.fields)?;
+    if layout.repr == Repr::C {
+        if let Some(field) = layout.fields.iter().find(|f| f.ty.align().is_none()) {
+            return Err(format!(
+                "field `{}`: `{}` has no C layout",
+                field.name, field.ty
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn nested_unsized(ty: &Type) -> bool {
+    match ty {
+        Type::Vec(inner) | Type::Option(inner) | Type::Array(inner, _) => {
+            inner.is_unsized() || nested_unsized(inner)
+        }
+        Type::Tuple(types) => types.iter().any(|t| t.is_unsized() || nested_unsized(t)),
+        _ => false,
+    }
+}
+
+/// 检查每个 span 都落在真实存在的行上，且起始行包含 `text`
+pub fn verify_spans(case: &Case) -> Result<(), Error> {
+    for finding in &case.findings {
+        for span in [&finding.check, &finding.sink] {
+            let path = case.dir.join(&span.file);
+            let source = read(&path)?;
+            let lines: Vec<&str> = source.lines().collect();
+            if span.end() > lines.len() {
+                return Err(Error::invalid(
+                    &path,
+                    format!("span {}..={} past end of file", span.line, span.end()),
+                ));
+            }
+            if !lines[span.line - 1].contains(span.text.as_str()) {
+                return Err(Error::invalid(
+                    &path,
+                    format!("line {} does not contain {:?}", span.line, span.text),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
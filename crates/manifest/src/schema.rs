@@ -0,0 +1,345 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Bytes;
+
+/// 字段类型，按 Rust 类型的写法书写，例如 `u64`、`[f32; 3]`、`Option<Pubkey>`
+///
+/// 整数和浮点数一律小端；`String`、`Vec<T>`、`Option<T>` 按 borsh 编码（u32 长度前缀、
+/// u8 的 0/1 标记）。`[u8]` 和 `str` 是不带长度前缀的剩余字节，后面只能跟定长字段。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Type {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    F32,
+    F64,
+    Bool,
+    Pubkey,
+    String,
+    Vec(Box<Type>),
+    Option(Box<Type>),
+    Array(Box<Type>, usize),
+    Tuple(Vec<Type>),
+    /// `[u8]`
+    Rest,
+    /// `str`：UTF-8 的剩余字节
+    Str,
+}
+
+impl Type {
+    /// 定长类型的字节数
+    pub fn size(&self) -> Option<usize> {
+        Some(match self {
+            Type::U8 | Type::I8 | Type::Bool => 1,
+            Type::U16 | Type::I16 => 2,
+            Type::U32 | Type::I32 | Type::F32 => 4,
+            Type::U64 | Type::I64 | Type::F64 => 8,
+            Type::U128 | Type::I128 => 16,
+            Type::Pubkey => 32,
+            Type::Array(ty, len) => ty.size()? * len,
+            Type::Tuple(types) => types.iter().map(Type::size).sum::<Option<usize>>()?,
+            Type::String | Type::Vec(_) | Type::Option(_) | Type::Rest | Type::Str => return None,
+        })
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Type::U8
+                | Type::U16
+                | Type::U32
+                | Type::U64
+                | Type::U128
+                | Type::I8
+                | Type::I16
+                | Type::I32
+                | Type::I64
+                | Type::I128
+        )
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Type::F32 | Type::F64)
+    }
+
+    /// `#[repr(C)]` 下的对齐字节数；非定长类型没有 C 布局
+    pub fn align(&self) -> Option<usize> {
+        Some(match self {
+            Type::Pubkey => 1,
+            Type::Array(ty, _) => ty.align()?,
+            Type::Tuple(types) => types
+                .iter()
+                .map(Type::align)
+                .try_fold(1, |a, b| b.map(|b| a.max(b)))?,
+            _ => self.size()?,
+        })
+    }
+
+    /// 是否吃掉剩余的全部字节
+    pub fn is_unsized(&self) -> bool {
+        match self {
+            Type::Rest | Type::Str => true,
+            Type::Array(ty, _) => ty.is_unsized(),
+            Type::Tuple(types) => types.iter().any(Type::is_unsized),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::U8 => f.write_str("u8"),
+            Type::U16 => f.write_str("u16"),
+            Type::U32 => f.write_str("u32"),
+            Type::U64 => f.write_str("u64"),
+            Type::U128 => f.write_str("u128"),
+            Type::I8 => f.write_str("i8"),
+            Type::I16 => f.write_str("i16"),
+            Type::I32 => f.write_str("i32"),
+            Type::I64 => f.write_str("i64"),
+            Type::I128 => f.write_str("i128"),
+            Type::F32 => f.write_str("f32"),
+            Type::F64 => f.write_str("f64"),
+            Type::Bool => f.write_str("bool"),
+            Type::Pubkey => f.write_str("Pubkey"),
+            Type::String => f.write_str("String"),
+            Type::Vec(ty) => write!(f, "Vec<{ty}>"),
+            Type::Option(ty) => write!(f, "Option<{ty}>"),
+            Type::Array(ty, len) => write!(f, "[{ty}; {len}]"),
+            Type::Tuple(types) => {
+                f.write_str("(")?;
+                for (i, ty) in types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                f.write_str(")")
+            }
+            Type::Rest => f.write_str("[u8]"),
+            Type::Str => f.write_str("str"),
+        }
+    }
+}
+
+impl FromStr for Type {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, pos: 0 };
+        let ty = parser.ty()?;
+        parser.skip_ws();
+        if parser.pos != s.len() {
+            return Err(format!("unexpected {:?} in type {s:?}", &s[parser.pos..]));
+        }
+        Ok(ty)
+    }
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_ws(&mut self) {
+        while self.s[self.pos..].starts_with(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_ws();
+        if self.s[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected `{token}` at {} in type {:?}",
+                self.pos, self.s
+            ))
+        }
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.skip_ws();
+        let start = self.pos;
+        while self.s[self.pos..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+            self.pos += 1;
+        }
+        &self.s[start..self.pos]
+    }
+
+    fn ty(&mut self) -> Result<Type, String> {
+        if self.eat("[") {
+            let inner = self.ty()?;
+            if self.eat("]") {
+                return match inner {
+                    Type::U8 => Ok(Type::Rest),
+                    _ => Err(format!("only `[u8]` may be unsized, found `[{inner}]`")),
+                };
+            }
+            self.expect(";")?;
+            let len = self.word();
+            let len = len
+                .parse()
+                .map_err(|_| format!("bad array length {len:?} in type {:?}", self.s))?;
+            self.expect("]")?;
+            return Ok(Type::Array(Box::new(inner), len));
+        }
+        if self.eat("(") {
+            let mut types = Vec::new();
+            while !self.eat(")") {
+                types.push(self.ty()?);
+                if !self.eat(",") {
+                    self.expect(")")?;
+                    break;
+                }
+            }
+            return Ok(Type::Tuple(types));
+        }
+        let word = self.word();
+        Ok(match word {
+            "u8" => Type::U8,
+            "u16" => Type::U16,
+            "u32" => Type::U32,
+            "u64" => Type::U64,
+            "u128" => Type::U128,
+            "i8" => Type::I8,
+            "i16" => Type::I16,
+            "i32" => Type::I32,
+            "i64" => Type::I64,
+            "i128" => Type::I128,
+            "f32" => Type::F32,
+            "f64" => Type::F64,
+            "bool" => Type::Bool,
+            "Pubkey" => Type::Pubkey,
+            "String" => Type::String,
+            "str" => Type::Str,
+            "Vec" | "Option" => {
+                self.expect("<")?;
+                let inner = Box::new(self.ty()?);
+                self.expect(">")?;
+                if word == "Vec" {
+                    Type::Vec(inner)
+                } else {
+                    Type::Option(inner)
+                }
+            }
+            _ => return Err(format!("unknown type {word:?} in {:?}", self.s)),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// 取值范围的端点，整数字段写整数，浮点字段可以写小数
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Bound {
+    Int(i64),
+    Float(f64),
+}
+
+impl Bound {
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Bound::Int(v) => v as f64,
+            Bound::Float(v) => v,
+        }
+    }
+}
+
+/// 一个命名字段
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Field {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: Type,
+    /// 程序能正常处理的取值范围（闭区间），只用于整数和浮点字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<Bound>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<Bound>,
+    /// 字段记录的是程序自己记账的哪种资产（代币余额、存款份额等），只用于账户布局中的整数字段
+    ///
+    /// 同名资产在不同布局之间可以互相转移；`lamports` 留给账户本身的余额。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asset: Option<String>,
+}
+
+/// 程序接受的一种 instruction_data
+///
+/// 数据以 `tag` 开头（borsh 枚举的变体下标或手写的操作码），随后依次是 `fields`。
+/// 同一程序按账户状态区分的两种调用（例如初始化与出价）可以有相同的布局。
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstructionSchema {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    #[serde(default, skip_serializing_if = "Bytes::is_empty")]
+    pub tag: Bytes,
+    #[serde(default)]
+    pub fields: Vec<Field>,
+}
+
+/// 账户数据的编码方式
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Repr {
+    /// borsh 序列化，字段紧挨着排列
+    #[default]
+    #[serde(rename = "borsh")]
+    Borsh,
+    /// `#[repr(C)]` 结构体用 `ptr::read_unaligned` 直接读出，字段按对齐补齐
+    C,
+}
+
+/// 程序账户的一种数据布局，对应程序里的一个结构体
+///
+/// 账户数据可以比结构体长，多出的字节不参与解码。
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Layout {
+    /// 程序里的结构体名
+    pub name: String,
+    #[serde(default)]
+    pub repr: Repr,
+    pub fields: Vec<Field>,
+}
+
+impl Layout {
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
@@ -0,0 +1,71 @@
+use std::{fs, path::PathBuf};
+
+use solracebench_manifest::{load_all, verify_spans, Category, Label};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+#[test]
+fn every_case_has_a_manifest() {
+    let root = root().canonicalize().unwrap();
+    let cases = load_all(&root).unwrap();
+    for category in Category::ALL {
+        for entry in fs::read_dir(root.join(category.as_str())).unwrap() {
+            let dir = entry.unwrap().path();
+            if !dir.join("Cargo.toml").is_file() {
+                continue;
+            }
+            let id = format!("{category}/{}", dir.file_name().unwrap().to_string_lossy());
+            let case = cases
+                .iter()
+                .find(|c| c.id == id)
+                .unwrap_or_else(|| panic!("{id} has no bench.toml"));
+            assert_eq!(case.category, *category, "{id}");
+            match case.label {
+                Label::Vulnerable => {
+                    assert!(!case.findings.is_empty(), "{id} has no findings");
+                    assert!(!case.witness.is_empty(), "{id} has no witness");
+                }
+                Label::Safe => assert!(case.findings.is_empty(), "{id} has findings"),
+            }
+            assert!(!case.trigger.steps.is_empty(), "{id} has no trigger");
+        }
+    }
+    assert_eq!(cases.len(), 224);
+}
+
+#[test]
+fn every_vulnerable_case_has_a_safe_twin() {
+    let root = root().canonicalize().unwrap();
+    let cases = load_all(&root).unwrap();
+    for case in cases.iter().filter(|c| c.label == Label::Vulnerable) {
+        let twins: Vec<_> = cases
+            .iter()
+            .filter(|c| c.twin.as_deref() == Some(case.id.as_str()))
+            .collect();
+        assert_eq!(twins.len(), 1, "{}", case.id);
+        assert_eq!(twins[0].category, case.category, "{}", case.id);
+        assert_eq!(twins[0].id, format!("{}_safe", case.id));
+    }
+}
+
+#[test]
+fn spans_point_at_existing_lines() {
+    let root = root().canonicalize().unwrap();
+    for case in load_all(&root).unwrap() {
+        verify_spans(&case).unwrap();
+    }
+}
+
+#[test]
+fn ccd_findings_name_a_clock_field() {
+    let root = root().canonicalize().unwrap();
+    for case in load_all(&root).unwrap() {
+        for finding in &case.findings {
+            if finding.class == Category::Ccd {
+                assert!(!finding.clock_fields.is_empty(), "{}", case.id);
+            }
+        }
+    }
+}
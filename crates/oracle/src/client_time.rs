@@ -0,0 +1,259 @@
+use std::fmt;
+
+use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey};
+use solracebench_codec::{candidates, decode, encode_fields, encode_instruction, offsets, Value};
+use solracebench_executor::{Failure, Scenario};
+use solracebench_manifest::{Field, InstructionSchema};
+
+/// 换算时长常用的倍数：分钟、小时和天的秒数
+const MULTIPLIERS: [i128; 3] = [60, 3_600, 86_400];
+
+/// 调用方能控制的一个时间值
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimeSource {
+    /// 第 `step` 步 instruction_data 中的整数字段
+    Data { step: usize, field: String },
+    /// 不归被测程序所有的账户中按布局的整数字段：调用方自己创建的账户可以写入任意值
+    Account { account: Pubkey, field: String },
+}
+
+impl fmt::Display for TimeSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeSource::Data { step, field } => write!(f, "step {step} `{field}`"),
+            TimeSource::Account { account, field } => write!(f, "account {account} `{field}`"),
+        }
+    }
+}
+
+/// 把时间值换成 `value` 后重新执行 trigger 的结果
+#[derive(Clone, Debug)]
+pub struct Forgery {
+    pub value: i128,
+    /// 按步骤排列
+    pub results: Vec<Result<(), Failure>>,
+    /// 原 trigger 中失败、换值后成功的步骤
+    ///
+    /// 被换值的那一步原本因为参数不合法（`InvalidArgument`、`InvalidInstructionData`）
+    /// 失败时不算：把不合法的值换成合法的值后成功是正常行为。
+    pub bypassed: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ClientTimeReport {
+    pub source: TimeSource,
+    /// 原 trigger 各步骤的结果
+    pub original: Vec<Result<(), Failure>>,
+    pub forgeries: Vec<Forgery>,
+    /// 写不进 `source` 的取值及原因，例如编码不进字段类型、账户不存在或数据比布局短；这些取值没有执行
+    pub skipped: Vec<(i128, String)>,
+}
+
+impl ClientTimeReport {
+    /// 某个取值让原本被拒绝的步骤执行成功
+    pub fn is_exploitable(&self) -> bool {
+        self.forgeries.iter().any(|f| !f.bypassed.is_empty())
+    }
+
+    /// 让程序 panic 的取值，例如换算时长时乘法溢出
+    pub fn panics(&self) -> Vec<i128> {
+        self.forgeries
+            .iter()
+            .filter(|f| {
+                f.results
+                    .iter()
+                    .any(|r| matches!(r, Err(Failure::Panic(_))))
+            })
+            .map(|f| f.value)
+            .collect()
+    }
+}
+
+/// 时间字段要尝试的取值，按大小排列
+///
+/// 包括类型范围两端和 0、±1（不受字段声明的范围限制：调用方可以传任何值），
+/// 按分钟、小时、天换算成秒时刚好溢出的值，以及 `clocks` 中每个 slot 和
+/// `unix_timestamp` 本身和前后各一。不是整数字段时为空。
+pub fn time_candidates(field: &Field, clocks: &[&Clock]) -> Vec<i128> {
+    let unbounded = Field {
+        min: None,
+        max: None,
+        ..field.clone()
+    };
+    let mut out: Vec<i128> = candidates(&unbounded)
+        .iter()
+        .filter_map(Value::as_int)
+        .collect();
+    let (Some(&lo), Some(&hi)) = (out.iter().min(), out.iter().max()) else {
+        return out;
+    };
+    for m in MULTIPLIERS {
+        out.extend([hi / m + 1, lo / m - 1]);
+    }
+    for clock in clocks {
+        for anchor in [i128::from(clock.slot), i128::from(clock.unix_timestamp)] {
+            out.extend([anchor - 1, anchor, anchor + 1]);
+        }
+    }
+    out.retain(|v| (lo..=hi).contains(v));
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// trigger 中调用方能控制的全部整数字段
+///
+/// instruction_data 按 `schemas` 解码；账户只看声明了布局、owner 不是被测程序的。
+pub fn time_sources(scenario: &Scenario, schemas: &[InstructionSchema]) -> Vec<TimeSource> {
+    let mut sources = Vec::new();
+    for (step, s) in scenario.steps.iter().enumerate() {
+        if let Ok(decoded) = decode(schemas, &s.instruction.data) {
+            for field in decoded.schema.fields.iter().filter(|f| f.ty.is_integer()) {
+                sources.push(TimeSource::Data {
+                    step,
+                    field: field.name.clone(),
+                });
+            }
+        }
+    }
+    for (key, layout) in &scenario.layouts {
+        let foreign = scenario
+            .executor
+            .account(key)
+            .is_some_and(|a| a.owner != scenario.program_id);
+        if foreign && offsets(layout).is_some() {
+            for field in layout.fields.iter().filter(|f| f.ty.is_integer()) {
+                sources.push(TimeSource::Account {
+                    account: *key,
+                    field: field.name.clone(),
+                });
+            }
+        }
+    }
+    sources
+}
+
+/// 把 `source` 依次换成 [`time_candidates`] 中的每个值，重新执行整个 trigger
+///
+/// 候选值参考 trigger 中每一步的时钟。`source` 解码失败（步骤的数据不符合任何布局、
+/// 字段不存在或不是整数）时没有 forgery；写不进去的取值记入 `skipped`。
+pub fn forge_time(
+    scenario: &Scenario,
+    schemas: &[InstructionSchema],
+    source: &TimeSource,
+) -> ClientTimeReport {
+    let original: Vec<_> = scenario
+        .clone()
+        .run()
+        .into_iter()
+        .map(|outcome| outcome.result)
+        .collect();
+    let clocks: Vec<&Clock> = scenario.steps.iter().map(|step| &step.clock).collect();
+    let forged_step = match source {
+        TimeSource::Data { step, .. } => Some(*step),
+        TimeSource::Account { .. } => None,
+    };
+
+    let mut forgeries = Vec::new();
+    let mut skipped = Vec::new();
+    if let Some((field, write)) = writer(scenario, schemas, source) {
+        for value in time_candidates(&field, &clocks) {
+            let mut forged = scenario.clone();
+            if let Err(reason) = write(&mut forged, value) {
+                skipped.push((value, reason));
+                continue;
+            }
+            let results: Vec<_> = forged.run().into_iter().map(|o| o.result).collect();
+            let bypassed = (0..results.len())
+                .filter(|&i| original[i].is_err() && results[i].is_ok())
+                .filter(|&i| !(forged_step == Some(i) && invalid_input(&original[i])))
+                .collect();
+            forgeries.push(Forgery {
+                value,
+                results,
+                bypassed,
+            });
+        }
+    }
+    ClientTimeReport {
+        source: source.clone(),
+        original,
+        forgeries,
+        skipped,
+    }
+}
+
+fn invalid_input(result: &Result<(), Failure>) -> bool {
+    matches!(
+        result,
+        Err(Failure::Error(
+            ProgramError::InvalidArgument | ProgramError::InvalidInstructionData
+        ))
+    )
+}
+
+type Write = Box<dyn Fn(&mut Scenario, i128) -> Result<(), String>>;
+
+/// `source` 对应的字段，以及把它改成某个值的方法
+fn writer(
+    scenario: &Scenario,
+    schemas: &[InstructionSchema],
+    source: &TimeSource,
+) -> Option<(Field, Write)> {
+    match source {
+        TimeSource::Data { step, field } => {
+            let decoded = decode(schemas, &scenario.steps.get(*step)?.instruction.data).ok()?;
+            let index = decoded.fields.iter().position(|(name, _)| name == field)?;
+            let schema = decoded.schema.clone();
+            let values: Vec<Value> = decoded.fields.into_iter().map(|(_, v)| v).collect();
+            let step = *step;
+            Some((
+                schema.fields[index].clone(),
+                Box::new(move |forged: &mut Scenario, value: i128| {
+                    let mut values = values.clone();
+                    values[index] = Value::Int(value);
+                    forged.steps[step].instruction.data =
+                        encode_instruction(&schema, &values).map_err(|err| err.to_string())?;
+                    Ok(())
+                }),
+            ))
+        }
+        TimeSource::Account { account, field } => {
+            let layout = scenario.layouts.get(account)?;
+            let index = layout.fields.iter().position(|f| &f.name == field)?;
+            let offset = offsets(layout)?[index];
+            let field = layout.fields[index].clone();
+            let account = *account;
+            Some((
+                field.clone(),
+                Box::new(move |forged: &mut Scenario, value: i128| {
+                    let mut bytes = Vec::new();
+                    encode_fields(
+                        std::slice::from_ref(&field),
+                        &[Value::Int(value)],
+                        &mut bytes,
+                    )
+                    .map_err(|err| err.to_string())?;
+                    let mut forged_account = forged
+                        .executor
+                        .account(&account)
+                        .ok_or_else(|| format!("account {account} does not exist"))?
+                        .clone();
+                    let len = forged_account.data.len();
+                    forged_account
+                        .data
+                        .get_mut(offset..offset + bytes.len())
+                        .ok_or_else(|| {
+                            format!(
+                                "account {account} has {len} bytes, `{}` is at {offset}",
+                                field.name
+                            )
+                        })?
+                        .copy_from_slice(&bytes);
+                    forged.executor.set_account(forged_account);
+                    Ok(())
+                }),
+            ))
+        }
+    }
+}
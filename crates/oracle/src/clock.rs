@@ -0,0 +1,211 @@
+use std::{collections::BTreeSet, fmt};
+
+use solana_program::{
+    clock::{Clock, DEFAULT_MS_PER_SLOT, DEFAULT_SLOTS_PER_EPOCH},
+    instruction::Instruction,
+};
+use solracebench_executor::{Executor, Scenario};
+use solracebench_manifest::ClockField;
+
+use crate::{diff, Difference, State};
+
+/// 一个 epoch 的名义时长（秒）
+const EPOCH_SECONDS: i64 = (DEFAULT_SLOTS_PER_EPOCH * DEFAULT_MS_PER_SLOT / 1000) as i64;
+
+/// leader 能给出的时钟相对于交易提交时的偏差范围
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    /// 交易在 blockhash 过期前可以落在前后这么多个 slot 内
+    pub max_skipped_slots: u64,
+    /// `unix_timestamp` 和 `epoch_start_timestamp` 可以偏离的秒数
+    pub max_drift_secs: i64,
+    /// `epoch` 可以跨过的边界数
+    pub max_epochs: u64,
+}
+
+impl Default for Bounds {
+    fn default() -> Self {
+        Bounds {
+            // 与 blockhash 的有效期相同
+            max_skipped_slots: 150,
+            max_drift_secs: 3600,
+            max_epochs: 1,
+        }
+    }
+}
+
+/// 对某个字段的一次扰动，`clock` 是扰动后的完整时钟
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Perturbation {
+    pub field: ClockField,
+    pub clock: Clock,
+}
+
+impl Perturbation {
+    pub fn value(&self) -> i128 {
+        field_value(&self.clock, self.field)
+    }
+}
+
+impl fmt::Display for Perturbation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.field, self.value())
+    }
+}
+
+pub(crate) fn field_value(clock: &Clock, field: ClockField) -> i128 {
+    match field {
+        ClockField::Slot => clock.slot.into(),
+        ClockField::EpochStartTimestamp => clock.epoch_start_timestamp.into(),
+        ClockField::Epoch => clock.epoch.into(),
+        ClockField::LeaderScheduleEpoch => clock.leader_schedule_epoch.into(),
+        ClockField::UnixTimestamp => clock.unix_timestamp.into(),
+    }
+}
+
+/// 1, 2, 4, ... 直到 `max`（包含 `max`）
+fn offsets(max: u64) -> Vec<u64> {
+    let mut out: Vec<u64> = (0..u64::BITS)
+        .map(|shift| 1u64 << shift)
+        .take_while(|&step| step < max)
+        .collect();
+    if max > 0 {
+        out.push(max);
+    }
+    out
+}
+
+fn around_u64(value: u64, max: u64) -> Vec<u64> {
+    offsets(max)
+        .into_iter()
+        .flat_map(|d| [value.checked_sub(d), value.checked_add(d)])
+        .flatten()
+        .collect()
+}
+
+fn around_i64(value: i64, offsets: impl IntoIterator<Item = i64>) -> Vec<i64> {
+    offsets
+        .into_iter()
+        .flat_map(|d| [value.checked_sub(d), value.checked_add(d)])
+        .flatten()
+        .collect()
+}
+
+/// 按字段生成一组 leader 可能给出的时钟
+///
+/// - `slot`：前后跳过若干个 slot
+/// - `unix_timestamp`：在 `max_drift_secs` 内漂移
+/// - `epoch`：跨过前后的 epoch 边界
+/// - `epoch_start_timestamp`：在漂移范围内变化，或移到相邻 epoch 的起点
+/// - `leader_schedule_epoch`：改成当前 epoch 附近的任意值
+pub fn perturbations(clock: &Clock, bounds: &Bounds) -> Vec<Perturbation> {
+    let mut out = Vec::new();
+    let mut push = |field, edit: &dyn Fn(&mut Clock)| {
+        let mut perturbed = clock.clone();
+        edit(&mut perturbed);
+        if perturbed != *clock && !out.iter().any(|p: &Perturbation| p.clock == perturbed) {
+            out.push(Perturbation {
+                field,
+                clock: perturbed,
+            });
+        }
+    };
+    for slot in around_u64(clock.slot, bounds.max_skipped_slots) {
+        push(ClockField::Slot, &|c| c.slot = slot);
+    }
+    let drift = offsets(bounds.max_drift_secs.max(0) as u64)
+        .into_iter()
+        .map(|d| d as i64);
+    for timestamp in around_i64(clock.unix_timestamp, drift.clone()) {
+        push(ClockField::UnixTimestamp, &|c| c.unix_timestamp = timestamp);
+    }
+    for epoch in around_u64(clock.epoch, bounds.max_epochs) {
+        push(ClockField::Epoch, &|c| c.epoch = epoch);
+    }
+    let starts = drift.chain([EPOCH_SECONDS]);
+    for timestamp in around_i64(clock.epoch_start_timestamp, starts) {
+        push(ClockField::EpochStartTimestamp, &|c| {
+            c.epoch_start_timestamp = timestamp
+        });
+    }
+    let mut schedules = around_u64(clock.leader_schedule_epoch, bounds.max_epochs);
+    schedules.extend((0..=2).filter_map(|d| clock.epoch.checked_add(d)));
+    for epoch in schedules {
+        push(ClockField::LeaderScheduleEpoch, &|c| {
+            c.leader_schedule_epoch = epoch
+        });
+    }
+    out
+}
+
+/// 一次扰动后的结果
+#[derive(Clone, Debug)]
+pub struct Observation {
+    pub perturbation: Perturbation,
+    pub state: State,
+    /// 相对基准时钟的差异，为空表示结果不受这次扰动影响
+    pub differences: Vec<Difference>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ClockReport {
+    pub baseline: State,
+    pub observations: Vec<Observation>,
+}
+
+impl ClockReport {
+    /// 改变了执行结果的扰动
+    pub fn divergent(&self) -> impl Iterator<Item = &Observation> {
+        self.observations
+            .iter()
+            .filter(|o| !o.differences.is_empty())
+    }
+
+    /// 扰动后会改变执行结果的字段
+    pub fn sensitive_fields(&self) -> BTreeSet<ClockField> {
+        self.divergent().map(|o| o.perturbation.field).collect()
+    }
+
+    pub fn is_sensitive(&self) -> bool {
+        self.divergent().next().is_some()
+    }
+}
+
+fn run(executor: &Executor, instruction: &Instruction, clock: &Clock) -> State {
+    let mut executor = executor.clone();
+    let outcome = executor.execute(instruction, clock);
+    State::new(vec![outcome], &executor)
+}
+
+/// 从 `executor` 的状态出发，以 `clock` 及其全部扰动分别执行同一条指令
+pub fn perturb(
+    executor: &Executor,
+    instruction: &Instruction,
+    clock: &Clock,
+    bounds: &Bounds,
+) -> ClockReport {
+    let baseline = run(executor, instruction, clock);
+    let observations = perturbations(clock, bounds)
+        .into_iter()
+        .map(|perturbation| {
+            let state = run(executor, instruction, &perturbation.clock);
+            Observation {
+                differences: diff(&baseline, &state),
+                perturbation,
+                state,
+            }
+        })
+        .collect();
+    ClockReport {
+        baseline,
+        observations,
+    }
+}
+
+/// 按原时钟执行场景中除最后一步以外的步骤，再对最后一步调用 [`perturb`]
+pub fn perturb_scenario(scenario: &Scenario, bounds: &Bounds) -> ClockReport {
+    let last = scenario.steps.last().expect("scenario has steps");
+    let mut prefix = scenario.clone();
+    prefix.run_until(scenario.steps.len() - 1);
+    perturb(&prefix.executor, &last.instruction, &last.clock, bounds)
+}
@@ -0,0 +1,156 @@
+use std::ops::RangeInclusive;
+
+use solana_program::{
+    clock::{Clock, Epoch},
+    epoch_schedule::EpochSchedule,
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+use solracebench_codec::Change;
+use solracebench_executor::{Outcome, Scenario};
+
+use crate::{diff, field_diff, randomness::SLOT_MS, Difference, State};
+
+/// 各笔交易落在某个 epoch 第一个或最后一个 slot 上的一种放置方式
+#[derive(Clone, Debug)]
+pub struct Placement {
+    /// 按交易排列
+    pub slots: Vec<u64>,
+    /// 各笔交易所在的 epoch，按交易排列
+    pub epochs: Vec<Epoch>,
+    pub state: State,
+}
+
+/// 两种放置方式让每笔交易都落在相同的 epoch，结果却不同
+#[derive(Clone, Debug)]
+pub struct Conflict {
+    /// `placements` 中的下标
+    pub first: usize,
+    pub second: usize,
+    pub differences: Vec<Difference>,
+    /// 按账户布局解码后不同的字段
+    pub changes: Vec<(Pubkey, Change)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EpochReport {
+    pub placements: Vec<Placement>,
+    pub conflicts: Vec<Conflict>,
+}
+
+impl EpochReport {
+    /// 结果取决于交易落在 epoch 的哪个 slot，而不只是哪个 epoch
+    pub fn is_boundary_sensitive(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+}
+
+/// `epochs` 中每个 epoch 的第一个和最后一个 slot，按 slot 排列
+pub fn boundary_slots(schedule: &EpochSchedule, epochs: RangeInclusive<Epoch>) -> Vec<u64> {
+    epochs
+        .flat_map(|epoch| {
+            [
+                schedule.get_first_slot_in_epoch(epoch),
+                schedule.get_last_slot_in_epoch(epoch),
+            ]
+        })
+        .collect()
+}
+
+/// 第 `slot` 个 slot 的时钟
+///
+/// epoch 和 leader schedule 的 epoch 按 `schedule` 换算；`epoch_start_timestamp`
+/// 和 `unix_timestamp` 按每个 slot 400 毫秒从 `base` 所在 epoch 的起点推算。
+pub fn clock_at(schedule: &EpochSchedule, base: &Clock, slot: u64) -> Clock {
+    let seconds = |from: u64, to: u64| (to as i64 - from as i64).saturating_mul(SLOT_MS) / 1000;
+    let base_first = schedule.get_first_slot_in_epoch(schedule.get_epoch(base.slot));
+    let epoch = schedule.get_epoch(slot);
+    let first = schedule.get_first_slot_in_epoch(epoch);
+    let epoch_start_timestamp = base.epoch_start_timestamp + seconds(base_first, first);
+    Clock {
+        slot,
+        epoch_start_timestamp,
+        epoch,
+        leader_schedule_epoch: schedule.get_leader_schedule_epoch(slot),
+        unix_timestamp: epoch_start_timestamp + seconds(first, slot),
+    }
+}
+
+/// 把 trigger 的各笔交易按原顺序放到 epoch 边界两侧的 slot 上执行，
+/// 比较每笔交易所在 epoch 都相同的放置方式
+///
+/// 候选 slot 是 trigger 覆盖的各个 epoch 以及之后一个 epoch 的第一个和最后一个 slot，
+/// 每笔交易的 slot 不早于前一笔。epoch 划分取自执行器。
+pub fn place(scenario: &Scenario) -> EpochReport {
+    let schedule = scenario.executor.epoch_schedule().clone();
+    let transactions = scenario.transactions();
+    let base = &scenario.steps[0].clock;
+    let first = schedule.get_epoch(base.slot);
+    let last = schedule.get_epoch(scenario.steps.last().unwrap().clock.slot);
+    let candidates = boundary_slots(&schedule, first..=last + 1);
+
+    let mut placements: Vec<Placement> = Vec::new();
+    for slots in ascending(&candidates, transactions.len()) {
+        let mut executor = scenario.executor.clone();
+        let mut outcomes: Vec<Outcome> = Vec::new();
+        for (transaction, &slot) in transactions.iter().zip(&slots) {
+            let clock = clock_at(&schedule, base, slot);
+            let steps = &scenario.steps[transaction.clone()];
+            match steps {
+                [step] => outcomes.push(executor.submit(step, &clock)),
+                _ => {
+                    let instructions: Vec<Instruction> =
+                        steps.iter().map(|step| step.instruction.clone()).collect();
+                    outcomes.extend(executor.execute_transaction(&instructions, &clock));
+                }
+            }
+        }
+        placements.push(Placement {
+            epochs: slots.iter().map(|&slot| schedule.get_epoch(slot)).collect(),
+            slots,
+            state: State::new(outcomes, &executor),
+        });
+    }
+
+    let mut conflicts = Vec::new();
+    for (second, placement) in placements.iter().enumerate() {
+        let Some(first) = placements[..second]
+            .iter()
+            .position(|p| p.epochs == placement.epochs)
+        else {
+            continue;
+        };
+        let differences = diff(&placements[first].state, &placement.state);
+        if !differences.is_empty() {
+            conflicts.push(Conflict {
+                first,
+                second,
+                differences,
+                changes: field_diff(
+                    &scenario.layouts,
+                    &placements[first].state,
+                    &placement.state,
+                ),
+            });
+        }
+    }
+    EpochReport {
+        placements,
+        conflicts,
+    }
+}
+
+/// 从 `slots` 中为 `n` 笔交易依次选出不递减的 slot 的全部方式
+fn ascending(slots: &[u64], n: usize) -> Vec<Vec<u64>> {
+    if n == 0 {
+        return vec![Vec::new()];
+    }
+    let mut out = Vec::new();
+    for (i, &slot) in slots.iter().enumerate() {
+        for mut rest in ascending(&slots[i..], n - 1) {
+            rest.insert(0, slot);
+            out.push(rest);
+        }
+    }
+    out
+}
@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use solana_program::pubkey::Pubkey;
+use solracebench_codec::{decode_account, Value};
+use solracebench_executor::Scenario;
+use solracebench_manifest::Layout;
+
+use crate::{explore_scenario, Run, State};
+
+/// 默认的相对误差：比 f32 的机器精度宽一个数量级，f64 的舍入差异自然也在其中
+pub const RELATIVE_EPSILON: f64 = 1e-6;
+
+/// 一次执行相对基准的差别，从小到大排列
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Agreement {
+    /// 按位相同
+    Identical,
+    /// 只有浮点字段不同，且都在相对误差以内
+    WithinEpsilon,
+    /// 执行结果、日志、非浮点数据不同，或者浮点字段超出相对误差
+    Divergent,
+}
+
+/// 一个按位不同的浮点字段；数组元素写成 `weights[0]` 的形式
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drift {
+    pub account: Pubkey,
+    pub field: String,
+    pub baseline: f64,
+    pub value: f64,
+}
+
+impl Drift {
+    pub fn within(&self, epsilon: f64) -> bool {
+        within(self.baseline, self.value, epsilon)
+    }
+}
+
+/// 一个排列与基准的比较
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub order: Vec<usize>,
+    pub agreement: Agreement,
+    pub drifts: Vec<Drift>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FloatReport {
+    pub epsilon: f64,
+    /// trigger 中的原顺序
+    pub baseline: Run,
+    /// 其余执行过的排列
+    pub comparisons: Vec<Comparison>,
+}
+
+impl FloatReport {
+    /// 所有排列中最大的差别
+    pub fn worst(&self) -> Agreement {
+        self.comparisons
+            .iter()
+            .map(|c| c.agreement)
+            .max()
+            .unwrap_or(Agreement::Identical)
+    }
+
+    /// 有排列的结果按位不同
+    pub fn is_bitwise_divergent(&self) -> bool {
+        self.worst() > Agreement::Identical
+    }
+
+    /// 有排列的结果在误差范围之外也不同
+    pub fn is_divergent(&self) -> bool {
+        self.worst() == Agreement::Divergent
+    }
+}
+
+fn within(a: f64, b: f64, epsilon: f64) -> bool {
+    (a - b).abs() <= epsilon * a.abs().max(b.abs())
+}
+
+/// 比较两个结果，浮点字段按 `epsilon` 的相对误差区分舍入差异和真正的分歧
+///
+/// 只有 `layouts` 里有、而且两边都能完整解码的账户逐字段比较，其余账户按字节比较。
+pub fn compare(
+    layouts: &BTreeMap<Pubkey, Layout>,
+    a: &State,
+    b: &State,
+    epsilon: f64,
+) -> (Agreement, Vec<Drift>) {
+    if a.results != b.results || a.logs != b.logs || a.accounts.len() != b.accounts.len() {
+        return (Agreement::Divergent, Vec::new());
+    }
+    let mut divergent = false;
+    let mut drifts = Vec::new();
+    for (x, y) in a.accounts.iter().zip(&b.accounts) {
+        if x.key != y.key || x.lamports != y.lamports || x.owner != y.owner {
+            divergent = true;
+        } else if x.data != y.data {
+            let views = layouts.get(&x.key).map(|layout| {
+                (
+                    decode_account(layout, &x.data),
+                    decode_account(layout, &y.data),
+                )
+            });
+            match views {
+                Some((before, after)) if before.error.is_none() && after.error.is_none() => {
+                    for ((name, u), (_, v)) in before.fields.iter().zip(&after.fields) {
+                        divergent |= !walk(x.key, name, u, v, &mut drifts);
+                    }
+                }
+                _ => divergent = true,
+            }
+        }
+    }
+    let agreement = if divergent || drifts.iter().any(|d| !d.within(epsilon)) {
+        Agreement::Divergent
+    } else if drifts.is_empty() {
+        Agreement::Identical
+    } else {
+        Agreement::WithinEpsilon
+    };
+    (agreement, drifts)
+}
+
+/// 逐个叶子比较，把不同的浮点数记到 `drifts`；其他类型的值不同时返回 false
+fn walk(account: Pubkey, path: &str, a: &Value, b: &Value, drifts: &mut Vec<Drift>) -> bool {
+    match (a, b) {
+        (Value::F32(_) | Value::F64(_), Value::F32(_) | Value::F64(_)) => {
+            if !a.same(b) {
+                drifts.push(Drift {
+                    account,
+                    field: path.to_owned(),
+                    baseline: a.as_f64().unwrap(),
+                    value: b.as_f64().unwrap(),
+                });
+            }
+            true
+        }
+        (Value::List(a), Value::List(b)) if a.len() == b.len() => {
+            a.iter().zip(b).enumerate().fold(true, |same, (i, (a, b))| {
+                walk(account, &format!("{path}[{i}]"), a, b, drifts) && same
+            })
+        }
+        (Value::Option(Some(a)), Value::Option(Some(b))) => walk(account, path, a, b, drifts),
+        _ => a.same(b),
+    }
+}
+
+/// 先按顺序执行前 `setup` 步，再执行其余步骤的全部排列（见 [`explore_scenario`]），
+/// 逐个与 trigger 原顺序的结果比较
+pub fn float_order(scenario: &Scenario, setup: usize, epsilon: f64) -> FloatReport {
+    let mut runs = explore_scenario(scenario, setup).runs;
+    let identity: Vec<usize> = (0..scenario.steps.len() - setup).collect();
+    let index = runs
+        .iter()
+        .position(|run| run.order == identity)
+        .unwrap_or(0);
+    let baseline = runs.remove(index);
+    let comparisons = runs
+        .into_iter()
+        .map(|run| {
+            let (agreement, drifts) =
+                compare(&scenario.layouts, &baseline.state, &run.state, epsilon);
+            Comparison {
+                order: run.order,
+                agreement,
+                drifts,
+            }
+        })
+        .collect();
+    FloatReport {
+        epsilon,
+        baseline,
+        comparisons,
+    }
+}
@@ -0,0 +1,96 @@
+use solana_program::pubkey::Pubkey;
+use solracebench_codec::{Change, Value};
+use solracebench_executor::{Outcome, Scenario};
+
+use crate::{diff, field_diff, Difference, State};
+
+/// 攻击者的初始化移到某个位置后重新执行的结果
+#[derive(Clone, Debug)]
+pub struct FrontRun {
+    /// 攻击者的初始化排在其余步骤中的第几步之前
+    pub position: usize,
+    /// 攻击者的初始化是否执行成功
+    pub ok: bool,
+    /// 不含攻击者那一步的结果和日志
+    pub state: State,
+    pub differences: Vec<Difference>,
+    /// 按账户布局解码后与基线不同的字段
+    pub changes: Vec<(Pubkey, Change)>,
+}
+
+impl FrontRun {
+    /// 执行完后某个有布局的字段变成了 `attacker`，例如管理员或提款权限
+    pub fn captures(&self, attacker: &Pubkey) -> bool {
+        self.changes
+            .iter()
+            .any(|(_, change)| change.after == Some(Value::Pubkey(*attacker)))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct InitReport {
+    /// trigger 中攻击者初始化的步骤
+    pub attack: usize,
+    /// 去掉攻击者那一步、其余步骤按原顺序执行的结果
+    pub baseline: State,
+    /// 按 `position` 排列
+    pub runs: Vec<FrontRun>,
+}
+
+impl InitReport {
+    /// 攻击者的初始化在某个位置执行成功，并且改变了其余步骤的结果
+    pub fn is_front_runnable(&self) -> bool {
+        self.runs
+            .iter()
+            .any(|run| run.ok && !run.differences.is_empty())
+    }
+}
+
+/// 把场景的第 `attack` 步移到其余步骤之间的每个位置上重新执行
+///
+/// 前 `setup` 步保持在最前面。基线是去掉这一步后其余步骤按原顺序执行的结果；
+/// 每个位置的结果都去掉攻击者那一步再与基线比较，差异只来自攻击者的初始化。
+pub fn front_run(scenario: &Scenario, setup: usize, attack: usize) -> InitReport {
+    let victims: Vec<usize> = (0..scenario.steps.len()).filter(|&i| i != attack).collect();
+    let baseline = run(scenario, &victims, None).1;
+    let runs = (setup.min(victims.len())..=victims.len())
+        .map(|position| {
+            let (attacker, state) = run(scenario, &victims, Some((position, attack)));
+            let attacker = attacker.expect("the attack step is inserted");
+            FrontRun {
+                position,
+                ok: attacker.is_ok(),
+                differences: diff(&baseline, &state),
+                changes: field_diff(&scenario.layouts, &baseline, &state),
+                state,
+            }
+        })
+        .collect();
+    InitReport {
+        attack,
+        baseline,
+        runs,
+    }
+}
+
+/// 按顺序执行 `steps`，`insert` 给出时把这一步插在第 `position` 步之前
+fn run(
+    scenario: &Scenario,
+    steps: &[usize],
+    insert: Option<(usize, usize)>,
+) -> (Option<Outcome>, State) {
+    let mut executor = scenario.executor.clone();
+    let mut inserted = None;
+    let mut outcomes = Vec::with_capacity(steps.len());
+    for position in 0..=steps.len() {
+        if let Some((_, step)) = insert.filter(|&(at, _)| at == position) {
+            let step = &scenario.steps[step];
+            inserted = Some(executor.submit(step, &step.clock));
+        }
+        if let Some(&step) = steps.get(position) {
+            let step = &scenario.steps[step];
+            outcomes.push(executor.submit(step, &step.clock));
+        }
+    }
+    (inserted, State::new(outcomes, &executor))
+}
@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solracebench_executor::{Failure, Scenario};
+
+use crate::sandwich::{balances, changes};
+
+/// 交易中的指令按一种顺序重新执行的结果
+#[derive(Clone, Debug)]
+pub struct Arrangement {
+    /// 按执行顺序排列的步骤下标，插入的副本与原步骤下标相同
+    pub order: Vec<usize>,
+    /// 按 `order` 排列
+    pub results: Vec<Result<(), Failure>>,
+    /// 攻击者账户各余额字段在这笔交易前后的变化
+    pub gain: BTreeMap<String, i128>,
+}
+
+impl Arrangement {
+    /// 整笔交易执行成功
+    pub fn is_ok(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IntrospectionReport {
+    /// 重新排列的那笔交易包含的步骤
+    pub transaction: Range<usize>,
+    pub arrangements: Vec<Arrangement>,
+}
+
+impl IntrospectionReport {
+    /// 某种排列下交易执行成功，攻击者没有哪种余额减少，并且至少一种增加
+    pub fn is_exploitable(&self) -> bool {
+        self.arrangements.iter().any(|arrangement| {
+            arrangement.is_ok()
+                && arrangement.gain.values().all(|&change| change >= 0)
+                && arrangement.gain.values().any(|&change| change > 0)
+        })
+    }
+}
+
+/// 交易中指令的全部排列，以及在每种排列的每个位置插入其中一条指令的副本
+///
+/// 插入副本对应攻击者在同一笔交易里重复调用某条指令，例如再借一次款。
+pub fn arrangements(transaction: Range<usize>) -> Vec<Vec<usize>> {
+    let mut orders: Vec<Vec<usize>> = Vec::new();
+    for order in permutations(&transaction.collect::<Vec<_>>()) {
+        for &step in &order {
+            for position in 0..=order.len() {
+                let mut inserted = order.clone();
+                inserted.insert(position, step);
+                if !orders.contains(&inserted) {
+                    orders.push(inserted);
+                }
+            }
+        }
+        if !orders.contains(&order) {
+            orders.push(order);
+        }
+    }
+    orders.sort_by_key(Vec::len);
+    orders
+}
+
+fn permutations(steps: &[usize]) -> Vec<Vec<usize>> {
+    if steps.len() <= 1 {
+        return vec![steps.to_vec()];
+    }
+    let mut out = Vec::new();
+    for (i, &first) in steps.iter().enumerate() {
+        let mut rest = steps.to_vec();
+        rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first);
+            out.push(tail);
+        }
+    }
+    out
+}
+
+/// 把 `transaction` 这笔交易中的指令按 [`arrangements`] 的每种顺序重新执行
+///
+/// 之前的交易按原顺序执行，之后的交易不执行；重新排列后的交易使用原交易的时钟。
+/// `attacker` 是攻击者存放余额的账户，余额取自账户布局中的整数字段。
+pub fn rearrange(
+    scenario: &Scenario,
+    transaction: Range<usize>,
+    attacker: &Pubkey,
+) -> IntrospectionReport {
+    let mut before = scenario.clone();
+    for earlier in scenario.transactions() {
+        if earlier.end <= transaction.start {
+            before.run_transaction(earlier);
+        }
+    }
+    let start = balances(scenario, &before.executor, attacker);
+    let clock = &scenario.steps[transaction.start].clock;
+
+    let arrangements = arrangements(transaction.clone())
+        .into_iter()
+        .map(|order| {
+            let mut executor = before.executor.clone();
+            let instructions: Vec<Instruction> = order
+                .iter()
+                .map(|&step| scenario.steps[step].instruction.clone())
+                .collect();
+            let results = executor
+                .execute_transaction(&instructions, clock)
+                .into_iter()
+                .map(|outcome| outcome.result)
+                .collect();
+            Arrangement {
+                gain: changes(&start, &balances(scenario, &executor, attacker)),
+                order,
+                results,
+            }
+        })
+        .collect();
+    IntrospectionReport {
+        transaction,
+        arrangements,
+    }
+}
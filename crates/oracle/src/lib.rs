@@ -0,0 +1,43 @@
+//! 在执行器之上判定竞态、伪造 sysvar 账户、可预测随机数、初始化抢跑、三明治攻击、durable nonce 延迟执行、交易内指令顺序、预言机价格过期、epoch 边界、调用方提供的时间、时间运算的符号与类型转换和浮点运算顺序是否真实可利用，以及资产是否守恒的 oracle。
+
+mod client_time;
+mod clock;
+mod epoch;
+mod float;
+mod init;
+mod introspection;
+mod nonce;
+mod price;
+mod randomness;
+mod sandwich;
+mod state;
+mod sysvar;
+mod time_arith;
+mod tod;
+mod value;
+
+pub use client_time::{
+    forge_time, time_candidates, time_sources, ClientTimeReport, Forgery, TimeSource,
+};
+pub use clock::{
+    perturb, perturb_scenario, perturbations, Bounds, ClockReport, Observation, Perturbation,
+};
+pub use epoch::{boundary_slots, clock_at, place, Conflict, EpochReport, Placement};
+pub use float::{
+    compare, float_order, Agreement, Comparison, Drift, FloatReport, RELATIVE_EPSILON,
+};
+pub use init::{front_run, FrontRun, InitReport};
+pub use introspection::{arrangements, rearrange, Arrangement, IntrospectionReport};
+pub use nonce::{hold, holds, Landing, NonceReport, MAX_PROCESSING_AGE};
+pub use price::{
+    age_price, ages, estimated_timestamp, timestamp_bounds, Attempt, PriceReport, MAX_PRICE_AGE,
+};
+pub use randomness::{predict, submissions, Draw, Prediction, Submission};
+pub use sandwich::{sandwich, SandwichReport};
+pub use state::{diff, field_diff, Difference, State};
+pub use sysvar::{spoof, spoof_clock, ClockForgery, SpoofReport};
+pub use time_arith::{probe_time, time_probes, Divergence, Probe, TimeArithReport};
+pub use tod::{commute, explore, explore_scenario, Run, TodReport};
+pub use value::{
+    attackers, conservation, holdings, AssetOverflow, Flow, Holdings, ValueReport, LAMPORTS,
+};
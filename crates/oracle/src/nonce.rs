@@ -0,0 +1,140 @@
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solracebench_codec::Change;
+use solracebench_executor::{Failure, Outcome, Scenario};
+
+use crate::{
+    diff, field_diff,
+    randomness::{delay, SLOT_MS},
+    Difference, State,
+};
+
+/// recent blockhash 的有效期（slot）：在这之内落地的交易不需要 durable nonce
+pub const MAX_PROCESSING_AGE: u64 = 150;
+
+/// 签名的交易在某个 slot 落地后重新执行的结果
+#[derive(Clone, Debug)]
+pub struct Landing {
+    pub slot: u64,
+    /// 扣留的那一步的执行结果
+    pub result: Result<(), Failure>,
+    pub state: State,
+    /// 与按时落地相比的差异，不含 nonce 账户推进到的值
+    pub differences: Vec<Difference>,
+    /// 按账户布局解码后与按时落地不同的字段
+    pub changes: Vec<(Pubkey, Change)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct NonceReport {
+    /// trigger 中用 durable nonce 签名的步骤
+    pub step: usize,
+    /// 签名时的 slot
+    pub signed: u64,
+    /// 交易在签名的 slot 立即落地的结果
+    pub baseline: State,
+    /// 按 `slot` 排列
+    pub landings: Vec<Landing>,
+}
+
+impl NonceReport {
+    /// 交易在 recent blockhash 本该过期之后落地成功，并且结果与按时落地不同
+    pub fn is_delayable(&self) -> bool {
+        self.landings.iter().any(|landing| {
+            landing.slot > self.signed + MAX_PROCESSING_AGE
+                && landing.result.is_ok()
+                && !landing.differences.is_empty()
+        })
+    }
+}
+
+/// 扣留交易的一方会选择的落地 slot
+///
+/// 包括 recent blockhash 过期后的第一个 slot、trigger 中各步骤落地的 slot（紧跟在结算、
+/// 窗口关闭这类步骤之后，以及 trigger 本身扣留到的 slot），和从过期起按 4 倍递增的延迟；
+/// 都不超过签名后 `horizon` 个 slot。
+pub fn holds(scenario: &Scenario, signed: u64, horizon: u64) -> Vec<u64> {
+    let first = signed + MAX_PROCESSING_AGE + 1;
+    let mut slots: Vec<u64> = scenario
+        .steps
+        .iter()
+        .map(|step| step.clock.slot)
+        .filter(|&slot| slot >= first)
+        .collect();
+    let mut slot = first;
+    while slot - signed <= horizon {
+        slots.push(slot);
+        slot = signed + (slot - signed) * 4;
+    }
+    slots.retain(|&slot| slot - signed <= horizon);
+    slots.sort_unstable();
+    slots.dedup();
+    slots
+}
+
+/// 把第 `step` 步（用 durable nonce 在 `signed` slot 签名）扣留到每个 `slots` 落地，
+/// 与签名后立即落地的结果比较
+///
+/// 其余步骤按各自的时钟和 trigger 顺序执行，这一步排在第一个更晚落地的步骤之前；
+/// 落地时的时间戳按每个 slot 400 毫秒从这一步的时钟推算。
+pub fn hold(scenario: &Scenario, step: usize, signed: u64, slots: &[u64]) -> NonceReport {
+    let nonce = scenario.steps[step]
+        .nonce
+        .as_ref()
+        .unwrap_or_else(|| panic!("step {step} is not signed with a durable nonce"));
+    let (_, baseline) = run(scenario, step, signed);
+    let landings = slots
+        .iter()
+        .map(|&slot| {
+            let (outcome, state) = run(scenario, step, slot);
+            let mut differences = diff(&baseline, &state);
+            differences.retain(|d| *d != Difference::Data(nonce.account));
+            Landing {
+                slot,
+                result: outcome.result,
+                changes: field_diff(&scenario.layouts, &baseline, &state),
+                differences,
+                state,
+            }
+        })
+        .collect();
+    NonceReport {
+        step,
+        signed,
+        baseline,
+        landings,
+    }
+}
+
+/// 第 `slot` 个 slot 的时钟，时间戳按两者之间的 slot 数从 `clock` 推算
+fn land(clock: &Clock, slot: u64) -> Clock {
+    if slot >= clock.slot {
+        return delay(clock, slot - clock.slot);
+    }
+    let slots = (clock.slot - slot) as i64;
+    Clock {
+        slot,
+        unix_timestamp: clock.unix_timestamp - slots.saturating_mul(SLOT_MS) / 1000,
+        ..clock.clone()
+    }
+}
+
+/// 按 trigger 顺序执行，第 `held` 步改在 `slot` 落地
+fn run(scenario: &Scenario, held: usize, slot: u64) -> (Outcome, State) {
+    let clock = land(&scenario.steps[held].clock, slot);
+    let mut executor = scenario.executor.clone();
+    let mut outcomes: Vec<Option<Outcome>> = vec![None; scenario.steps.len()];
+    for (i, step) in scenario.steps.iter().enumerate() {
+        if i == held {
+            continue;
+        }
+        if outcomes[held].is_none() && step.clock.slot > slot {
+            outcomes[held] = Some(executor.submit(&scenario.steps[held], &clock));
+        }
+        outcomes[i] = Some(executor.submit(step, &step.clock));
+    }
+    if outcomes[held].is_none() {
+        outcomes[held] = Some(executor.submit(&scenario.steps[held], &clock));
+    }
+    let outcomes: Vec<Outcome> = outcomes.into_iter().flatten().collect();
+    (outcomes[held].clone(), State::new(outcomes, &executor))
+}
@@ -0,0 +1,139 @@
+use std::ops::RangeInclusive;
+
+use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey};
+use solracebench_executor::{Failure, PriceFeed, Scenario};
+
+use crate::randomness::SLOT_MS;
+
+/// 价格被当作新鲜的最长年龄（秒），超过它的价格都算过期
+pub const MAX_PRICE_AGE: u64 = 60;
+
+/// 投票给出的时间戳最多比 PoH 推算的时刻慢多少（占 epoch 起点以来时长的百分比）
+const MAX_DRIFT_FAST_PERCENT: i64 = 25;
+/// 投票给出的时间戳最多比 PoH 推算的时刻快多少（同上）
+const MAX_DRIFT_SLOW_PERCENT: i64 = 150;
+
+/// 按 epoch 起点和每个 slot 400 毫秒推算出的 `clock.slot` 的时刻
+pub fn estimated_timestamp(clock: &Clock) -> i64 {
+    let first = EpochSchedule::default().get_first_slot_in_epoch(clock.epoch);
+    let elapsed = clock.slot.saturating_sub(first) as i64;
+    clock.epoch_start_timestamp + elapsed.saturating_mul(SLOT_MS) / 1000
+}
+
+/// 运行时接受的 `unix_timestamp` 范围
+///
+/// 时间戳是投票的权益加权中位数，运行时把它限制在 PoH 推算的时刻附近：
+/// 最多慢 epoch 起点以来时长的 25%，最多快 150%。
+pub fn timestamp_bounds(clock: &Clock) -> RangeInclusive<i64> {
+    let estimated = estimated_timestamp(clock);
+    let elapsed = estimated - clock.epoch_start_timestamp;
+    estimated - elapsed * MAX_DRIFT_FAST_PERCENT / 100
+        ..=estimated + elapsed * MAX_DRIFT_SLOW_PERCENT / 100
+}
+
+/// 价格账户改成某个年龄后，这一步在某个时间戳下执行的结果
+#[derive(Clone, Debug)]
+pub struct Attempt {
+    /// 价格在这一步落地前多少秒写入
+    pub age: u64,
+    pub feed: PriceFeed,
+    pub clock: Clock,
+    pub result: Result<(), Failure>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PriceReport {
+    /// trigger 中读取价格的步骤
+    pub step: usize,
+    /// 价格账户
+    pub account: Pubkey,
+    /// 按 `age` 排列，同一年龄下依次是 PoH 推算的时间戳、最慢和最快的时间戳
+    pub attempts: Vec<Attempt>,
+}
+
+impl PriceReport {
+    /// 某个超过 [`MAX_PRICE_AGE`] 的价格在运行时接受的时间戳下通过了检查
+    pub fn is_exploitable(&self) -> bool {
+        self.attempts
+            .iter()
+            .any(|attempt| attempt.age > MAX_PRICE_AGE && attempt.result.is_ok())
+    }
+
+    /// 过期价格只有在 leader 把时间戳调离 PoH 推算的时刻后才能通过
+    pub fn needs_shift(&self) -> bool {
+        self.is_exploitable()
+            && self.attempts.iter().all(|attempt| {
+                attempt.age <= MAX_PRICE_AGE
+                    || attempt.result.is_err()
+                    || attempt.clock.unix_timestamp != estimated_timestamp(&attempt.clock)
+            })
+    }
+}
+
+/// 要尝试的价格年龄（秒）：刚超过 [`MAX_PRICE_AGE`]，之后按 4 倍递增，不超过 `horizon`
+pub fn ages(horizon: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    let mut age = MAX_PRICE_AGE + 1;
+    while age <= horizon {
+        out.push(age);
+        age *= 4;
+    }
+    out
+}
+
+/// 把第 `step` 步读取的价格账户 `account` 改成 `ages` 中每个年龄的价格，
+/// 再让 leader 分别给出 PoH 推算的、最慢和最快的时间戳执行这一步
+///
+/// 之前的交易按原顺序执行；价格不变，`publish_time` 和 `publish_slot`
+/// 按每个 slot 400 毫秒从这一步的 slot 和推算的时刻往前推。
+pub fn age_price(scenario: &Scenario, step: usize, account: &Pubkey, ages: &[u64]) -> PriceReport {
+    let mut before = scenario.clone();
+    for earlier in scenario.transactions() {
+        if earlier.end <= step {
+            before.run_transaction(earlier);
+        }
+    }
+    let original = before
+        .executor
+        .account(account)
+        .and_then(|a| PriceFeed::from_data(&a.data))
+        .unwrap_or_else(|| panic!("{account} is not a price account"));
+    let step_clock = &scenario.steps[step].clock;
+    let estimated = estimated_timestamp(step_clock);
+    let bounds = timestamp_bounds(step_clock);
+
+    let mut attempts = Vec::new();
+    for &age in ages {
+        let feed = PriceFeed {
+            publish_time: estimated - age as i64,
+            publish_slot: step_clock
+                .slot
+                .saturating_sub((age * 1000).div_ceil(SLOT_MS as u64)),
+            ..original
+        };
+        let mut timestamps = vec![estimated, *bounds.start(), *bounds.end()];
+        timestamps.dedup();
+        for unix_timestamp in timestamps {
+            let clock = Clock {
+                unix_timestamp,
+                ..step_clock.clone()
+            };
+            let mut executor = before.executor.clone();
+            let mut price = executor.account(account).unwrap().clone();
+            price.data = feed.data();
+            executor.set_account(price);
+            let outcome = executor.submit(&scenario.steps[step], &clock);
+            attempts.push(Attempt {
+                age,
+                feed,
+                clock,
+                result: outcome.result,
+            });
+        }
+    }
+    PriceReport {
+        step,
+        account: *account,
+        attempts,
+    }
+}
@@ -0,0 +1,147 @@
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solracebench_codec::{decode, domain, encode_instruction, Value};
+use solracebench_executor::{Executor, Scenario};
+use solracebench_manifest::InstructionSchema;
+
+/// 逐个枚举的整数字段最多的取值个数
+const MAX_DOMAIN: usize = 256;
+/// 每个 slot 的时长（毫秒），与主网的目标出块时间一致
+pub(crate) const SLOT_MS: i64 = 400;
+
+/// 玩家（攻击者）可以选择的一种提交方式
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    /// 比 trigger 中晚多少个 slot 落地，时间戳按每个 slot 400 毫秒推后
+    pub delay: u64,
+    pub data: Vec<u8>,
+}
+
+impl Submission {
+    /// 落地 slot 推后 `delay` 个之后的时钟
+    pub fn clock(&self, clock: &Clock) -> Clock {
+        delay(clock, self.delay)
+    }
+}
+
+/// 落地 slot 推后 `slots` 个之后的时钟，时间戳按每个 slot 400 毫秒推后
+pub(crate) fn delay(clock: &Clock, slots: u64) -> Clock {
+    Clock {
+        slot: clock.slot.saturating_add(slots),
+        unix_timestamp: clock
+            .unix_timestamp
+            .saturating_add((slots as i64).saturating_mul(SLOT_MS) / 1000),
+        ..clock.clone()
+    }
+}
+
+/// 一种提交方式在提交前模拟得到的结果
+#[derive(Clone, Debug)]
+pub struct Draw {
+    pub submission: Submission,
+    pub ok: bool,
+    /// 这一步中玩家账户的 lamports 变化
+    pub gain: i128,
+}
+
+#[derive(Clone, Debug)]
+pub struct Prediction {
+    /// 玩家提交的步骤
+    pub step: usize,
+    pub player: Pubkey,
+    pub draws: Vec<Draw>,
+}
+
+impl Prediction {
+    /// 模拟中让玩家获利的提交方式
+    pub fn winning(&self) -> impl Iterator<Item = &Draw> {
+        self.draws.iter().filter(|d| d.gain > 0)
+    }
+
+    /// 玩家能在提交前算出胜负：有的提交方式获利、有的不获利，只提交获利的那些即可
+    ///
+    /// 所有提交方式都获利说明结果与随机数无关；都不获利说明胜负不在这一步决定，
+    /// 例如承诺–揭示方案里要等庄家揭示 secret 后才开奖。
+    pub fn is_predictable(&self) -> bool {
+        self.winning().next().is_some() && self.draws.iter().any(|d| d.gain <= 0)
+    }
+}
+
+/// 玩家在 `data` 的基础上可以选择的提交方式
+///
+/// 玩家可以按原定 slot 挑选数据，也可以带着原数据等待合适的 slot：
+/// `data` 能按 `schemas` 解码时，取值不超过 256 个的整数字段（猜的号码、自带的盐等）
+/// 逐个枚举、其余字段保持原值，在原定 slot 提交；原数据再分别推后 `1..=slots` 个 slot 提交。
+pub fn submissions(schemas: &[InstructionSchema], data: &[u8], slots: u64) -> Vec<Submission> {
+    let datas = match decode(schemas, data) {
+        Ok(decoded) => {
+            let mut values: Vec<Vec<Value>> = vec![Vec::new()];
+            for (field, (_, value)) in decoded.schema.fields.iter().zip(&decoded.fields) {
+                let choices = domain(field, MAX_DOMAIN).unwrap_or_else(|| vec![value.clone()]);
+                values = values
+                    .iter()
+                    .flat_map(|prefix| {
+                        choices.iter().map(move |choice| {
+                            let mut next = prefix.clone();
+                            next.push(choice.clone());
+                            next
+                        })
+                    })
+                    .collect();
+            }
+            values
+                .iter()
+                .filter_map(|values| encode_instruction(decoded.schema, values).ok())
+                .collect()
+        }
+        Err(_) => vec![data.to_vec()],
+    };
+    let waits = (1..=slots).map(|delay| Submission {
+        delay,
+        data: data.to_vec(),
+    });
+    datas
+        .into_iter()
+        .map(|data| Submission { delay: 0, data })
+        .chain(waits)
+        .collect()
+}
+
+fn lamports(executor: &Executor, key: &Pubkey) -> i128 {
+    executor.account(key).map_or(0, |a| a.lamports.into())
+}
+
+/// 在第 `step` 步之前的状态上分别模拟每一种提交，记录 `player` 的获利
+///
+/// 之前的步骤按 trigger 原样执行，之后的步骤不执行：玩家只凭提交前已经上链的状态
+/// 和自己选择的数据与落地 slot 预测结果，和在 RPC 节点上模拟交易一样。
+pub fn predict(
+    scenario: &Scenario,
+    step: usize,
+    player: &Pubkey,
+    submissions: &[Submission],
+) -> Prediction {
+    let mut prefix = scenario.clone();
+    prefix.run_until(step);
+    let executor = prefix.executor;
+    let before = lamports(&executor, player);
+    let target = &scenario.steps[step];
+    let draws = submissions
+        .iter()
+        .map(|submission| {
+            let mut executor = executor.clone();
+            let mut target = target.clone();
+            target.instruction.data = submission.data.clone();
+            let outcome = executor.submit(&target, &submission.clock(&target.clock));
+            Draw {
+                submission: submission.clone(),
+                ok: outcome.is_ok(),
+                gain: lamports(&executor, player) - before,
+            }
+        })
+        .collect();
+    Prediction {
+        step,
+        player: *player,
+        draws,
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use solana_program::pubkey::Pubkey;
+use solracebench_codec::{decode_account, Value};
+use solracebench_executor::{Executor, Failure, Scenario};
+
+use crate::{diff, randomness::delay, Difference, State};
+
+/// 受害者交易被夹在攻击者抢跑和尾随之间执行的结果
+#[derive(Clone, Debug)]
+pub struct SandwichReport {
+    /// 去掉攻击者两步、其余步骤按原顺序执行的结果
+    pub baseline: State,
+    /// 按 trigger 顺序执行的结果，不含攻击者两步的结果和日志
+    pub state: State,
+    pub differences: Vec<Difference>,
+    /// 抢跑、受害者交易和尾随三步的执行结果
+    pub results: [Result<(), Failure>; 3],
+    /// 攻击者账户各余额字段从抢跑前到尾随后的变化
+    pub extracted: BTreeMap<String, i128>,
+    /// 受害者账户各余额字段相对基线的变化
+    pub victim: BTreeMap<String, i128>,
+}
+
+impl SandwichReport {
+    /// 三步都成功，攻击者没有哪种余额减少，并且至少一种增加
+    pub fn is_extractable(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+            && self.extracted.values().all(|&change| change >= 0)
+            && self.extracted.values().any(|&change| change > 0)
+    }
+}
+
+/// 按 trigger 顺序执行夹击，并与去掉攻击者两步的基线比较
+///
+/// `steps` 是抢跑、受害者交易和尾随的下标；`attacker` 和 `victim` 是双方存放余额的账户，
+/// 余额取自账户布局中的整数字段。`hold` 让受害者交易比 trigger 中晚若干个 slot 落地，
+/// 相当于出块者扣留交易等价格变化，用来检查截止 slot。
+pub fn sandwich(
+    scenario: &Scenario,
+    steps: [usize; 3],
+    hold: u64,
+    attacker: &Pubkey,
+    victim: &Pubkey,
+) -> SandwichReport {
+    let [front, target, back] = steps;
+
+    let mut executor = scenario.executor.clone();
+    let outcomes = scenario
+        .steps
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != front && i != back)
+        .map(|(_, step)| executor.submit(step, &step.clock))
+        .collect();
+    let baseline = State::new(outcomes, &executor);
+    let victim_baseline = balances(scenario, &executor, victim);
+
+    let mut executor = scenario.executor.clone();
+    let mut outcomes = Vec::new();
+    let mut results: [Result<(), Failure>; 3] = [Ok(()), Ok(()), Ok(())];
+    let mut before = BTreeMap::new();
+    for (i, step) in scenario.steps.iter().enumerate() {
+        if i == front {
+            before = balances(scenario, &executor, attacker);
+        }
+        let clock = if i == target {
+            delay(&step.clock, hold)
+        } else {
+            step.clock.clone()
+        };
+        let outcome = executor.submit(step, &clock);
+        if let Some(k) = steps.iter().position(|&s| s == i) {
+            results[k] = outcome.result.clone();
+        }
+        if i != front && i != back {
+            outcomes.push(outcome);
+        }
+    }
+    let state = State::new(outcomes, &executor);
+
+    SandwichReport {
+        differences: diff(&baseline, &state),
+        extracted: changes(&before, &balances(scenario, &executor, attacker)),
+        victim: changes(&victim_baseline, &balances(scenario, &executor, victim)),
+        baseline,
+        state,
+        results,
+    }
+}
+
+/// 按布局解码 `key` 账户，取出全部整数字段
+pub(crate) fn balances(
+    scenario: &Scenario,
+    executor: &Executor,
+    key: &Pubkey,
+) -> BTreeMap<String, i128> {
+    let (Some(layout), Some(account)) = (scenario.layouts.get(key), executor.account(key)) else {
+        return BTreeMap::new();
+    };
+    decode_account(layout, &account.data)
+        .fields
+        .into_iter()
+        .filter_map(|(name, value)| match value {
+            Value::Int(amount) => Some((name, amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+pub(crate) fn changes(
+    before: &BTreeMap<String, i128>,
+    after: &BTreeMap<String, i128>,
+) -> BTreeMap<String, i128> {
+    before
+        .keys()
+        .chain(after.keys())
+        .map(|name| {
+            let get = |m: &BTreeMap<String, i128>| m.get(name).copied().unwrap_or(0);
+            (name.clone(), get(after) - get(before))
+        })
+        .collect()
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use solana_program::pubkey::Pubkey;
+use solana_sdk_ids::sysvar;
+use solracebench_codec::{changes, decode_account, Change};
+use solracebench_executor::{Account, Executor, Failure, Outcome};
+use solracebench_manifest::Layout;
+
+/// 一组指令执行完后可以比较的结果
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State {
+    /// 按指令编号（不是执行位置）排列的执行结果
+    pub results: Vec<Result<(), Failure>>,
+    /// 按指令编号排列的 `msg!` 输出；不少用例的 sink 只是一行日志
+    pub logs: Vec<Vec<String>>,
+    /// 按 key 排序的账户，不含执行器按时钟生成的 sysvar 账户
+    pub accounts: Vec<Account>,
+}
+
+impl State {
+    /// `outcomes` 按指令编号排列，`executor` 是全部执行完后的执行器
+    pub fn new(outcomes: Vec<Outcome>, executor: &Executor) -> Self {
+        let mut accounts: Vec<Account> = executor
+            .accounts()
+            .iter()
+            .filter(|a| a.owner != sysvar::ID)
+            .cloned()
+            .collect();
+        accounts.sort_by_key(|a| a.key);
+        let (results, logs) = outcomes.into_iter().map(|o| (o.result, o.logs)).unzip();
+        State {
+            results,
+            logs,
+            accounts,
+        }
+    }
+}
+
+/// 两个结果之间的一处差异
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Difference {
+    /// 第几条指令的执行结果不同
+    Result(usize),
+    /// 第几条指令的日志不同
+    Logs(usize),
+    /// 账户只在其中一边存在
+    Account(Pubkey),
+    Lamports(Pubkey),
+    Data(Pubkey),
+    Owner(Pubkey),
+}
+
+pub fn diff(a: &State, b: &State) -> Vec<Difference> {
+    let mut out: Vec<Difference> = a
+        .results
+        .iter()
+        .zip(&b.results)
+        .enumerate()
+        .filter(|(_, (x, y))| x != y)
+        .map(|(i, _)| Difference::Result(i))
+        .collect();
+    out.extend(
+        a.logs
+            .iter()
+            .zip(&b.logs)
+            .enumerate()
+            .filter(|(_, (x, y))| x != y)
+            .map(|(i, _)| Difference::Logs(i)),
+    );
+    for x in &a.accounts {
+        let Some(y) = b.accounts.iter().find(|y| y.key == x.key) else {
+            out.push(Difference::Account(x.key));
+            continue;
+        };
+        if x.lamports != y.lamports {
+            out.push(Difference::Lamports(x.key));
+        }
+        if x.data != y.data {
+            out.push(Difference::Data(x.key));
+        }
+        if x.owner != y.owner {
+            out.push(Difference::Owner(x.key));
+        }
+    }
+    for y in &b.accounts {
+        if !a.accounts.iter().any(|x| x.key == y.key) {
+            out.push(Difference::Account(y.key));
+        }
+    }
+    out
+}
+
+/// 按账户布局比较两边的数据，列出值不同的字段
+///
+/// 只看 `layouts` 里有的账户；填充字节和结构体之后的空余字节不算差异。
+pub fn field_diff(
+    layouts: &BTreeMap<Pubkey, Layout>,
+    a: &State,
+    b: &State,
+) -> Vec<(Pubkey, Change)> {
+    layouts
+        .iter()
+        .flat_map(|(key, layout)| {
+            let before = decode_account(layout, data(a, key));
+            let after = decode_account(layout, data(b, key));
+            changes(&before, &after)
+                .into_iter()
+                .map(|change| (*key, change))
+        })
+        .collect()
+}
+
+fn data<'a>(state: &'a State, key: &Pubkey) -> &'a [u8] {
+    state
+        .accounts
+        .iter()
+        .find(|x| x.key == *key)
+        .map_or(&[], |x| &x.data)
+}
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solana_sdk_ids::sysvar;
+use solracebench_executor::{
+    attacker_program, clock_data, name_key, Account, Scenario, ScenarioStep,
+};
+
+use crate::{diff, Difference, State};
+
+/// 攻击者写进伪造 Clock 账户的时钟，相对每一步的真实时钟给出
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClockForgery {
+    /// 全部字段为 0
+    Past,
+    /// slot 往后一百万个，epoch 往后 10 个，时间戳往后 30 天
+    Future,
+}
+
+impl ClockForgery {
+    pub const ALL: &'static [ClockForgery] = &[ClockForgery::Past, ClockForgery::Future];
+
+    pub fn apply(self, clock: &Clock) -> Clock {
+        const DAYS_30: i64 = 30 * 24 * 3600;
+        match self {
+            ClockForgery::Past => Clock::default(),
+            ClockForgery::Future => Clock {
+                slot: clock.slot.saturating_add(1_000_000),
+                epoch_start_timestamp: clock.epoch_start_timestamp.saturating_add(DAYS_30),
+                epoch: clock.epoch.saturating_add(10),
+                leader_schedule_epoch: clock.leader_schedule_epoch.saturating_add(10),
+                unix_timestamp: clock.unix_timestamp.saturating_add(DAYS_30),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ClockForgery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClockForgery::Past => f.write_str("past"),
+            ClockForgery::Future => f.write_str("future"),
+        }
+    }
+}
+
+/// 用伪造账户替换 sysvar 账户后重新执行场景的结果
+#[derive(Clone, Debug)]
+pub struct SpoofReport {
+    /// 被替换的 sysvar 账户
+    pub sysvar: Pubkey,
+    /// 攻击者程序持有的伪造账户
+    pub forged: Pubkey,
+    pub baseline: State,
+    /// 伪造账户本身不计入
+    pub state: State,
+    pub differences: Vec<Difference>,
+    /// 传入了伪造账户并且执行成功的步骤
+    pub accepted: Vec<usize>,
+}
+
+impl SpoofReport {
+    /// 程序接受了伪造账户，并且结果因此改变
+    pub fn is_spoofable(&self) -> bool {
+        !self.accepted.is_empty() && !self.differences.is_empty()
+    }
+}
+
+/// 把场景中传入 `sysvar` 账户的位置全部换成攻击者程序持有的伪造账户后重新执行
+///
+/// 每笔交易执行前用 `forge` 按交易的第一步生成伪造账户的数据，程序通过 `Clock::get` 等 syscall
+/// 读到的仍是真实的值。没有任何一步传入 `sysvar` 账户时返回 `None`。
+pub fn spoof(
+    scenario: &Scenario,
+    sysvar: &Pubkey,
+    forge: impl Fn(&ScenarioStep) -> Vec<u8>,
+) -> Option<SpoofReport> {
+    let uses = |step: &ScenarioStep| {
+        step.instruction
+            .accounts
+            .iter()
+            .any(|m| m.pubkey == *sysvar)
+    };
+    if !scenario.steps.iter().any(uses) {
+        return None;
+    }
+    let baseline = {
+        let mut scenario = scenario.clone();
+        let outcomes = scenario.run();
+        State::new(outcomes, &scenario.executor)
+    };
+
+    let forged = name_key("forged_sysvar");
+    let mut replay = scenario.clone();
+    for step in &mut replay.steps {
+        for meta in &mut step.instruction.accounts {
+            if meta.pubkey == *sysvar {
+                meta.pubkey = forged;
+            }
+        }
+    }
+    // 和基线一样按交易提交；一笔交易内的指令共用第一步的时钟，伪造账户也只在交易之间写入
+    let mut outcomes = Vec::new();
+    for transaction in scenario.transactions() {
+        let mut account = Account::new(forged, attacker_program());
+        account.lamports = 1;
+        account.data = forge(&scenario.steps[transaction.start]);
+        replay.executor.set_account(account);
+        outcomes.extend(replay.run_transaction(transaction));
+    }
+    let accepted = scenario
+        .steps
+        .iter()
+        .zip(&outcomes)
+        .enumerate()
+        .filter(|(_, (step, outcome))| uses(step) && outcome.is_ok())
+        .map(|(i, _)| i)
+        .collect();
+    let mut state = State::new(outcomes, &replay.executor);
+    state.accounts.retain(|a| a.key != forged);
+    Some(SpoofReport {
+        sysvar: *sysvar,
+        forged,
+        differences: diff(&baseline, &state),
+        baseline,
+        state,
+        accepted,
+    })
+}
+
+/// 对场景中的 Clock sysvar 账户分别做每一种 [`ClockForgery`]
+///
+/// 场景没有传入 Clock 账户时为空。
+pub fn spoof_clock(scenario: &Scenario) -> Vec<(ClockForgery, SpoofReport)> {
+    ClockForgery::ALL
+        .iter()
+        .filter_map(|&forgery| {
+            let report = spoof(scenario, &sysvar::clock::ID, |step| {
+                clock_data(&forgery.apply(&step.clock))
+            })?;
+            Some((forgery, report))
+        })
+        .collect()
+}
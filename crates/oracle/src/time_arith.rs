@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use solana_program::{clock::Clock, pubkey::Pubkey};
+use solracebench_codec::decode_account;
+use solracebench_executor::{Failure, Scenario};
+use solracebench_manifest::ClockField;
+
+use crate::{clock::field_value, State};
+
+/// 把某一步的时钟字段换成 `value` 后执行整个 trigger 的结果
+#[derive(Clone, Debug)]
+pub struct Probe {
+    pub value: i128,
+    pub state: State,
+    /// 执行完后声明了布局的账户中能解码的整数字段
+    pub fields: BTreeMap<(Pubkey, String), i128>,
+}
+
+impl Probe {
+    /// 第一处 panic 的消息
+    pub fn panic(&self) -> Option<&str> {
+        self.state.results.iter().find_map(|r| match r {
+            Err(Failure::Panic(message)) => Some(message.as_str()),
+            _ => None,
+        })
+    }
+
+    /// panic 是整数运算溢出（`attempt to add with overflow` 之类）
+    pub fn overflowed(&self) -> bool {
+        self.panic().is_some_and(|m| m.contains("overflow"))
+    }
+}
+
+/// 随时钟单调推进时不该出现的反复
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Divergence {
+    /// 第几步的执行结果变了又变回来
+    Result(usize),
+    /// 第几步的日志变了又变回来
+    Logs(usize),
+    /// 账户字段不随时钟单调变化
+    Field(Pubkey, String),
+}
+
+#[derive(Clone, Debug)]
+pub struct TimeArithReport {
+    pub step: usize,
+    pub field: ClockField,
+    /// 按取值从小到大排列
+    pub probes: Vec<Probe>,
+    /// 只看没有 panic 的取值
+    pub divergences: Vec<Divergence>,
+}
+
+impl TimeArithReport {
+    /// 让程序 panic 的取值，例如时间相减下溢、加上宽限期后溢出
+    pub fn panics(&self) -> Vec<i128> {
+        self.probes
+            .iter()
+            .filter(|p| p.panic().is_some())
+            .map(|p| p.value)
+            .collect()
+    }
+
+    /// 其中是整数溢出的取值
+    pub fn overflows(&self) -> Vec<i128> {
+        self.probes
+            .iter()
+            .filter(|p| p.overflowed())
+            .map(|p| p.value)
+            .collect()
+    }
+
+    /// 没有 panic，但结果不随时钟单调变化：负时间戳转成无符号数、时长回绕之类的逻辑错误
+    ///
+    /// 只在某段时间内成功的时间窗口本身也不单调，需要结合用例判断。
+    pub fn is_divergent(&self) -> bool {
+        !self.divergences.is_empty()
+    }
+}
+
+fn range(field: ClockField) -> (i128, i128) {
+    match field {
+        ClockField::Slot | ClockField::Epoch | ClockField::LeaderScheduleEpoch => {
+            (0, u64::MAX.into())
+        }
+        ClockField::EpochStartTimestamp | ClockField::UnixTimestamp => {
+            (i64::MIN.into(), i64::MAX.into())
+        }
+    }
+}
+
+fn with_field(clock: &Clock, field: ClockField, value: i128) -> Option<Clock> {
+    let mut clock = clock.clone();
+    match field {
+        ClockField::Slot => clock.slot = value.try_into().ok()?,
+        ClockField::EpochStartTimestamp => clock.epoch_start_timestamp = value.try_into().ok()?,
+        ClockField::Epoch => clock.epoch = value.try_into().ok()?,
+        ClockField::LeaderScheduleEpoch => clock.leader_schedule_epoch = value.try_into().ok()?,
+        ClockField::UnixTimestamp => clock.unix_timestamp = value.try_into().ok()?,
+    }
+    Some(clock)
+}
+
+/// 时钟字段要尝试的取值，按大小排列
+///
+/// 包括字段类型范围两端、0 和 ±1，以及 `anchors` 中每个值本身和前后各一，
+/// 超出类型范围的丢掉。
+pub fn time_probes(field: ClockField, anchors: &[i128]) -> Vec<i128> {
+    let (lo, hi) = range(field);
+    let mut out = vec![lo, lo + 1, -1, 0, 1, hi - 1, hi];
+    for &anchor in anchors {
+        out.extend([anchor - 1, anchor, anchor + 1]);
+    }
+    out.retain(|v| (lo..=hi).contains(v));
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// trigger 里和时间有关的参照值：每一步时钟中的 `field`，以及声明了布局的账户中的整数字段
+fn anchors(scenario: &Scenario, field: ClockField) -> Vec<i128> {
+    let mut out: Vec<i128> = scenario
+        .steps
+        .iter()
+        .map(|step| field_value(&step.clock, field))
+        .collect();
+    for (key, layout) in &scenario.layouts {
+        if let Some(account) = scenario.executor.account(key) {
+            let view = decode_account(layout, &account.data);
+            out.extend(view.fields.iter().filter_map(|(_, v)| v.as_int()));
+        }
+    }
+    out
+}
+
+/// 把第 `step` 步时钟的 `field` 依次换成 [`time_probes`] 中的每个值，重新执行整个 trigger
+///
+/// 参照值见 `anchors`。`step` 和前面的步骤属于同一笔交易时整笔交易按第一步的时钟执行，
+/// 换值不起作用。
+pub fn probe_time(scenario: &Scenario, step: usize, field: ClockField) -> TimeArithReport {
+    let probes: Vec<Probe> = time_probes(field, &anchors(scenario, field))
+        .into_iter()
+        .filter_map(|value| {
+            let mut probed = scenario.clone();
+            probed.steps[step].clock = with_field(&probed.steps[step].clock, field, value)?;
+            let outcomes = probed.run();
+            let state = State::new(outcomes, &probed.executor);
+            let fields = fields(&probed);
+            Some(Probe {
+                value,
+                state,
+                fields,
+            })
+        })
+        .collect();
+    TimeArithReport {
+        step,
+        field,
+        divergences: divergences(&probes),
+        probes,
+    }
+}
+
+fn fields(scenario: &Scenario) -> BTreeMap<(Pubkey, String), i128> {
+    let mut out = BTreeMap::new();
+    for (key, layout) in &scenario.layouts {
+        if let Some(account) = scenario.executor.account(key) {
+            for (name, value) in decode_account(layout, &account.data).fields {
+                if let Some(v) = value.as_int() {
+                    out.insert((*key, name), v);
+                }
+            }
+        }
+    }
+    out
+}
+
+fn divergences(probes: &[Probe]) -> Vec<Divergence> {
+    let sane: Vec<&Probe> = probes.iter().filter(|p| p.panic().is_none()).collect();
+    let mut out = Vec::new();
+    let steps = sane.first().map_or(0, |p| p.state.results.len());
+    for i in 0..steps {
+        if revisits(sane.iter().map(|p| &p.state.results[i])) {
+            out.push(Divergence::Result(i));
+        }
+    }
+    for i in 0..steps {
+        if revisits(sane.iter().map(|p| &p.state.logs[i])) {
+            out.push(Divergence::Logs(i));
+        }
+    }
+    let keys = sane
+        .first()
+        .map(|p| p.fields.keys().cloned().collect::<Vec<_>>());
+    for (key, name) in keys.unwrap_or_default() {
+        let values: Vec<i128> = sane
+            .iter()
+            .filter_map(|p| p.fields.get(&(key, name.clone())).copied())
+            .collect();
+        if !monotone(&values) {
+            out.push(Divergence::Field(key, name));
+        }
+    }
+    out
+}
+
+/// 离开某个值之后又回到它
+fn revisits<T: PartialEq>(values: impl Iterator<Item = T>) -> bool {
+    let mut seen: Vec<T> = Vec::new();
+    for v in values {
+        if seen.last() == Some(&v) {
+            continue;
+        }
+        if seen.contains(&v) {
+            return true;
+        }
+        seen.push(v);
+    }
+    false
+}
+
+fn monotone(values: &[i128]) -> bool {
+    values.windows(2).all(|w| w[0] <= w[1]) || values.windows(2).all(|w| w[0] >= w[1])
+}
@@ -0,0 +1,179 @@
+use std::collections::{BTreeSet, HashMap};
+
+use solana_program::{clock::Clock, instruction::Instruction};
+use solracebench_executor::{Executor, Scenario};
+
+use crate::State;
+
+/// 两条指令是否可交换：没有共同的账户被其中任一方以可写方式使用
+///
+/// 程序状态只存在于账户中，所以这是可交换的充分条件。
+pub fn commute(a: &Instruction, b: &Instruction) -> bool {
+    a.accounts.iter().all(|x| {
+        b.accounts
+            .iter()
+            .all(|y| x.pubkey != y.pubkey || !(x.is_writable || y.is_writable))
+    })
+}
+
+/// 一个执行过的排列
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// 指令编号的执行顺序
+    pub order: Vec<usize>,
+    pub state: State,
+}
+
+#[derive(Clone, Debug)]
+pub struct TodReport {
+    pub runs: Vec<Run>,
+    /// 因可交换而没有执行的排列数
+    pub pruned: usize,
+    /// 相邻交换后结果不同的指令对 `(i, j)`，`i < j`
+    pub divergent_pairs: BTreeSet<(usize, usize)>,
+}
+
+impl TodReport {
+    /// 是否有排列得到了不同的结果
+    pub fn is_divergent(&self) -> bool {
+        !self.divergent_pairs.is_empty()
+    }
+
+    /// 按最终结果把执行过的排列分组，每组内结果相同
+    pub fn groups(&self) -> Vec<Vec<&Run>> {
+        let mut groups: Vec<Vec<&Run>> = Vec::new();
+        for run in &self.runs {
+            match groups.iter_mut().find(|g| g[0].state == run.state) {
+                Some(group) => group.push(run),
+                None => groups.push(vec![run]),
+            }
+        }
+        groups
+    }
+}
+
+struct Explorer<'a> {
+    executor: &'a Executor,
+    instructions: &'a [Instruction],
+    clock: &'a Clock,
+    runs: HashMap<Vec<usize>, State>,
+    order: Vec<Vec<usize>>,
+}
+
+impl Explorer<'_> {
+    fn run(&mut self, order: &[usize]) -> &State {
+        if !self.runs.contains_key(order) {
+            let mut executor = self.executor.clone();
+            let mut outcomes: Vec<_> = order
+                .iter()
+                .map(|&i| (i, executor.execute(&self.instructions[i], self.clock)))
+                .collect();
+            outcomes.sort_by_key(|(i, _)| *i);
+            let outcomes = outcomes.into_iter().map(|(_, o)| o).collect();
+            self.runs
+                .insert(order.to_vec(), State::new(outcomes, &executor));
+            self.order.push(order.to_vec());
+        }
+        &self.runs[order]
+    }
+
+    fn commute(&self, a: usize, b: usize) -> bool {
+        commute(&self.instructions[a], &self.instructions[b])
+    }
+
+    /// 把排列换成同一等价类中字典序最小的那个：每次取能移到最前面的最小编号
+    fn normalize(&self, order: &[usize]) -> Vec<usize> {
+        let mut rest = order.to_vec();
+        let mut out = Vec::with_capacity(order.len());
+        while !rest.is_empty() {
+            let k = (0..rest.len())
+                .filter(|&k| rest[..k].iter().all(|&x| self.commute(x, rest[k])))
+                .min_by_key(|&k| rest[k])
+                .expect("the first instruction can always move to the front");
+            out.push(rest.remove(k));
+        }
+        out
+    }
+}
+
+/// 从 `executor` 的状态出发执行 `instructions` 的全部排列
+///
+/// 只差在可交换指令先后的排列属于同一等价类，每类只执行字典序最小的一个。
+/// 对每个执行过的排列，交换其中相邻的不可交换指令得到另一类，结果不同即记为一对分歧指令。
+/// 排列数是 n!，指令数应保持在个位数。
+pub fn explore(executor: &Executor, instructions: &[Instruction], clock: &Clock) -> TodReport {
+    let mut explorer = Explorer {
+        executor,
+        instructions,
+        clock,
+        runs: HashMap::new(),
+        order: Vec::new(),
+    };
+    let mut divergent_pairs = BTreeSet::new();
+    let mut total = 0;
+    let mut order: Vec<usize> = (0..instructions.len()).collect();
+    loop {
+        total += 1;
+        if explorer.normalize(&order) == order {
+            let state = explorer.run(&order).clone();
+            for k in 0..order.len().saturating_sub(1) {
+                let (a, b) = (order[k], order[k + 1]);
+                if explorer.commute(a, b) {
+                    continue;
+                }
+                let mut swapped = order.clone();
+                swapped.swap(k, k + 1);
+                let swapped = explorer.normalize(&swapped);
+                if *explorer.run(&swapped) != state {
+                    divergent_pairs.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+        if !next_permutation(&mut order) {
+            break;
+        }
+    }
+    let Explorer {
+        mut runs, order, ..
+    } = explorer;
+    let runs: Vec<Run> = order
+        .into_iter()
+        .map(|order| {
+            let state = runs
+                .remove(&order)
+                .expect("every executed order is recorded");
+            Run { order, state }
+        })
+        .collect();
+    TodReport {
+        pruned: total - runs.len(),
+        runs,
+        divergent_pairs,
+    }
+}
+
+/// 先按顺序执行场景的前 `setup` 步，再对其余步骤调用 [`explore`]
+///
+/// 报告中的指令编号从 0 开始，对应场景的第 `setup` 步；所有步骤使用第一条被排列步骤的时钟。
+pub fn explore_scenario(scenario: &Scenario, setup: usize) -> TodReport {
+    let mut prefix = scenario.clone();
+    prefix.run_until(setup);
+    let racing = &scenario.steps[setup..];
+    let instructions: Vec<Instruction> = racing.iter().map(|s| s.instruction.clone()).collect();
+    let clock = racing.first().map(|s| s.clock.clone()).unwrap_or_default();
+    explore(&prefix.executor, &instructions, &clock)
+}
+
+/// 按字典序变为下一个排列，已是最后一个时返回 false
+fn next_permutation(order: &mut [usize]) -> bool {
+    let Some(i) = order.windows(2).rposition(|w| w[0] < w[1]) else {
+        return false;
+    };
+    let j = order
+        .iter()
+        .rposition(|&x| x > order[i])
+        .expect("order[i + 1] > order[i]");
+    order.swap(i, j);
+    order[i + 1..].reverse();
+    true
+}
@@ -0,0 +1,230 @@
+use std::{collections::BTreeMap, fmt, ops::Range};
+
+use solana_program::pubkey::Pubkey;
+use solana_sdk_ids::{system_program, sysvar};
+use solracebench_codec::{decode_account, Value};
+use solracebench_executor::{Failure, Scenario};
+
+/// 账户本身的余额记作这种资产
+pub const LAMPORTS: &str = "lamports";
+
+/// 各账户持有的各种资产：lamports 以及布局中标了 `asset` 的整数字段，同一账户的同名资产相加
+pub type Holdings = BTreeMap<(Pubkey, String), i128>;
+
+/// 某个账户的某种资产超出了 `i128`，无法计算变化量
+///
+/// 大于 `i128::MAX` 的 u128 字段，或同一账户的同名资产相加后溢出。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetOverflow {
+    pub account: Pubkey,
+    pub asset: String,
+}
+
+impl fmt::Display for AssetOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` held by {} does not fit in i128",
+            self.asset, self.account
+        )
+    }
+}
+
+impl std::error::Error for AssetOverflow {}
+
+/// 一笔交易前后的资产流动
+#[derive(Clone, Debug)]
+pub struct Flow {
+    /// 交易包含的步骤
+    pub steps: Range<usize>,
+    pub results: Vec<Result<(), Failure>>,
+    /// 各账户各资产的变化，不含没有变化的
+    pub changes: Holdings,
+}
+
+impl Flow {
+    /// 各资产在全部账户上的变化之和，不含守恒的资产
+    ///
+    /// lamports 不守恒的指令在链上会被运行时拒绝，执行器不检查这一点；
+    /// 程序记账的资产不守恒说明它被凭空记入或抹去了。
+    pub fn imbalances(&self) -> BTreeMap<String, i128> {
+        let mut sums: BTreeMap<String, i128> = BTreeMap::new();
+        for ((_, asset), change) in &self.changes {
+            *sums.entry(asset.clone()).or_default() += change;
+        }
+        sums.retain(|_, sum| *sum != 0);
+        sums
+    }
+}
+
+/// 按 trigger 顺序执行时的资产流动
+#[derive(Clone, Debug)]
+pub struct ValueReport {
+    /// 按交易排列
+    pub flows: Vec<Flow>,
+    /// 收益归于攻击者的账户
+    pub attackers: Vec<Pubkey>,
+}
+
+impl ValueReport {
+    /// 整个场景前后各账户各资产的净变化，不含没有变化的
+    pub fn net(&self) -> Holdings {
+        let mut net = Holdings::new();
+        for flow in &self.flows {
+            for (key, change) in &flow.changes {
+                *net.entry(key.clone()).or_default() += change;
+            }
+        }
+        net.retain(|_, change| *change != 0);
+        net
+    }
+
+    /// 攻击者各资产的净变化，不含没有变化的
+    pub fn gained(&self) -> BTreeMap<String, i128> {
+        let mut gained: BTreeMap<String, i128> = BTreeMap::new();
+        for ((account, asset), change) in self.net() {
+            if self.attackers.contains(&account) {
+                *gained.entry(asset).or_default() += change;
+            }
+        }
+        gained.retain(|_, change| *change != 0);
+        gained
+    }
+
+    /// 每笔交易的每种资产都守恒
+    pub fn is_conserved(&self) -> bool {
+        self.flows.iter().all(|flow| flow.imbalances().is_empty())
+    }
+
+    /// 攻击者没有哪种资产减少，并且至少一种增加
+    pub fn is_profitable(&self) -> bool {
+        let gained = self.gained();
+        !gained.is_empty() && gained.values().all(|&change| change > 0)
+    }
+}
+
+impl fmt::Display for ValueReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gained = self.gained();
+        if gained.is_empty() {
+            write!(f, "attacker gained nothing")?;
+        }
+        for (i, (asset, change)) in gained.iter().enumerate() {
+            let verb = if *change > 0 { "gained" } else { "lost" };
+            let sep = if i == 0 { "attacker " } else { ", " };
+            write!(f, "{sep}{verb} {} {asset}", change.unsigned_abs())?;
+        }
+        for flow in &self.flows {
+            for (asset, sum) in flow.imbalances() {
+                let verb = if sum > 0 { "created" } else { "destroyed" };
+                write!(
+                    f,
+                    "; steps {}..{} {verb} {} {asset}",
+                    flow.steps.start,
+                    flow.steps.end,
+                    sum.unsigned_abs()
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 收益归于攻击者的账户
+///
+/// trigger 中名字以 `attacker` 开头的账户；没有这样的账户时，
+/// 取调用方一侧的钱包，即 system 持有、没有数据的账户。
+pub fn attackers(scenario: &Scenario) -> Vec<Pubkey> {
+    let named: Vec<Pubkey> = scenario
+        .keys
+        .iter()
+        .filter(|(name, _)| name.starts_with("attacker"))
+        .map(|(_, key)| *key)
+        .collect();
+    if !named.is_empty() {
+        return named;
+    }
+    scenario
+        .keys
+        .values()
+        .filter(|key| {
+            scenario.executor.account(key).is_some_and(|a| {
+                a.owner == system_program::ID && a.data.is_empty() && !a.executable
+            })
+        })
+        .copied()
+        .collect()
+}
+
+/// 场景当前各账户持有的资产，不含执行器按时钟生成的 sysvar 账户
+pub fn holdings(scenario: &Scenario) -> Result<Holdings, AssetOverflow> {
+    let mut holdings = Holdings::new();
+    for account in scenario.executor.accounts() {
+        if account.owner == sysvar::ID {
+            continue;
+        }
+        holdings.insert((account.key, LAMPORTS.to_owned()), account.lamports.into());
+        let Some(layout) = scenario.layouts.get(&account.key) else {
+            continue;
+        };
+        for (name, value) in decode_account(layout, &account.data).fields {
+            let Some(asset) = layout.field(&name).and_then(|f| f.asset.clone()) else {
+                continue;
+            };
+            let overflow = || AssetOverflow {
+                account: account.key,
+                asset: asset.clone(),
+            };
+            let amount = match value {
+                Value::Int(amount) => amount,
+                Value::U128(_) => return Err(overflow()),
+                _ => continue,
+            };
+            let held = holdings.entry((account.key, asset.clone())).or_default();
+            *held = held.checked_add(amount).ok_or_else(overflow)?;
+        }
+    }
+    Ok(holdings)
+}
+
+/// 按 trigger 顺序逐笔执行交易，记录每笔交易前后的资产流动
+///
+/// 某个时刻有资产超出 `i128` 时返回错误，而不是跳过这种资产。
+pub fn conservation(
+    scenario: &Scenario,
+    attackers: &[Pubkey],
+) -> Result<ValueReport, AssetOverflow> {
+    let mut scenario = scenario.clone();
+    let mut flows = Vec::new();
+    for steps in scenario.transactions() {
+        let before = holdings(&scenario)?;
+        let results = scenario
+            .run_transaction(steps.clone())
+            .into_iter()
+            .map(|outcome| outcome.result)
+            .collect();
+        let after = holdings(&scenario)?;
+        let mut changes = Holdings::new();
+        for key in before.keys().chain(after.keys()) {
+            let get = |h: &Holdings| h.get(key).copied().unwrap_or(0);
+            let change = get(&after)
+                .checked_sub(get(&before))
+                .ok_or_else(|| AssetOverflow {
+                    account: key.0,
+                    asset: key.1.clone(),
+                })?;
+            if change != 0 {
+                changes.insert(key.clone(), change);
+            }
+        }
+        flows.push(Flow {
+            steps,
+            results,
+            changes,
+        });
+    }
+    Ok(ValueReport {
+        flows,
+        attackers: attackers.to_vec(),
+    })
+}
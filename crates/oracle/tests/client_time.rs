@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use solana_program::clock::Clock;
+use solracebench_executor::{name_key, Scenario};
+use solracebench_manifest::{load_all, load_case, Bound, Case, Category, Field, Label, Type};
+use solracebench_oracle::{
+    forge_time, time_candidates, time_sources, ClientTimeReport, TimeSource,
+};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+/// trigger 中每个调用方能控制的整数字段各一份报告
+fn reports(case: &Case) -> Vec<ClientTimeReport> {
+    let scenario = scenario(case);
+    time_sources(&scenario, &case.instructions)
+        .iter()
+        .map(|source| forge_time(&scenario, &case.instructions, source))
+        .collect()
+}
+
+#[test]
+fn candidates_cover_type_bounds_overflow_points_and_clocks() {
+    let days = Field {
+        name: "days".to_owned(),
+        ty: Type::I64,
+        min: Some(Bound::Int(1)),
+        max: Some(Bound::Int(1460)),
+        asset: None,
+    };
+    let clock = Clock {
+        slot: 1000,
+        unix_timestamp: 1_700_000_000,
+        ..Clock::default()
+    };
+    let values = time_candidates(&days, &[&clock]);
+    // 声明的范围不限制候选值
+    for v in [i64::MIN, -1, 0, i64::MAX] {
+        assert!(values.contains(&v.into()), "{v}");
+    }
+    assert!(values.contains(&(i128::from(i64::MAX / 86_400) + 1)));
+    assert!(values.contains(&(i128::from(i64::MIN / 60) - 1)));
+    assert!(values.contains(&999) && values.contains(&1_700_000_001));
+    assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+    let slot = Field {
+        ty: Type::U64,
+        ..days
+    };
+    assert!(time_candidates(&slot, &[]).iter().all(|&v| v >= 0));
+}
+
+#[test]
+fn only_vulnerable_client_time_cases_can_be_bypassed() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::ClientTime)
+        .collect();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let reports = reports(case);
+        assert!(!reports.is_empty(), "{}", case.id);
+        let exploitable = reports.iter().any(ClientTimeReport::is_exploitable);
+        match case.label {
+            Label::Vulnerable => assert!(exploitable, "{}", case.id),
+            Label::Safe => assert!(!exploitable, "{}: {reports:?}", case.id),
+        }
+    }
+}
+
+/// lock3 拒绝小于 1 的天数，只有换算成秒时溢出的天数才能让锁仓当场解锁
+#[test]
+fn an_overflowing_duration_unlocks_at_once() {
+    let root = root();
+    let case = load_case(&root, &root.join("client-time/lock3")).unwrap();
+    let scenario = scenario(&case);
+    let source = TimeSource::Data {
+        step: 0,
+        field: "days".to_owned(),
+    };
+    let report = forge_time(&scenario, &case.instructions, &source);
+    assert!(report.original[1].is_err());
+    let bypasses: Vec<i128> = report
+        .forgeries
+        .iter()
+        .filter(|f| f.bypassed.contains(&1))
+        .map(|f| f.value)
+        .collect();
+    assert!(!bypasses.is_empty());
+    assert!(bypasses
+        .iter()
+        .all(|&days| days > i128::from(i64::MAX / 86_400)));
+    assert!(report.panics().is_empty());
+}
+
+/// ccd24 的 unlock_slot 取自初始化指令：提前提取时把它填成 0 即可
+#[test]
+fn ccd24_trusts_the_unlock_slot_from_the_caller() {
+    let root = root();
+    let case = load_case(&root, &root.join("ccd/ccd24")).unwrap();
+    let mut scenario = scenario(&case);
+    scenario.steps[1].clock.slot = 10;
+    let source = TimeSource::Data {
+        step: 0,
+        field: "unlock_slot".to_owned(),
+    };
+    assert!(time_sources(&scenario, &case.instructions).contains(&source));
+    let report = forge_time(&scenario, &case.instructions, &source);
+    assert!(report.original[1].is_err());
+    let zero = report.forgeries.iter().find(|f| f.value == 0).unwrap();
+    assert_eq!(zero.bypassed, [1]);
+}
+
+/// 账户数据比布局短或账户不存在时，候选值记入 `skipped`，而不是让 oracle panic
+#[test]
+fn unwritable_account_candidates_are_skipped() {
+    let root = root();
+    let case = load_case(&root, &root.join("client-time/voucher2")).unwrap();
+    let mut scenario = scenario(&case);
+    let stale = scenario.key("stale");
+    let mut account = scenario.executor.account(&stale).unwrap().clone();
+    account.data.truncate(48);
+    scenario.executor.set_account(account);
+    let missing = name_key("missing");
+    let layout = scenario.layouts[&stale].clone();
+    scenario.layouts.insert(missing, layout);
+
+    for account in [stale, missing] {
+        let source = TimeSource::Account {
+            account,
+            field: "expires_at".to_owned(),
+        };
+        let report = forge_time(&scenario, &case.instructions, &source);
+        assert!(report.forgeries.is_empty(), "{source}");
+        assert!(!report.skipped.is_empty(), "{source}");
+    }
+}
+
+/// tod30 的租期直接乘 86400：溢出的天数让程序 panic，而不是绕过检查
+#[test]
+fn tod30_panics_on_an_overflowing_lease() {
+    let root = root();
+    let case = load_case(&root, &root.join("tod/tod30")).unwrap();
+    let scenario = scenario(&case);
+    let source = TimeSource::Data {
+        step: 0,
+        field: "days".to_owned(),
+    };
+    let report = forge_time(&scenario, &case.instructions, &source);
+    assert!(report
+        .panics()
+        .contains(&(i128::from(i64::MAX / 86_400) + 1)));
+}
+
+/// 其他类别中带 client-time 发现的用例，其安全孪生不再从调用方取时间
+#[test]
+fn twins_of_client_time_findings_cannot_be_bypassed() {
+    let cases = load_all(&root()).unwrap();
+    let mut twins = 0;
+    for case in &cases {
+        let Some(twin) = &case.twin else { continue };
+        let vulnerable = cases.iter().find(|c| &c.id == twin).unwrap();
+        if !vulnerable
+            .findings
+            .iter()
+            .any(|f| f.class == Category::ClientTime)
+        {
+            continue;
+        }
+        twins += 1;
+        for report in reports(case) {
+            assert!(!report.is_exploitable(), "{}: {report:?}", case.id);
+        }
+    }
+    assert!(twins >= 4, "{twins}");
+}
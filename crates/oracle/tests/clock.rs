@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use solana_program::clock::Clock;
+use solracebench_executor::{name_key, Scenario};
+use solracebench_manifest::{load_all, load_case, Case, Category, ClockField, Label};
+use solracebench_oracle::{perturb_scenario, perturbations, Bounds, Difference};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn case(id: &str) -> Case {
+    let root = root();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+#[test]
+fn ccd_cases_are_sensitive_to_their_clock_fields() {
+    let mut mismatches = Vec::new();
+    for case in load_all(&root()).unwrap() {
+        // epochstart15 只在 epoch_start_timestamp 恰好等于常量 333 时跳过 sink，合理的扰动碰不到
+        if case.category != Category::Ccd || case.id == "ccd/epochstart15" {
+            continue;
+        }
+        let report = perturb_scenario(&scenario(&case), &Bounds::default());
+        let sensitive = report.sensitive_fields();
+        // 安全孪生在界限内的任何扰动下都得到相同结果
+        if case.label == Label::Safe && !sensitive.is_empty() {
+            mismatches.push(format!("{}: sensitive {sensitive:?}", case.id));
+        }
+        for finding in &case.findings {
+            if !finding.clock_fields.iter().any(|f| sensitive.contains(f)) {
+                mismatches.push(format!(
+                    "{}: declared {:?}, sensitive {sensitive:?}",
+                    case.id, finding.clock_fields
+                ));
+            }
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+#[test]
+fn perturbations_stay_within_bounds() {
+    let clock = Clock {
+        slot: 100,
+        epoch_start_timestamp: 1_700_000_000,
+        epoch: 3,
+        leader_schedule_epoch: 4,
+        unix_timestamp: 1_700_000_100,
+    };
+    let bounds = Bounds::default();
+    let all = perturbations(&clock, &bounds);
+    for field in ClockField::ALL {
+        assert!(all.iter().any(|p| p.field == *field), "{field}");
+    }
+    for p in &all {
+        let delta = (p.value() - perturbation_base(&clock, p.field)).abs();
+        let limit = match p.field {
+            ClockField::Slot => bounds.max_skipped_slots as i128,
+            ClockField::UnixTimestamp => bounds.max_drift_secs as i128,
+            ClockField::Epoch => bounds.max_epochs as i128,
+            ClockField::EpochStartTimestamp => 432_000 * 400 / 1000,
+            ClockField::LeaderScheduleEpoch => 2,
+        };
+        assert!(delta > 0 && delta <= limit, "{p}");
+    }
+    assert!(all
+        .iter()
+        .any(|p| p.field == ClockField::Slot && p.clock.slot == 250));
+    assert!(all
+        .iter()
+        .any(|p| p.field == ClockField::LeaderScheduleEpoch && p.clock.leader_schedule_epoch == 3));
+}
+
+fn perturbation_base(clock: &Clock, field: ClockField) -> i128 {
+    match field {
+        ClockField::Slot => clock.slot.into(),
+        ClockField::EpochStartTimestamp => clock.epoch_start_timestamp.into(),
+        ClockField::Epoch => clock.epoch.into(),
+        ClockField::LeaderScheduleEpoch => clock.leader_schedule_epoch.into(),
+        ClockField::UnixTimestamp => clock.unix_timestamp.into(),
+    }
+}
+
+#[test]
+fn mixslotts20_activation_depends_on_both_fields() {
+    let report = perturb_scenario(&scenario(&case("ccd/mixslotts20")), &Bounds::default());
+    let state = name_key("state");
+    let is_active = |accounts: &[solracebench_executor::Account]| {
+        accounts.iter().find(|a| a.key == state).unwrap().data[16]
+    };
+    assert_eq!(is_active(&report.baseline.accounts), 1);
+    assert_eq!(
+        report.sensitive_fields(),
+        [ClockField::Slot, ClockField::UnixTimestamp].into()
+    );
+    // 少一个 slot 或早一秒都不会激活
+    for o in report.divergent() {
+        let lower = match o.perturbation.field {
+            ClockField::Slot => o.perturbation.clock.slot <= 100,
+            _ => o.perturbation.clock.unix_timestamp <= 3600,
+        };
+        assert_eq!(
+            is_active(&o.state.accounts),
+            u8::from(!lower),
+            "{}",
+            o.perturbation
+        );
+    }
+}
+
+#[test]
+fn ccd28_loan_is_refused_after_a_second_of_drift() {
+    let report = perturb_scenario(&scenario(&case("ccd/ccd28")), &Bounds::default());
+    assert_eq!(
+        report.sensitive_fields(),
+        [ClockField::UnixTimestamp].into()
+    );
+    let late = report
+        .observations
+        .iter()
+        .find(|o| o.perturbation.clock.unix_timestamp == 1_700_000_060)
+        .unwrap();
+    assert!(late
+        .differences
+        .contains(&Difference::Data(name_key("pool"))));
+    let early = report
+        .observations
+        .iter()
+        .find(|o| o.perturbation.clock.unix_timestamp == 1_700_000_058)
+        .unwrap();
+    assert!(!early
+        .differences
+        .contains(&Difference::Data(name_key("pool"))));
+}
@@ -0,0 +1,102 @@
+use std::path::PathBuf;
+
+use solana_program::epoch_schedule::EpochSchedule;
+use solracebench_executor::{clock, Scenario};
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{boundary_slots, clock_at, place, Difference};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+fn epoch_cases() -> Vec<Case> {
+    load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Epoch)
+        .collect()
+}
+
+#[test]
+fn warmup_epochs_double_until_slots_per_epoch() {
+    let schedule = EpochSchedule::custom(8192, 8192, true);
+    assert_eq!(boundary_slots(&schedule, 0..=1), [0, 31, 32, 95]);
+    // warmup 在 slot 8160 结束，之后每个 epoch 8192 个 slot
+    assert_eq!(boundary_slots(&schedule, 12..=12), [40_928, 49_119]);
+}
+
+/// trigger 中的时钟与按 epoch 划分推算的一致
+#[test]
+fn trigger_clocks_follow_the_epoch_schedule() {
+    for case in epoch_cases() {
+        let scenario = scenario(&case);
+        let schedule = scenario.executor.epoch_schedule();
+        let base = &scenario.steps[0].clock;
+        for (i, step) in case.trigger.steps.iter().enumerate() {
+            let expected = clock_at(schedule, base, step.clock.slot);
+            assert_eq!(clock(&step.clock), expected, "{} step {i}", case.id);
+        }
+    }
+}
+
+#[test]
+fn only_vulnerable_epoch_cases_are_boundary_sensitive() {
+    let cases = epoch_cases();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let report = place(&scenario(case));
+        match case.label {
+            Label::Vulnerable => assert!(report.is_boundary_sensitive(), "{}", case.id),
+            Label::Safe => assert!(
+                !report.is_boundary_sensitive(),
+                "{}: {:?}",
+                case.id,
+                report.conflicts
+            ),
+        }
+    }
+}
+
+/// 在同一个 epoch 的第一个和最后一个 slot 领取时，第二次领取只在后者成功
+#[test]
+fn a_second_claim_succeeds_only_at_the_end_of_the_epoch() {
+    let root = root();
+    for id in ["epoch/vesting1", "epoch/tips3"] {
+        let report = place(&scenario(&load_case(&root, &root.join(id)).unwrap()));
+        assert!(
+            report.conflicts.iter().any(|conflict| {
+                let first = &report.placements[conflict.first];
+                let second = &report.placements[conflict.second];
+                first.epochs[0] == first.epochs[1]
+                    && conflict.differences.contains(&Difference::Result(1))
+                    && first.state.results[1].is_err()
+                    && second.state.results[1].is_ok()
+            }),
+            "{id}: {:?}",
+            report.conflicts
+        );
+    }
+}
+
+/// 跨过边界前在 epoch 最后一个 slot 结算，这个 epoch 的奖励就丢了
+#[test]
+fn settling_on_the_last_slot_skips_a_reward() {
+    let root = root();
+    let report = place(&scenario(
+        &load_case(&root, &root.join("epoch/staking2")).unwrap(),
+    ));
+    let skipped = report.conflicts.iter().any(|conflict| {
+        conflict
+            .changes
+            .iter()
+            .any(|(_, change)| change.field == "rewards")
+    });
+    assert!(skipped, "{:?}", report.conflicts);
+}
@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{float_order, Agreement, FloatReport, RELATIVE_EPSILON};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn report(case: &Case, epsilon: f64) -> FloatReport {
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    float_order(&scenario, case.trigger.setup, epsilon)
+}
+
+fn load(id: &str) -> Case {
+    let root = root();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+/// 带 race 的 float-order finding 交换顺序后至少按位不同，safe 的 twin 在任何顺序下都相同
+#[test]
+fn float_order_races_diverge_only_in_vulnerable_cases() {
+    let mut checked = 0;
+    for case in load_all(&root()).unwrap() {
+        let races = case
+            .findings
+            .iter()
+            .any(|f| f.class == Category::FloatOrder && f.race.is_some());
+        if races {
+            let report = report(&case, RELATIVE_EPSILON);
+            assert!(report.is_bitwise_divergent(), "{}", case.id);
+            checked += 1;
+        } else if case.category == Category::FloatOrder && case.label == Label::Safe {
+            let report = report(&case, RELATIVE_EPSILON);
+            assert_eq!(report.worst(), Agreement::Identical, "{}", case.id);
+        }
+    }
+    assert!(checked >= 5);
+}
+
+/// tod20 的两次乘法交换顺序后只差末位：按位不同，但在误差范围以内
+#[test]
+fn tod20_differs_only_by_rounding() {
+    let case = load("tod/tod20");
+    let rounded = report(&case, RELATIVE_EPSILON);
+    assert_eq!(rounded.worst(), Agreement::WithinEpsilon);
+    let drift = &rounded.comparisons[0].drifts[0];
+    assert_eq!(drift.field, "dynamic_factor");
+    assert_ne!(drift.baseline.to_bits(), drift.value.to_bits());
+
+    // 误差收紧到 0 时按位比较
+    assert_eq!(report(&case, 0.0).worst(), Agreement::Divergent);
+}
+
+/// 学习率衰减的先后让权重相差约 0.001，远超 f32 的舍入误差；学习率本身与顺序无关
+#[test]
+fn tod23_diverges_beyond_epsilon() {
+    let report = report(&load("tod/tod23"), RELATIVE_EPSILON);
+    assert!(report.is_divergent());
+    let fields: Vec<&str> = report.comparisons[0]
+        .drifts
+        .iter()
+        .map(|d| d.field.as_str())
+        .collect();
+    assert_eq!(fields, ["weights[0]", "weights[1]", "weights[2]"]);
+}
+
+/// 0.1 最后到达时三笔出资都被接受，原顺序下第三笔被拒绝
+#[test]
+fn pool1_cap_depends_on_contribution_order() {
+    let report = report(&load("float-order/pool1"), RELATIVE_EPSILON);
+    assert!(report.baseline.state.results[2].is_err());
+    let accepted: Vec<&[usize]> = report
+        .comparisons
+        .iter()
+        .filter(|c| c.agreement == Agreement::Divergent)
+        .map(|c| c.order.as_slice())
+        .collect();
+    assert!(accepted.contains(&&[2, 1, 0][..]));
+    assert!(accepted.contains(&&[1, 2, 0][..]));
+}
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{front_run, InitReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn report(case: &Case) -> (Scenario, InitReport) {
+    let attack = case
+        .trigger
+        .attack
+        .unwrap_or_else(|| panic!("{} names no attack step", case.id));
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    let report = front_run(&scenario, case.trigger.setup, attack);
+    (scenario, report)
+}
+
+/// 有漏洞的用例里攻击者抢先初始化后掌握了账户；对照用例把初始化绑定到预期的权限，攻击者在任何位置都初始化不了
+#[test]
+fn only_vulnerable_init_cases_are_front_runnable() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Init)
+        .collect();
+    assert!(cases.len() >= 10);
+    for case in &cases {
+        let (scenario, report) = report(case);
+        let attacker = scenario.key("attacker");
+        match case.label {
+            Label::Vulnerable => {
+                assert!(report.is_front_runnable(), "{}", case.id);
+                assert!(
+                    report.runs.iter().any(|r| r.ok && r.captures(&attacker)),
+                    "{}: the attacker never takes over",
+                    case.id
+                );
+            }
+            Label::Safe => {
+                assert!(!report.is_front_runnable(), "{}", case.id);
+                for run in &report.runs {
+                    assert!(!run.ok, "{} at {}", case.id, run.position);
+                    assert!(
+                        run.differences.is_empty(),
+                        "{} at {}",
+                        case.id,
+                        run.position
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// 攻击者只有排在 victim 的初始化（或认领）之前才能成功
+#[test]
+fn init_races_are_won_only_before_the_victim_initializes() {
+    let root = root();
+    for (id, winning) in [("init/config1", 0), ("init/admin2", 1), ("init/vault3", 0)] {
+        let case = load_case(&root, &root.join(id)).unwrap();
+        let (_, report) = report(&case);
+        let won: Vec<usize> = report
+            .runs
+            .iter()
+            .filter(|r| r.ok)
+            .map(|r| r.position)
+            .collect();
+        assert_eq!(won, [winning], "{id}");
+    }
+}
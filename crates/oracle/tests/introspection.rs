@@ -0,0 +1,98 @@
+use std::{ops::Range, path::PathBuf};
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{arrangements, rearrange, IntrospectionReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+/// trigger 中第一笔含多条指令的交易
+fn transaction(scenario: &Scenario) -> Range<usize> {
+    scenario
+        .transactions()
+        .into_iter()
+        .find(|t| t.len() > 1)
+        .expect("a multi-instruction transaction")
+}
+
+fn report(scenario: &Scenario) -> IntrospectionReport {
+    rearrange(
+        scenario,
+        transaction(scenario),
+        &scenario.key("attacker_wallet"),
+    )
+}
+
+#[test]
+fn arrangements_permute_and_duplicate() {
+    let orders = arrangements(0..2);
+    assert_eq!(orders[..2], [vec![0, 1], vec![1, 0]]);
+    assert!(orders.contains(&vec![0, 1, 0]));
+    assert!(orders.contains(&vec![1, 1, 0]));
+    // 2 种排列，加上 2 × 2 × 3 种插入去重后剩下的 6 种
+    assert_eq!(orders.len(), 8);
+}
+
+/// 有漏洞的用例里某种排列让攻击者净赚；对照用例里每种成功的排列都要付手续费
+#[test]
+fn only_vulnerable_introspection_cases_are_exploitable() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Introspection)
+        .collect();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let report = report(&scenario(case));
+        match case.label {
+            Label::Vulnerable => assert!(report.is_exploitable(), "{}", case.id),
+            Label::Safe => {
+                assert!(!report.is_exploitable(), "{}", case.id);
+                for arrangement in report.arrangements.iter().filter(|a| a.is_ok()) {
+                    assert!(
+                        arrangement.gain.values().any(|&change| change < 0),
+                        "{}: {:?} {:?}",
+                        case.id,
+                        arrangement.order,
+                        arrangement.gain
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// 只有借款和还款的交易本身没问题，插入第二次借款后才能借到不用还的钱
+#[test]
+fn a_duplicated_borrow_escapes_an_absolute_index_check() {
+    let root = root();
+    for (id, exploitable) in [
+        ("introspection/flashloan3", true),
+        ("introspection/flashloan3_safe", false),
+    ] {
+        let case = load_case(&root, &root.join(id)).unwrap();
+        let mut scenario = scenario(&case);
+        scenario.steps.truncate(2);
+        let report = report(&scenario);
+        let winning: Vec<&[usize]> = report
+            .arrangements
+            .iter()
+            .filter(|a| a.is_ok() && a.gain.values().any(|&change| change > 0))
+            .map(|a| &a.order[..])
+            .collect();
+        if exploitable {
+            assert_eq!(winning, [&[0, 1, 0][..]], "{id}");
+        } else {
+            assert!(winning.is_empty(), "{id}: {winning:?}");
+        }
+    }
+}
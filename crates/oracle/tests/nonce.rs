@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use solana_program::program_error::ProgramError;
+use solracebench_executor::{Failure, Scenario};
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{hold, holds, NonceReport, MAX_PROCESSING_AGE};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+/// trigger 中用 durable nonce 签名的那一步和签名时的 slot
+fn signed(case: &Case) -> (usize, u64) {
+    let (step, name) = case
+        .trigger
+        .steps
+        .iter()
+        .enumerate()
+        .find_map(|(i, step)| Some((i, step.nonce.as_ref()?)))
+        .unwrap_or_else(|| panic!("{} signs no step with a durable nonce", case.id));
+    (
+        step,
+        case.trigger.accounts[name].nonce.as_ref().unwrap().slot,
+    )
+}
+
+fn report(case: &Case, slots: Option<&[u64]>) -> NonceReport {
+    let (step, signed) = signed(case);
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    let slots = match slots {
+        Some(slots) => slots.to_vec(),
+        None => holds(&scenario, signed, 10_000_000),
+    };
+    hold(&scenario, step, signed, &slots)
+}
+
+/// 有漏洞的用例里扣留到 blockhash 过期之后的交易仍然落地，并且结果与按时落地不同；
+/// 对照用例里这样的交易要么被拒绝，要么与按时落地的结果完全相同
+#[test]
+fn only_vulnerable_nonce_cases_are_delayable() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Nonce)
+        .collect();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let report = report(case, None);
+        assert!(
+            report
+                .landings
+                .iter()
+                .all(|l| l.slot > report.signed + MAX_PROCESSING_AGE),
+            "{}",
+            case.id
+        );
+        match case.label {
+            Label::Vulnerable => assert!(report.is_delayable(), "{}", case.id),
+            Label::Safe => {
+                assert!(!report.is_delayable(), "{}", case.id);
+                for landing in report.landings.iter().filter(|l| l.result.is_ok()) {
+                    assert!(
+                        landing.differences.is_empty(),
+                        "{} at {}: {:?}",
+                        case.id,
+                        landing.slot,
+                        landing.differences
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// 出价在本轮截止前落地与按时落地相同，截止后结算前落地被拒绝，结算之后落地则进了下一轮
+#[test]
+fn held_bids_change_rounds_only_after_settlement() {
+    let root = root();
+    let case = load_case(&root, &root.join("nonce/bid1")).unwrap();
+    let report = report(&case, Some(&[1151, 2600, 3000]));
+    let [early, expired, settled] = &report.landings[..] else {
+        panic!("three landings");
+    };
+    assert_eq!(early.result, Ok(()));
+    assert!(early.differences.is_empty(), "{:?}", early.differences);
+    assert_eq!(expired.result, Err(Failure::Error(ProgramError::Custom(1))));
+    assert_eq!(settled.result, Ok(()));
+    assert!(settled
+        .changes
+        .iter()
+        .any(|(_, change)| change.field == "highest_bid"));
+}
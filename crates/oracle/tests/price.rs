@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use solana_program::clock::Clock;
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{age_price, ages, estimated_timestamp, timestamp_bounds, PriceReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+/// 一天之内的价格年龄下，trigger 中第一个读取价格账户的步骤
+fn report(case: &Case) -> PriceReport {
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    let (name, _) = case
+        .trigger
+        .accounts
+        .iter()
+        .find(|(_, spec)| spec.price.is_some())
+        .expect("a price account");
+    let step = case
+        .trigger
+        .steps
+        .iter()
+        .position(|step| step.accounts.contains(name))
+        .unwrap();
+    age_price(&scenario, step, &scenario.key(name), &ages(86_400))
+}
+
+#[test]
+fn timestamps_may_drift_from_the_poh_estimate() {
+    let clock = Clock {
+        slot: 297_092_256,
+        epoch_start_timestamp: 1_700_000_000,
+        epoch: 700,
+        leader_schedule_epoch: 701,
+        unix_timestamp: 0,
+    };
+    // epoch 700 从 slot 296876256 开始，之后 216000 个 slot 是 86400 秒
+    assert_eq!(estimated_timestamp(&clock), 1_700_086_400);
+    assert_eq!(timestamp_bounds(&clock), 1_700_064_800..=1_700_216_000);
+    assert_eq!(ages(86_400), [61, 244, 976, 3904, 15_616, 62_464]);
+}
+
+#[test]
+fn only_vulnerable_price_cases_accept_stale_prices() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Price)
+        .collect();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let report = report(case);
+        match case.label {
+            Label::Vulnerable => assert!(report.is_exploitable(), "{}", case.id),
+            Label::Safe => assert!(!report.is_exploitable(), "{}: {report:?}", case.id),
+        }
+    }
+}
+
+/// lend1 在 PoH 推算的时间戳下能拒绝过期价格，只有 leader 把时间戳调慢后才会接受
+#[test]
+fn a_slow_leader_timestamp_makes_a_stale_price_fresh() {
+    let root = root();
+    let report = |id: &str| self::report(&load_case(&root, &root.join(id)).unwrap());
+
+    let lend = report("price/lend1");
+    assert!(lend.needs_shift());
+    let stalest = lend
+        .attempts
+        .iter()
+        .filter(|a| a.result.is_ok())
+        .map(|a| a.age)
+        .max();
+    // 最多能慢 21600 秒
+    assert_eq!(stalest, Some(15_616));
+
+    // 另外两个用例不看 unix_timestamp，过期价格在推算的时间戳下就能通过
+    for id in ["price/liquidate2", "price/settle3"] {
+        let report = report(id);
+        assert!(report.is_exploitable() && !report.needs_shift(), "{id}");
+    }
+}
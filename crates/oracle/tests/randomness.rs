@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, Label};
+use solracebench_oracle::{predict, submissions, Prediction};
+
+/// 玩家可以等待的 slot 数
+const SLOTS: u64 = 64;
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn prediction(case: &Case) -> Prediction {
+    let play = case
+        .trigger
+        .play
+        .unwrap_or_else(|| panic!("{} names no play step", case.id));
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    let data = case.trigger.steps[play].data.as_slice();
+    let submissions = submissions(&case.instructions, data, SLOTS);
+    predict(&scenario, play, &scenario.key("player"), &submissions)
+}
+
+/// 有漏洞的用例里玩家总能在提交前挑出获胜的提交方式，承诺–揭示的对照用例在玩家提交时还没有开奖
+#[test]
+fn only_vulnerable_randomness_cases_are_predictable() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Randomness)
+        .collect();
+    assert!(cases.len() >= 24);
+    for case in &cases {
+        let prediction = prediction(case);
+        match case.label {
+            Label::Vulnerable => {
+                assert!(prediction.is_predictable(), "{}", case.id);
+                // trigger 本身就是挑中的一种提交方式
+                let play = &case.trigger.steps[prediction.step];
+                assert!(
+                    prediction
+                        .winning()
+                        .any(|d| d.submission.delay == 0 && d.submission.data == play.data.0),
+                    "{}: the trigger does not win",
+                    case.id
+                );
+            }
+            Label::Safe => {
+                assert!(!prediction.is_predictable(), "{}", case.id);
+                assert_eq!(prediction.winning().count(), 0, "{}", case.id);
+            }
+        }
+    }
+}
+
+#[test]
+fn coin_flips_follow_the_landing_slot() {
+    let root = root();
+    let case = load_case(&root, &root.join("randomness/coinflip1")).unwrap();
+    let prediction = prediction(&case);
+    let slot = case.trigger.steps[0].clock.slot;
+    for draw in &prediction.draws {
+        let side = (slot + draw.submission.delay) % 2;
+        let wins = draw.submission.data == [side as u8];
+        assert_eq!(draw.gain > 0, wins, "{:?}", draw.submission);
+        assert!(draw.ok);
+    }
+}
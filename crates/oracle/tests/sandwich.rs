@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use solana_program::program_error::ProgramError;
+use solracebench_executor::{Failure, Scenario};
+use solracebench_manifest::{load_all, Case, Category, Label};
+use solracebench_oracle::{sandwich, SandwichReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn cases() -> Vec<Case> {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::Sandwich)
+        .collect();
+    assert!(cases.len() >= 6);
+    cases
+}
+
+fn report(case: &Case, hold: u64) -> SandwichReport {
+    let steps = case
+        .trigger
+        .sandwich
+        .unwrap_or_else(|| panic!("{} names no sandwich steps", case.id));
+    let scenario = Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap();
+    sandwich(
+        &scenario,
+        steps,
+        hold,
+        &scenario.key("attacker_wallet"),
+        &scenario.key("victim_wallet"),
+    )
+}
+
+/// 没有数量界限时攻击者净赚、受害者少得；对照用例里受害者的交易被拒绝，攻击者只付了手续费
+#[test]
+fn only_vulnerable_sandwich_cases_leak_value() {
+    for case in &cases() {
+        let report = report(case, 0);
+        match case.label {
+            Label::Vulnerable => {
+                assert!(
+                    report.is_extractable(),
+                    "{}: {:?}",
+                    case.id,
+                    report.extracted
+                );
+                assert!(
+                    report.victim.values().any(|&change| change < 0),
+                    "{}: {:?}",
+                    case.id,
+                    report.victim
+                );
+            }
+            Label::Safe => {
+                assert!(!report.is_extractable(), "{}", case.id);
+                assert_eq!(
+                    report.results[1],
+                    Err(Failure::Error(ProgramError::Custom(1))),
+                    "{}",
+                    case.id
+                );
+                assert!(
+                    report.extracted.values().all(|&change| change <= 0),
+                    "{}: {:?}",
+                    case.id,
+                    report.extracted
+                );
+            }
+        }
+    }
+}
+
+/// 出块者把受害者的交易扣留到截止 slot 之后，对照用例拒绝执行
+#[test]
+fn held_victim_trades_miss_their_deadline() {
+    for case in &cases() {
+        let report = report(case, 100);
+        match case.label {
+            Label::Vulnerable => assert!(report.is_extractable(), "{}", case.id),
+            Label::Safe => assert_eq!(
+                report.results[1],
+                Err(Failure::Error(ProgramError::Custom(2))),
+                "{}",
+                case.id
+            ),
+        }
+    }
+}
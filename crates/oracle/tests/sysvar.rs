@@ -0,0 +1,152 @@
+use std::path::PathBuf;
+
+use solana_program::pubkey::Pubkey;
+use solana_sdk_ids::sysvar;
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category};
+use solracebench_oracle::{spoof, spoof_clock, ClockForgery, Difference};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn case(id: &str) -> Case {
+    let root = root();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+/// 把 trigger 里的 `fake_x` 换回真实的 sysvar 账户 `x`，得到 oracle 的输入
+fn honest(case: &Case) -> Case {
+    let mut case = case.clone();
+    for step in &mut case.trigger.steps {
+        for name in &mut step.accounts {
+            if let Some(real) = name.strip_prefix("fake_") {
+                *name = real.to_owned();
+            }
+        }
+    }
+    case
+}
+
+#[test]
+fn sysvar_clock_cases_accept_a_forged_clock() {
+    for id in ["sysvar/clock1", "sysvar/clock2"] {
+        let vulnerable = spoof_clock(&scenario(&honest(&case(id))));
+        assert_eq!(vulnerable.len(), ClockForgery::ALL.len(), "{id}");
+        assert!(vulnerable.iter().any(|(_, r)| r.is_spoofable()), "{id}");
+
+        let safe = format!("{id}_safe");
+        for (forgery, report) in spoof_clock(&scenario(&honest(&case(&safe)))) {
+            assert!(!report.is_spoofable(), "{safe}: {forgery}");
+            assert!(report.accepted.is_empty(), "{safe}: {forgery}");
+        }
+    }
+}
+
+/// 只有带 Clock 相关 sysvar finding 的用例会接受伪造的 Clock 账户
+///
+/// timestamp5/6 用 bincode 直接解析传入的账户；ccd21/22/28/30/31 虽然不检查账户
+/// （ccd30/31 甚至把它命名为 `fake_clock`），但 `Clock::from_account_info` 会校验 key。
+#[test]
+fn forged_clocks_are_accepted_only_where_a_finding_says_so() {
+    let mut mismatches = Vec::new();
+    for case in load_all(&root()).unwrap() {
+        let expected = case
+            .findings
+            .iter()
+            .any(|f| f.class == Category::Sysvar && !f.clock_fields.is_empty());
+        let spoofable: Vec<ClockForgery> = spoof_clock(&scenario(&case))
+            .into_iter()
+            .filter(|(_, report)| report.is_spoofable())
+            .map(|(forgery, _)| forgery)
+            .collect();
+        if spoofable.is_empty() == expected {
+            mismatches.push(format!("{}: spoofable with {spoofable:?}", case.id));
+        }
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+
+    for id in [
+        "ccd/ccd21",
+        "ccd/ccd22",
+        "ccd/ccd28",
+        "ccd/ccd30",
+        "ccd/ccd31",
+    ] {
+        let reports = spoof_clock(&scenario(&case(id)));
+        assert!(!reports.is_empty(), "{id} passes no clock account");
+        for (forgery, report) in reports {
+            assert!(report.accepted.is_empty(), "{id}: {forgery}");
+            assert!(report.differences.contains(&Difference::Result(0)), "{id}");
+        }
+    }
+}
+
+/// 用 trigger 中 `fake_x` 账户的数据伪造 sysvar 账户 `x`
+fn spoof_with_trigger_data(id: &str, name: &str, sysvar: &Pubkey) -> bool {
+    let case = case(id);
+    let forged = case.trigger.accounts[&format!("fake_{name}")]
+        .data
+        .0
+        .clone();
+    let report = spoof(&scenario(&honest(&case)), sysvar, |_| forged.clone())
+        .unwrap_or_else(|| panic!("{id} passes no {name} account"));
+    report.is_spoofable()
+}
+
+#[test]
+fn forged_sysvar_accounts_are_accepted_only_without_check_id() {
+    for (id, name, sysvar) in [
+        ("sysvar/rent3", "rent", sysvar::rent::ID),
+        (
+            "sysvar/epochschedule4",
+            "epoch_schedule",
+            sysvar::epoch_schedule::ID,
+        ),
+        ("sysvar/slothashes5", "slot_hashes", sysvar::slot_hashes::ID),
+        (
+            "sysvar/instructions6",
+            "instructions",
+            sysvar::instructions::ID,
+        ),
+    ] {
+        assert!(spoof_with_trigger_data(id, name, &sysvar), "{id}");
+        let safe = format!("{id}_safe");
+        assert!(!spoof_with_trigger_data(&safe, name, &sysvar), "{safe}");
+    }
+}
+
+#[test]
+fn honest_sysvar_cases_use_generated_sysvar_data() {
+    let mut slot_hashes = scenario(&honest(&case("sysvar/slothashes5")));
+    let results: Vec<bool> = slot_hashes.run().iter().map(|o| o.is_ok()).collect();
+    // 伪造的 slot 1000000000 不在真实的 SlotHashes 里
+    assert_eq!(results, [true, false, true]);
+
+    let mut instructions = scenario(&honest(&case("sysvar/instructions6")));
+    let outcomes = instructions.run();
+    assert!(
+        !outcomes[0].is_ok(),
+        "only the redeem instruction is in the transaction"
+    );
+}
+
+/// 伪造的一侧和基线一样按交易提交：同一笔交易的后续指令随第一条的失败一起回滚，
+/// 差异只出现在读取伪造账户的那一步
+#[test]
+fn forged_replays_keep_transactions_together() {
+    let scenario = scenario(&case("introspection/flashloan1_safe"));
+    let report = spoof(&scenario, &sysvar::instructions::ID, |_| vec![0; 8]).unwrap();
+    assert_eq!(report.state.results[1], report.baseline.results[1]);
+    assert_eq!(
+        report.differences,
+        [Difference::Result(0), Difference::Logs(0)]
+    );
+}
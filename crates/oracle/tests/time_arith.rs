@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+
+use solracebench_executor::Scenario;
+use solracebench_manifest::{load_all, load_case, Case, Category, ClockField, Label};
+use solracebench_oracle::{probe_time, time_probes, Divergence, TimeArithReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+fn load(id: &str) -> Case {
+    let root = root();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+/// 每一步、finding 标注的每个时钟字段各一份报告
+fn reports(case: &Case) -> Vec<TimeArithReport> {
+    let scenario = scenario(case);
+    let mut fields: Vec<ClockField> = case
+        .findings
+        .iter()
+        .flat_map(|f| f.clock_fields.iter().copied())
+        .collect();
+    fields.sort_unstable();
+    fields.dedup();
+    (0..scenario.steps.len())
+        .flat_map(|step| fields.iter().map(move |&field| (step, field)))
+        .map(|(step, field)| probe_time(&scenario, step, field))
+        .collect()
+}
+
+#[test]
+fn probes_cover_type_bounds_and_anchors() {
+    let values = time_probes(ClockField::UnixTimestamp, &[1_700_000_000]);
+    for v in [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX] {
+        assert!(values.contains(&v.into()), "{v}");
+    }
+    assert!(values.contains(&1_699_999_999) && values.contains(&1_700_000_001));
+    assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+    let slots = time_probes(ClockField::Slot, &[0]);
+    assert!(slots.iter().all(|&v| v >= 0));
+    assert!(slots.contains(&u64::MAX.into()));
+}
+
+#[test]
+fn only_vulnerable_time_arith_cases_panic_or_diverge() {
+    let cases: Vec<Case> = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .filter(|c| c.category == Category::TimeArith)
+        .collect();
+    assert!(cases.len() >= 6);
+    for case in &cases {
+        let reports = reports(case);
+        let flagged = reports
+            .iter()
+            .any(|r| !r.panics().is_empty() || r.is_divergent());
+        match case.label {
+            Label::Vulnerable => assert!(flagged, "{}", case.id),
+            Label::Safe => assert!(!flagged, "{}: {reports:?}", case.id),
+        }
+    }
+}
+
+/// 尚未开始的归属在开始之前全部可领，开始之后反而领不到：已领取数量随时间先降后升
+#[test]
+fn grant1_vests_everything_before_the_start() {
+    let case = load("time-arith/grant1");
+    let scenario = scenario(&case);
+    let scheduled = scenario.keys["scheduled"];
+    let report = probe_time(&scenario, 1, ClockField::UnixTimestamp);
+    assert!(report
+        .divergences
+        .contains(&Divergence::Field(scheduled, "claimed".to_owned())));
+    assert!(report.divergences.contains(&Divergence::Result(1)));
+    // now - start 在 now 接近 i64::MIN 时溢出，和逻辑错误分开报告
+    assert!(report.overflows().contains(&i64::MIN.into()));
+}
+
+/// 从未领取的 last_claim 是 i64::MIN，任何非负的时间戳都让冷却检查溢出
+#[test]
+fn faucet2_overflows_on_the_first_claim() {
+    let case = load("time-arith/faucet2");
+    let report = probe_time(&scenario(&case), 1, ClockField::UnixTimestamp);
+    let overflows = report.overflows();
+    assert!(overflows.contains(&0) && overflows.contains(&1_700_000_000));
+    assert!(!overflows.contains(&-1));
+    assert!(!report.is_divergent());
+}
+
+/// 长期有效的订单在任何时间清理都溢出
+#[test]
+fn order3_overflows_for_good_till_cancelled_orders() {
+    let case = load("time-arith/order3");
+    let report = probe_time(&scenario(&case), 1, ClockField::UnixTimestamp);
+    assert_eq!(report.overflows().len(), report.probes.len());
+}
+
+/// 负的时间戳转成 u64 后越过阈值：日志在 0 附近消失，又在阈值之后出现
+#[test]
+fn timestamp4_logs_again_before_the_epoch() {
+    let case = load("ccd/timestamp4");
+    let report = probe_time(&scenario(&case), 0, ClockField::UnixTimestamp);
+    assert!(report.panics().is_empty());
+    assert_eq!(report.divergences, [Divergence::Logs(0)]);
+}
+
+/// 初始化时的 slot 大于结算时的 slot，结算时减法下溢
+#[test]
+fn ccd30_underflows_when_the_slot_goes_back() {
+    let case = load("ccd/ccd30");
+    let report = probe_time(&scenario(&case), 0, ClockField::Slot);
+    let overflows = report.overflows();
+    assert!(overflows.contains(&102) && overflows.contains(&u64::MAX.into()));
+    assert!(overflows.iter().all(|&slot| slot > 101));
+}
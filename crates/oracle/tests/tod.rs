@@ -0,0 +1,140 @@
+use std::{collections::BTreeSet, path::PathBuf};
+
+use solana_program::{clock::Clock, instruction::AccountMeta, instruction::Instruction};
+use solracebench_executor::{name_key, Account, Executor, Failure, Scenario};
+use solracebench_manifest::{load_all, Category, Label};
+use solracebench_oracle::{explore, explore_scenario, field_diff, Difference, TodReport};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+#[test]
+fn tod_cases_diverge_on_the_expected_pair() {
+    let mut mismatches = Vec::new();
+    for case in load_all(&root()).unwrap() {
+        if case.category != Category::Tod {
+            continue;
+        }
+        let processor = solracebench_cases::processor(&case.id).unwrap();
+        let scenario = Scenario::new(&case, processor).unwrap();
+        let setup = case.trigger.setup;
+        let report = explore_scenario(&scenario, setup);
+        let found: BTreeSet<[usize; 2]> = report
+            .divergent_pairs
+            .iter()
+            .map(|&(a, b)| [a + setup, b + setup])
+            .collect();
+        // 安全孪生的任意执行顺序都得到相同结果，或者只是有指令按约定被拒绝
+        let expected: BTreeSet<[usize; 2]> = match case.label {
+            Label::Vulnerable => case.findings.iter().filter_map(|f| f.race).collect(),
+            Label::Safe => BTreeSet::new(),
+        };
+        let rejected = case.label == Label::Safe && only_rejections_diverge(&report);
+        if found != expected && !rejected {
+            mismatches.push(format!(
+                "{}: found {found:?}, expected {expected:?}",
+                case.id
+            ));
+        }
+        assert_eq!(
+            report.groups().len() > 1,
+            report.is_divergent(),
+            "{}",
+            case.id
+        );
+    }
+    assert!(mismatches.is_empty(), "{mismatches:#?}");
+}
+
+/// 所有指令都成功的排列得到同一结果，其余排列只是有指令以错误（而不是 panic）被拒绝
+///
+/// 例如 tod2_safe 的 Transfer 携带调用方接受的最高费率：SetFee 抢先时转账被拒绝，
+/// 和 tod5_safe 拒绝非管理员关闭拍卖一样，拒绝本身就是安全的结果。
+fn only_rejections_diverge(report: &TodReport) -> bool {
+    let mut settled = report
+        .runs
+        .iter()
+        .filter(|run| run.state.results.iter().all(Result::is_ok));
+    let first = settled.next();
+    settled.all(|run| Some(&run.state) == first.map(|r| &r.state))
+        && report.runs.iter().all(|run| {
+            run.state
+                .results
+                .iter()
+                .all(|r| matches!(r, Ok(()) | Err(Failure::Error(_))))
+        })
+}
+
+fn tod1() -> (Executor, Instruction, Instruction) {
+    let program_id = name_key("tod1");
+    let mut executor = Executor::new();
+    executor.add_program(program_id, solracebench_cases::processor("tod1").unwrap());
+    let step = |account: &str, op: u8| Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(name_key(account), false)],
+        data: vec![op],
+    };
+    for name in ["a", "b"] {
+        executor.set_account(Account::new(name_key(name), program_id));
+        executor.execute(&step(name, 0), &Clock::default());
+    }
+    (executor, step("a", 0), step("b", 1))
+}
+
+#[test]
+fn instructions_on_disjoint_accounts_are_pruned() {
+    let (executor, increment_a, double_b) = tod1();
+    let report = explore(
+        &executor,
+        &[increment_a.clone(), double_b.clone(), increment_a],
+        &Clock::default(),
+    );
+    // Double 可以放在任意位置，只有两条 Increment 的先后不同：3! 个排列只剩 2 类
+    assert_eq!(report.runs.len(), 2);
+    assert_eq!(report.pruned, 4);
+    assert!(!report.is_divergent());
+}
+
+#[test]
+fn divergent_orders_report_the_differing_account() {
+    let (executor, increment_a, _) = tod1();
+    let double_a = Instruction {
+        data: vec![1],
+        ..increment_a.clone()
+    };
+    let report = explore(&executor, &[increment_a, double_a], &Clock::default());
+    assert_eq!(report.divergent_pairs, BTreeSet::from([(0, 1)]));
+    let groups = report.groups();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(
+        solracebench_oracle::diff(&groups[0][0].state, &groups[1][0].state),
+        [Difference::Data(name_key("a"))]
+    );
+}
+
+#[test]
+fn divergent_orders_report_the_differing_fields() {
+    let case = load_all(&root())
+        .unwrap()
+        .into_iter()
+        .find(|c| c.matches("tod2"))
+        .unwrap();
+    let processor = solracebench_cases::processor(&case.id).unwrap();
+    let scenario = Scenario::new(&case, processor).unwrap();
+    let report = explore_scenario(&scenario, case.trigger.setup);
+    let groups = report.groups();
+    assert_eq!(groups.len(), 2);
+    let fields: Vec<String> =
+        field_diff(&scenario.layouts, &groups[0][0].state, &groups[1][0].state)
+            .into_iter()
+            .map(|(key, change)| {
+                assert_eq!(key, scenario.key("account"));
+                change.to_string()
+            })
+            .collect();
+    assert_eq!(fields, ["balance: 800 -> 900"]);
+}
@@ -0,0 +1,157 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use solracebench_executor::{name_key, Account, Scenario};
+use solracebench_manifest::{load_all, load_case, Case, Field, Layout, Repr, Type};
+use solracebench_oracle::{
+    attackers, conservation, holdings, AssetOverflow, ValueReport, LAMPORTS,
+};
+
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap()
+}
+
+fn scenario(case: &Case) -> Scenario {
+    Scenario::new(case, solracebench_cases::processor(&case.id).unwrap()).unwrap()
+}
+
+fn run(id: &str) -> (Scenario, ValueReport) {
+    let root = root();
+    let scenario = scenario(&load_case(&root, &root.join(id)).unwrap());
+    let report = conservation(&scenario, &attackers(&scenario)).unwrap();
+    (scenario, report)
+}
+
+fn assets(entries: &[(&str, i128)]) -> BTreeMap<String, i128> {
+    entries
+        .iter()
+        .map(|&(asset, amount)| (asset.to_owned(), amount))
+        .collect()
+}
+
+/// 金库的 lamports 全部转给调用者，总量守恒
+#[test]
+fn slot1_moves_the_vault_to_the_user() {
+    let (scenario, report) = run("ccd/slot1");
+    assert_eq!(report.attackers, [scenario.key("user")]);
+    assert!(report.is_conserved());
+    assert!(report.is_profitable());
+    assert_eq!(report.gained(), assets(&[(LAMPORTS, 1_000_000)]));
+    assert_eq!(report.to_string(), "attacker gained 1000000 lamports");
+}
+
+/// 金库被清零，lamports 凭空消失；链上运行时会拒绝这条指令
+#[test]
+fn slot2_destroys_lamports() {
+    let (_, report) = run("ccd/slot2");
+    assert!(!report.is_conserved());
+    assert_eq!(
+        report.flows[0].imbalances(),
+        assets(&[(LAMPORTS, -1_000_000)])
+    );
+    assert!(report.gained().is_empty());
+    assert_eq!(
+        report.to_string(),
+        "attacker gained nothing; steps 0..1 destroyed 1000000 lamports"
+    );
+}
+
+/// 用户拿到金库记录的 amount，记录随之清零而没有转给任何账户
+#[test]
+fn epoch19_pays_out_the_recorded_deposit() {
+    let (scenario, report) = run("ccd/epoch19");
+    assert_eq!(report.gained(), assets(&[(LAMPORTS, 3)]));
+    assert_eq!(report.flows[0].imbalances(), assets(&[("deposit", -3)]));
+    assert_eq!(
+        report
+            .net()
+            .get(&(scenario.key("vault"), "deposit".to_owned())),
+        Some(&-3)
+    );
+}
+
+/// 借出的代币从池子记到攻击者钱包，代币守恒但攻击者净得全部借款
+#[test]
+fn flashloan1_attacker_keeps_the_loan() {
+    let (scenario, report) = run("introspection/flashloan1");
+    assert!(report.attackers.contains(&scenario.key("attacker_wallet")));
+    assert!(report.is_conserved());
+    assert_eq!(report.gained(), assets(&[("token", 1_000_000)]));
+}
+
+/// 伪造的兑换券凭空记入 500 credit；safe 的 twin 拒绝兑换，没有资产变化
+#[test]
+fn voucher2_credits_out_of_nothing() {
+    let (scenario, report) = run("client-time/voucher2");
+    assert_eq!(report.flows[1].imbalances(), assets(&[("credit", 500)]));
+    assert_eq!(
+        report
+            .net()
+            .get(&(scenario.key("wallet"), "credit".to_owned())),
+        Some(&500)
+    );
+
+    let (_, safe) = run("client-time/voucher2_safe");
+    assert!(safe.is_conserved());
+    assert!(safe.net().is_empty());
+}
+
+/// 执行器本身从不凭空产生或抹去 lamports：只有直接改写余额的 slot2 不守恒
+#[test]
+fn only_slot2_breaks_lamport_conservation() {
+    let unbalanced: Vec<String> = load_all(&root())
+        .unwrap()
+        .iter()
+        .filter(|case| {
+            let scenario = scenario(case);
+            conservation(&scenario, &[])
+                .unwrap()
+                .flows
+                .iter()
+                .any(|flow| flow.imbalances().contains_key(LAMPORTS))
+        })
+        .map(|case| case.id.clone())
+        .collect();
+    assert_eq!(unbalanced, ["ccd/slot2"]);
+}
+
+/// u128 资产字段照样计入；超出 i128 的数额报错，而不是当作守恒跳过
+#[test]
+fn u128_assets_are_counted_or_rejected() {
+    let root = root();
+    let mut scenario = scenario(&load_case(&root, &root.join("ccd/slot1")).unwrap());
+    let pool = name_key("pool");
+    scenario.layouts.insert(
+        pool,
+        Layout {
+            name: "Pool".to_owned(),
+            repr: Repr::C,
+            fields: vec![Field {
+                name: "supply".to_owned(),
+                ty: Type::U128,
+                min: None,
+                max: None,
+                asset: Some("share".to_owned()),
+            }],
+        },
+    );
+    let mut account = Account::new(pool, scenario.program_id);
+    account.data = (1u128 << 100).to_le_bytes().to_vec();
+    scenario.executor.set_account(account.clone());
+    assert_eq!(
+        holdings(&scenario).unwrap()[&(pool, "share".to_owned())],
+        1 << 100
+    );
+    assert!(conservation(&scenario, &[]).unwrap().is_conserved());
+
+    account.data = u128::MAX.to_le_bytes().to_vec();
+    scenario.executor.set_account(account);
+    let overflow = AssetOverflow {
+        account: pool,
+        asset: "share".to_owned(),
+    };
+    assert_eq!(holdings(&scenario), Err(overflow.clone()));
+    assert_eq!(conservation(&scenario, &[]).unwrap_err(), overflow);
+}
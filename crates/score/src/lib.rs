@@ -0,0 +1,52 @@
+//! 把分析器/fuzzer 的报告与 ground truth 对比打分。
+
+mod metrics;
+mod report;
+pub mod sarif;
+mod score;
+
+use std::{error, fmt, io};
+
+pub use metrics::{Confusion, Counts, FalsePositives};
+pub use report::{read_reports, Reported};
+pub use score::{score, Scorecard};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// 第几行（从 1 开始）不是合法的报告
+    Json {
+        line: usize,
+        source: serde_json::Error,
+    },
+    Sarif(serde_json::Error),
+    UnknownCase(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{err}"),
+            Error::Json { line, source } => write!(f, "line {line}: {source}"),
+            Error::Sarif(err) => write!(f, "invalid SARIF: {err}"),
+            Error::UnknownCase(case) => write!(f, "unknown case `{case}`"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Json { source, .. } => Some(source),
+            Error::Sarif(err) => Some(err),
+            Error::UnknownCase(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
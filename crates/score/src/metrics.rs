@@ -0,0 +1,217 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Serialize, Serializer};
+use solracebench_manifest::Category;
+
+/// 命中、误报、漏报计数
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub tp: usize,
+    pub fp: usize,
+    pub fn_: usize,
+}
+
+impl Counts {
+    /// 没有任何报告时为 0
+    pub fn precision(&self) -> f64 {
+        ratio(self.tp, self.tp + self.fp)
+    }
+
+    /// 没有任何 ground truth 时为 0
+    pub fn recall(&self) -> f64 {
+        ratio(self.tp, self.tp + self.fn_)
+    }
+
+    pub fn f1(&self) -> f64 {
+        let (p, r) = (self.precision(), self.recall());
+        if p + r == 0.0 {
+            0.0
+        } else {
+            2.0 * p * r / (p + r)
+        }
+    }
+}
+
+fn ratio(a: usize, b: usize) -> f64 {
+    if b == 0 {
+        0.0
+    } else {
+        a as f64 / b as f64
+    }
+}
+
+impl Serialize for Counts {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Row {
+            tp: usize,
+            fp: usize,
+            #[serde(rename = "fn")]
+            fn_: usize,
+            precision: f64,
+            recall: f64,
+            f1: f64,
+        }
+        Row {
+            tp: self.tp,
+            fp: self.fp,
+            fn_: self.fn_,
+            precision: self.precision(),
+            recall: self.recall(),
+            f1: self.f1(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>4} {:>4} {:>4} {:>9.3} {:>6.3} {:>6.3}",
+            self.tp,
+            self.fp,
+            self.fn_,
+            self.precision(),
+            self.recall(),
+            self.f1()
+        )
+    }
+}
+
+/// 一个类别在对照用例上的误报：被报告的 safe 用例数与 safe 用例总数
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FalsePositives {
+    pub flagged: usize,
+    pub negatives: usize,
+}
+
+impl FalsePositives {
+    /// 没有对照用例时为 0
+    pub fn rate(&self) -> f64 {
+        ratio(self.flagged, self.negatives)
+    }
+}
+
+impl Serialize for FalsePositives {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Row {
+            flagged: usize,
+            negatives: usize,
+            rate: f64,
+        }
+        Row {
+            flagged: self.flagged,
+            negatives: self.negatives,
+            rate: self.rate(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl fmt::Display for FalsePositives {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>7} {:>9} {:>6.3}",
+            self.flagged,
+            self.negatives,
+            self.rate()
+        )
+    }
+}
+
+/// 各漏洞类别之间的混淆表
+///
+/// 行是报告位置上真实存在的漏洞类别，`None` 表示报告的位置不对应任何 ground truth；
+/// 列是报告的类别；`missed` 是没有被任何报告命中的 ground truth。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Confusion {
+    #[serde(serialize_with = "serialize_rows")]
+    pub reported: BTreeMap<Option<Category>, BTreeMap<Category, usize>>,
+    pub missed: BTreeMap<Category, usize>,
+}
+
+/// 行名：真实类别，或者 `none`
+fn row_name(truth: Option<Category>) -> &'static str {
+    truth.map_or("none", Category::as_str)
+}
+
+fn serialize_rows<S: Serializer>(
+    rows: &BTreeMap<Option<Category>, BTreeMap<Category, usize>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(rows.iter().map(|(truth, row)| (row_name(*truth), row)))
+}
+
+impl Confusion {
+    pub fn get(&self, truth: Option<Category>, reported: Category) -> usize {
+        self.reported
+            .get(&truth)
+            .and_then(|row| row.get(&reported))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn missed(&self, truth: Category) -> usize {
+        self.missed.get(&truth).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn add(&mut self, truth: Option<Category>, reported: Category) {
+        *self
+            .reported
+            .entry(truth)
+            .or_default()
+            .entry(reported)
+            .or_default() += 1;
+    }
+
+    pub(crate) fn add_missed(&mut self, truth: Category) {
+        *self.missed.entry(truth).or_default() += 1;
+    }
+}
+
+/// 计数列至少这么宽
+const COUNT_WIDTH: usize = 5;
+
+impl fmt::Display for Confusion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<Option<Category>> = Category::ALL
+            .iter()
+            .copied()
+            .map(Some)
+            .chain([None])
+            .collect();
+        // 列宽按最长的类别名计算
+        let first = rows
+            .iter()
+            .map(|&truth| row_name(truth).len())
+            .chain(["truth".len()])
+            .max()
+            .unwrap_or_default();
+        let width = |name: &str| name.len().max(COUNT_WIDTH);
+        write!(f, "{:<first$}", "truth")?;
+        for category in Category::ALL {
+            let name = category.as_str();
+            write!(f, " {name:>0$}", width(name))?;
+        }
+        writeln!(f, " {:>1$}", "missed", width("missed"))?;
+        for truth in rows {
+            write!(f, "{:<first$}", row_name(truth))?;
+            for &category in Category::ALL {
+                write!(
+                    f,
+                    " {:>1$}",
+                    self.get(truth, category),
+                    width(category.as_str())
+                )?;
+            }
+            match truth {
+                Some(truth) => writeln!(f, " {:>1$}", self.missed(truth), width("missed"))?,
+                None => writeln!(f, " {:>1$}", "-", width("missed"))?,
+            }
+        }
+        Ok(())
+    }
+}
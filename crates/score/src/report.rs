@@ -0,0 +1,44 @@
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+use solracebench_manifest::{Category, ClockField};
+
+use crate::Error;
+
+/// 工具报告的一条结果，每行一个 JSON 对象
+///
+/// ```json
+/// {"case": "ccd/ccd21", "file": "src/lib.rs", "line": 80, "category": "ccd", "clock_fields": ["slot"]}
+/// ```
+///
+/// `case` 可以写完整 id 或目录名；`file` 缺省为 `src/lib.rs`；`clock_fields` 可省略。
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Reported {
+    pub case: String,
+    #[serde(default = "default_file")]
+    pub file: String,
+    pub line: usize,
+    pub category: Category,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clock_fields: Vec<ClockField>,
+}
+
+fn default_file() -> String {
+    "src/lib.rs".to_owned()
+}
+
+/// 逐行读取 JSON lines，跳过空行
+pub fn read_reports(reader: impl BufRead) -> Result<Vec<Reported>, Error> {
+    let mut out = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        out.push(serde_json::from_str(&line).map_err(|source| Error::Json {
+            line: i + 1,
+            source,
+        })?);
+    }
+    Ok(out)
+}
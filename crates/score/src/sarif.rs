@@ -0,0 +1,281 @@
+//! SARIF 2.1.0 的导入与导出，只覆盖打分用到的部分。
+
+use std::{collections::BTreeMap, io::Read};
+
+use serde::{Deserialize, Serialize};
+use solracebench_manifest::{Case, Category, ClockField, Finding, Span};
+
+use crate::{Error, Reported};
+
+pub const VERSION: &str = "2.1.0";
+pub const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+/// 导出 ground truth 时 ruleId 的前缀，后面是类别名；导入时总能识别
+pub const RULE_PREFIX: &str = "solracebench/";
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Log {
+    pub version: String,
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Run {
+    pub tool: Tool,
+    #[serde(default)]
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Tool {
+    pub driver: Driver,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub information_uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<Message>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    pub message: Message,
+    #[serde(default)]
+    pub locations: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<Properties>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Message {
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_location: Option<PhysicalLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+}
+
+/// 结果上的附加属性；导出时写入 clock 字段，导入时读取
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Properties {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clock_fields: Vec<ClockField>,
+}
+
+/// ruleId 到漏洞类别的映射
+///
+/// 规则文件是 TOML：
+///
+/// ```toml
+/// [rules]
+/// "clock-dependence" = "ccd"
+/// "front-running" = "tod"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleMap {
+    #[serde(default)]
+    pub rules: BTreeMap<String, Category>,
+}
+
+impl RuleMap {
+    pub fn parse(text: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    pub fn category(&self, rule_id: &str) -> Option<Category> {
+        rule_id
+            .strip_prefix(RULE_PREFIX)
+            .and_then(|name| Category::ALL.iter().find(|c| c.as_str() == name))
+            .copied()
+            .or_else(|| self.rules.get(rule_id).copied())
+    }
+}
+
+/// 导入结果：能对应到用例的报告，以及跳过的结果数
+#[derive(Clone, Debug, Default)]
+pub struct Imported {
+    pub reports: Vec<Reported>,
+    /// 按 ruleId 计数，ruleId 没有映射到类别
+    pub unmapped: BTreeMap<String, usize>,
+    /// 按 uri 计数，uri 不在任何用例目录下
+    pub outside: BTreeMap<String, usize>,
+    /// 没有任何物理位置的结果数
+    pub unlocated: usize,
+}
+
+/// 把 SARIF 的 uri 拆成用例 id 和用例内的文件路径
+///
+/// uri 可以是相对基准根目录的路径，也可以是带 `file://` 的绝对路径，取最长的匹配用例。
+fn locate<'a>(cases: &'a [Case], uri: &str) -> Option<(&'a Case, String)> {
+    let path = uri.strip_prefix("file://").unwrap_or(uri);
+    let segments: Vec<&str> = path.split('/').collect();
+    cases
+        .iter()
+        .filter_map(|case| {
+            let id: Vec<&str> = case.id.split('/').collect();
+            let at = segments.windows(id.len()).rposition(|w| w == id)?;
+            let rest = &segments[at + id.len()..];
+            (!rest.is_empty()).then(|| (case, rest.join("/")))
+        })
+        .max_by_key(|(case, _)| case.id.len())
+}
+
+pub fn read_sarif(reader: impl Read, cases: &[Case], rules: &RuleMap) -> Result<Imported, Error> {
+    let log: Log = serde_json::from_reader(reader).map_err(Error::Sarif)?;
+    Ok(import(&log, cases, rules))
+}
+
+/// 每个结果取第一个物理位置；ruleId 没有映射、没有物理位置或位置不在任何用例下的结果跳过并计数
+pub fn import(log: &Log, cases: &[Case], rules: &RuleMap) -> Imported {
+    let mut imported = Imported::default();
+    for result in log.runs.iter().flat_map(|run| &run.results) {
+        let rule_id = result.rule_id.clone().unwrap_or_default();
+        let Some(category) = rules.category(&rule_id) else {
+            *imported.unmapped.entry(rule_id).or_default() += 1;
+            continue;
+        };
+        let Some(location) = result
+            .locations
+            .iter()
+            .find_map(|l| l.physical_location.as_ref())
+        else {
+            imported.unlocated += 1;
+            continue;
+        };
+        let uri = &location.artifact_location.uri;
+        let Some((case, file)) = locate(cases, uri) else {
+            *imported.outside.entry(uri.clone()).or_default() += 1;
+            continue;
+        };
+        imported.reports.push(Reported {
+            case: case.id.clone(),
+            file,
+            line: location.region.as_ref().map_or(1, |r| r.start_line),
+            category,
+            clock_fields: result
+                .properties
+                .as_ref()
+                .map(|p| p.clock_fields.clone())
+                .unwrap_or_default(),
+        });
+    }
+    imported
+}
+
+fn location(case: &Case, span: &Span, message: Option<&str>) -> Location {
+    Location {
+        physical_location: Some(PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri: format!("{}/{}", case.id, span.file),
+                uri_base_id: Some("SRCROOT".to_owned()),
+            },
+            region: Some(Region {
+                start_line: span.line,
+                end_line: span.end,
+            }),
+        }),
+        message: message.map(|text| Message {
+            text: text.to_owned(),
+        }),
+    }
+}
+
+pub fn rule_id(category: Category) -> String {
+    format!("{RULE_PREFIX}{category}")
+}
+
+fn result(case: &Case, finding: &Finding) -> SarifResult {
+    SarifResult {
+        rule_id: Some(rule_id(finding.class)),
+        level: Some("error".to_owned()),
+        message: Message {
+            text: format!("{}: {}", case.id, finding.effect),
+        },
+        locations: vec![location(case, &finding.check, None)],
+        related_locations: vec![location(case, &finding.sink, Some("sink"))],
+        properties: (!finding.clock_fields.is_empty()).then(|| Properties {
+            clock_fields: finding.clock_fields.clone(),
+        }),
+    }
+}
+
+/// 把 ground truth 导出为 SARIF：主位置是 check，sink 放在 relatedLocations
+pub fn export(cases: &[Case]) -> Log {
+    let rules = Category::ALL
+        .iter()
+        .map(|&category| Rule {
+            id: rule_id(category),
+            short_description: Some(Message {
+                text: category.title().to_owned(),
+            }),
+        })
+        .collect();
+    Log {
+        version: VERSION.to_owned(),
+        schema: Some(SCHEMA.to_owned()),
+        runs: vec![Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "solracebench".to_owned(),
+                    information_uri: None,
+                    rules,
+                },
+            },
+            results: cases
+                .iter()
+                .flat_map(|case| case.findings.iter().map(move |f| result(case, f)))
+                .collect(),
+        }],
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use serde::Serialize;
+use solracebench_manifest::{Case, Category, ClockField, Finding, Label};
+
+use crate::{Confusion, Counts, Error, FalsePositives, Reported};
+
+/// 一次打分的全部结果
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Scorecard {
+    /// 报告行号与 span 之间允许的距离
+    pub tolerance: usize,
+    pub overall: Counts,
+    pub categories: BTreeMap<Category, Counts>,
+    pub clock_fields: BTreeMap<ClockField, Counts>,
+    pub confusion: Confusion,
+    /// 对照用例上的误报率：按报告类别统计被报告过的同类别 safe 用例
+    pub false_positives: BTreeMap<Category, FalsePositives>,
+    /// 重复命中同一处 ground truth 的报告数，不计入误报
+    pub duplicates: usize,
+}
+
+/// 报告与某处 ground truth 位置匹配：文件相同，行号离 check 或 sink 不超过 `tolerance`
+fn near(finding: &Finding, report: &Reported, tolerance: usize) -> Option<usize> {
+    [&finding.check, &finding.sink]
+        .into_iter()
+        .filter(|span| span.file == report.file)
+        .map(|span| span.distance(report.line))
+        .filter(|&d| d <= tolerance)
+        .min()
+}
+
+/// 按位置把报告匹配到 ground truth
+///
+/// 同类别的最近一处算命中；只有其他类别的漏洞在附近时算误报，并记入混淆表。
+/// 按 clock 字段统计时，命中和漏报取 ground truth 的字段，误报取报告自己声明的字段。
+/// safe 用例上的任何报告都是误报；同类别的报告至少有一条时，该用例计入误报率。
+pub fn score(cases: &[Case], reports: &[Reported], tolerance: usize) -> Result<Scorecard, Error> {
+    let mut card = Scorecard {
+        tolerance,
+        ..Scorecard::default()
+    };
+    // (用例下标, finding 下标) -> 是否已被同类别报告命中
+    let mut hits: BTreeMap<(usize, usize), bool> = BTreeMap::new();
+    // 被同类别报告过的 safe 用例下标
+    let mut flagged: BTreeSet<usize> = BTreeSet::new();
+    for report in reports {
+        let ci = cases
+            .iter()
+            .position(|c| c.matches(&report.case))
+            .ok_or_else(|| Error::UnknownCase(report.case.clone()))?;
+        if cases[ci].label == Label::Safe && cases[ci].category == report.category {
+            flagged.insert(ci);
+        }
+        let nearby: Vec<(usize, &Finding, usize)> = cases[ci]
+            .findings
+            .iter()
+            .enumerate()
+            .filter_map(|(fi, f)| near(f, report, tolerance).map(|d| (fi, f, d)))
+            .collect();
+        let same = nearby
+            .iter()
+            .filter(|(_, f, _)| f.class == report.category)
+            .min_by_key(|(_, _, d)| *d);
+        if let Some(&(fi, finding, _)) = same {
+            let hit = hits.entry((ci, fi)).or_default();
+            if *hit {
+                card.duplicates += 1;
+            } else {
+                *hit = true;
+                card.confusion.add(Some(finding.class), report.category);
+            }
+            continue;
+        }
+        let truth = match nearby.iter().min_by_key(|(_, _, d)| *d) {
+            Some(&(fi, finding, _)) => {
+                hits.entry((ci, fi)).or_default();
+                Some(finding.class)
+            }
+            None => None,
+        };
+        card.confusion.add(truth, report.category);
+        card.categories.entry(report.category).or_default().fp += 1;
+        for field in &report.clock_fields {
+            card.clock_fields.entry(*field).or_default().fp += 1;
+        }
+    }
+    for (ci, case) in cases.iter().enumerate() {
+        if case.label == Label::Safe {
+            let fp = card.false_positives.entry(case.category).or_default();
+            fp.negatives += 1;
+            if flagged.contains(&ci) {
+                fp.flagged += 1;
+            }
+        }
+        for (fi, finding) in case.findings.iter().enumerate() {
+            let hit = hits.get(&(ci, fi));
+            if hit.is_none() {
+                card.confusion.add_missed(finding.class);
+            }
+            let counts = card.categories.entry(finding.class).or_default();
+            let detected = hit == Some(&true);
+            if detected {
+                counts.tp += 1;
+            } else {
+                counts.fn_ += 1;
+            }
+            for field in &finding.clock_fields {
+                let counts = card.clock_fields.entry(*field).or_default();
+                if detected {
+                    counts.tp += 1;
+                } else {
+                    counts.fn_ += 1;
+                }
+            }
+        }
+    }
+    for counts in card.categories.values() {
+        card.overall.tp += counts.tp;
+        card.overall.fp += counts.fp;
+        card.overall.fn_ += counts.fn_;
+    }
+    Ok(card)
+}
+
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = format!(
+            "{:>4} {:>4} {:>4} {:>9} {:>6} {:>6}",
+            "tp", "fp", "fn", "precision", "recall", "f1"
+        );
+        writeln!(f, "line tolerance: {}", self.tolerance)?;
+        writeln!(f)?;
+        writeln!(f, "{:<22} {header}", "category")?;
+        for (category, counts) in &self.categories {
+            writeln!(f, "{:<22} {counts}", category.as_str())?;
+        }
+        writeln!(f, "{:<22} {}", "all", self.overall)?;
+        writeln!(f)?;
+        writeln!(f, "{:<22} {header}", "clock field")?;
+        for (field, counts) in &self.clock_fields {
+            writeln!(f, "{:<22} {counts}", field.as_str())?;
+        }
+        if !self.false_positives.is_empty() {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "{:<22} {:>7} {:>9} {:>6}",
+                "safe twins", "flagged", "negatives", "fpr"
+            )?;
+            for (category, fp) in &self.false_positives {
+                writeln!(f, "{:<22} {fp}", category.as_str())?;
+            }
+        }
+        writeln!(f)?;
+        writeln!(f, "confusion (rows: ground truth, columns: reported)")?;
+        write!(f, "{}", self.confusion)?;
+        if self.duplicates > 0 {
+            writeln!(f)?;
+            writeln!(f, "duplicate reports ignored: {}", self.duplicates)?;
+        }
+        Ok(())
+    }
+}
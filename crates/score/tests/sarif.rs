@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use solracebench_manifest::{load_case, Case, Category, ClockField};
+use solracebench_score::{
+    sarif::{self, Log, RuleMap},
+    score, Reported,
+};
+
+fn case(id: &str) -> Case {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_case(&root, &root.join(id)).unwrap()
+}
+
+#[test]
+fn ground_truth_round_trips_through_sarif() {
+    let cases = [case("ccd/ccd21"), case("tod/tod24")];
+    let log = sarif::export(&cases);
+    let text = serde_json::to_string_pretty(&log).unwrap();
+    let parsed: Log = serde_json::from_str(&text).unwrap();
+    assert_eq!(parsed, log);
+    assert_eq!(parsed.version, "2.1.0");
+
+    let imported = sarif::read_sarif(text.as_bytes(), &cases, &RuleMap::default()).unwrap();
+    assert!(imported.unmapped.is_empty());
+    assert!(imported.outside.is_empty());
+    let rules = &parsed.runs[0].tool.driver.rules;
+    assert_eq!(rules.len(), Category::ALL.len());
+    for rule in rules {
+        let category = RuleMap::default().category(&rule.id).unwrap();
+        assert_eq!(rule.id, sarif::rule_id(category));
+    }
+    let expected: Vec<Reported> = cases
+        .iter()
+        .flat_map(|case| {
+            case.findings.iter().map(|f| Reported {
+                case: case.id.clone(),
+                file: f.check.file.clone(),
+                line: f.check.line,
+                category: f.class,
+                clock_fields: f.clock_fields.clone(),
+            })
+        })
+        .collect();
+    assert_eq!(imported.reports, expected);
+    assert_eq!(imported.reports[0].clock_fields, [ClockField::Slot]);
+
+    let card = score(&cases, &imported.reports, 0).unwrap();
+    assert_eq!(card.overall.f1(), 1.0);
+    assert_eq!(card.categories[&Category::Ccd].tp, 1);
+    assert_eq!(card.categories[&Category::Tod].tp, 1);
+}
+
+#[test]
+fn rule_map_assigns_categories_to_foreign_rules() {
+    let cases = [case("ccd/ccd21"), case("tod/tod24")];
+    let rules = RuleMap::parse(
+        r#"
+[rules]
+"clock-check" = "ccd"
+"front-run" = "tod"
+"#,
+    )
+    .unwrap();
+    let text = r#"{
+  "version": "2.1.0",
+  "runs": [{
+    "tool": {"driver": {"name": "analyzer"}},
+    "results": [
+      {"ruleId": "clock-check", "message": {"text": "slot"},
+       "locations": [{"physicalLocation": {"artifactLocation": {"uri": "file:///work/bench/ccd/ccd21/src/lib.rs"}, "region": {"startLine": 80}}}]},
+      {"ruleId": "front-run", "message": {"text": "price"},
+       "locations": [{"physicalLocation": {"artifactLocation": {"uri": "tod/tod24/src/lib.rs"}, "region": {"startLine": 64}}}]},
+      {"ruleId": "style", "message": {"text": "naming"},
+       "locations": [{"physicalLocation": {"artifactLocation": {"uri": "tod/tod24/src/lib.rs"}, "region": {"startLine": 1}}}]},
+      {"ruleId": "solracebench/ccd", "message": {"text": "vendored"},
+       "locations": [{"physicalLocation": {"artifactLocation": {"uri": "vendor/anchor/src/clock.rs"}, "region": {"startLine": 3}}}]},
+      {"ruleId": "front-run", "message": {"text": "somewhere"},
+       "locations": [{"message": {"text": "logical location only"}}]},
+      {"ruleId": "clock-check", "message": {"text": "nowhere"}}
+    ]
+  }]
+}"#;
+    let imported = sarif::read_sarif(text.as_bytes(), &cases, &rules).unwrap();
+    assert_eq!(imported.unmapped["style"], 1);
+    assert_eq!(imported.outside["vendor/anchor/src/clock.rs"], 1);
+    assert_eq!(imported.unlocated, 2);
+    assert_eq!(imported.reports.len(), 2);
+    assert_eq!(imported.reports[0].case, "ccd/ccd21");
+    assert_eq!(imported.reports[0].file, "src/lib.rs");
+    assert_eq!(imported.reports[1].category, Category::Tod);
+
+    let card = score(&cases, &imported.reports, 1).unwrap();
+    // ccd21 的 client-time finding 和 tod24 的 float-order finding 没有对应的报告
+    assert_eq!(
+        (card.overall.tp, card.overall.fp, card.overall.fn_),
+        (2, 0, 2)
+    );
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+
+use solracebench_manifest::{load_all, Case, Category, ClockField, Label};
+use solracebench_score::{read_reports, score, Confusion, Reported};
+
+fn cases() -> Vec<Case> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../..")
+        .canonicalize()
+        .unwrap();
+    load_all(&root).unwrap()
+}
+
+/// 在每处 ground truth 的 sink 上报告一条
+fn perfect(cases: &[Case]) -> Vec<Reported> {
+    cases
+        .iter()
+        .flat_map(|case| {
+            case.findings.iter().map(|f| Reported {
+                case: case.id.clone(),
+                file: f.sink.file.clone(),
+                line: f.sink.line,
+                category: f.class,
+                clock_fields: f.clock_fields.clone(),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn the_ground_truth_scores_perfectly() {
+    let cases = cases();
+    let card = score(&cases, &perfect(&cases), 0).unwrap();
+    assert_eq!(card.overall.fp, 0);
+    assert_eq!(card.overall.fn_, 0);
+    assert_eq!(card.overall.f1(), 1.0);
+    for field in ClockField::ALL {
+        assert_eq!(card.clock_fields[field].recall(), 1.0, "{field}");
+    }
+    assert_eq!(card.confusion.missed(Category::Ccd), 0);
+    assert_eq!(card.confusion.get(Some(Category::Tod), Category::Ccd), 0);
+    for category in Category::ALL {
+        let safe = cases
+            .iter()
+            .filter(|c| c.label == Label::Safe && c.category == *category)
+            .count();
+        let fp = card.false_positives[category];
+        assert_eq!((fp.flagged, fp.negatives), (0, safe), "{category}");
+    }
+}
+
+#[test]
+fn flagging_every_case_is_caught_by_the_safe_twins() {
+    let cases = cases();
+    let mut reports = perfect(&cases);
+    for case in cases.iter().filter(|c| c.label == Label::Safe) {
+        reports.push(Reported {
+            case: case.id.clone(),
+            file: "src/lib.rs".to_owned(),
+            line: 1,
+            category: case.category,
+            clock_fields: Vec::new(),
+        });
+    }
+    let card = score(&cases, &reports, 0).unwrap();
+    assert_eq!(card.overall.recall(), 1.0);
+    assert_eq!(card.overall.fp, 112);
+    // timestamp5/6 另有一条 sysvar finding，epoch16/timestamp9 另有一条 randomness finding，
+    // ccd21/24/27 和 tod30 另有一条 client-time finding，
+    // timestamp4/13 和 ccd22/30 另有一条 time-arith finding，tod20-24 另有一条 float-order finding
+    assert_eq!(card.overall.tp, 129);
+    for category in Category::ALL {
+        assert_eq!(card.false_positives[category].rate(), 1.0, "{category}");
+    }
+    assert_eq!(card.confusion.get(None, Category::Tod), 32);
+}
+
+#[test]
+fn tolerance_bounds_the_distance_to_a_span() {
+    let cases = cases();
+    let sink = &cases.iter().find(|c| c.matches("ccd21")).unwrap().findings[0].sink;
+    let report = Reported {
+        case: "ccd21".to_owned(),
+        file: "src/lib.rs".to_owned(),
+        line: sink.end() + 2,
+        category: Category::Ccd,
+        clock_fields: Vec::new(),
+    };
+    let card = score(&cases, std::slice::from_ref(&report), 1).unwrap();
+    assert_eq!((card.overall.tp, card.overall.fp), (0, 1));
+    assert_eq!(card.confusion.get(None, Category::Ccd), 1);
+
+    let card = score(&cases, &[report.clone(), report], 2).unwrap();
+    assert_eq!((card.overall.tp, card.overall.fp), (1, 0));
+    assert_eq!(card.duplicates, 1);
+}
+
+#[test]
+fn wrong_category_lands_in_the_confusion_table() {
+    let cases = cases();
+    let reports = read_reports(
+        &br#"
+{"case": "tod/tod1", "line": 75, "category": "ccd", "clock_fields": ["slot"]}
+{"case": "slot1", "file": "src/lib.rs", "line": 1, "category": "tod"}
+"#[..],
+    )
+    .unwrap();
+    let card = score(&cases, &reports, 0).unwrap();
+    assert_eq!(card.confusion.get(Some(Category::Tod), Category::Ccd), 1);
+    assert_eq!(card.confusion.get(None, Category::Tod), 1);
+    assert_eq!(card.categories[&Category::Ccd].fp, 1);
+    assert_eq!(card.categories[&Category::Tod].tp, 0);
+    assert_eq!(card.clock_fields[&ClockField::Slot].fp, 1);
+    // 报错位置上的 tod 漏洞被提到过，不算完全漏掉
+    assert_eq!(card.confusion.missed(Category::Tod), 31);
+}
+
+#[test]
+fn unknown_cases_are_rejected() {
+    let reports = read_reports(&br#"{"case": "tod99", "line": 1, "category": "tod"}"#[..]).unwrap();
+    assert!(score(&cases(), &reports, 0).is_err());
+    assert!(read_reports(&b"{\"case\": 1}"[..]).is_err());
+}
+
+#[test]
+fn confusion_columns_line_up() {
+    let text = Confusion::default().to_string();
+    let widths: Vec<usize> = text.lines().map(str::len).collect();
+    assert!(widths.windows(2).all(|w| w[0] == w[1]), "{text}");
+    assert!(text.starts_with("truth        "), "{text}");
+}
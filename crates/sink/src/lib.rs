@@ -0,0 +1,23 @@
+//! 用例在漏洞的 sink 处调用 [`bench_sink!`]，留下一行能被执行器识别的日志。
+//!
+//! 标记在链上同样是一行普通的程序日志，fuzzer 因此可以不理解各用例自己的输出，
+//! 统一按“在被操纵的时钟下到达了 sink”评分。
+
+/// 标记日志的前缀，后面是 sink 的 id
+pub const PREFIX: &str = "bench-sink: ";
+
+/// 记录到达了 id 为 `$id` 的 sink；id 取漏洞用例的目录名，safe 的 twin 沿用同一个 id
+#[macro_export]
+macro_rules! bench_sink {
+    ($id:literal) => {
+        $crate::sol_log(concat!("bench-sink: ", $id))
+    };
+}
+
+#[doc(hidden)]
+pub use solana_msg::sol_log;
+
+/// 日志行是 sink 标记时返回 sink 的 id
+pub fn parse(line: &str) -> Option<&str> {
+    line.strip_prefix(PREFIX)
+}
@@ -0,0 +1,185 @@
+use std::mem::size_of;
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::MAX_PERMITTED_DATA_INCREASE, pubkey::Pubkey,
+};
+use solana_sdk_ids::native_loader;
+
+// 缓冲区中各字段的字节偏移，与 loader 序列化的一个账户相同：
+// `AccountInfo::realloc` 读取 key 前面 4 字节的原始长度，改写数据前面 8 字节的当前长度
+const ORIGINAL_DATA_LEN: usize = 4;
+const KEY: usize = 8;
+const OWNER: usize = KEY + 32;
+const LAMPORTS: usize = OWNER + 32;
+const DATA_LEN: usize = LAMPORTS + 8;
+const DATA: usize = DATA_LEN + 8;
+
+/// 持有 lamports 和数据缓冲区的账户，借出的 `AccountInfo` 指向这块缓冲区
+///
+/// 数据后面留有 `MAX_PERMITTED_DATA_INCREASE` 字节，程序可以像在链上一样 `realloc`。
+#[derive(Clone, Debug)]
+pub struct TestAccount {
+    // 用 u64 存储保证 lamports 和数据 8 字节对齐
+    buf: Vec<u64>,
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+    pub rent_epoch: u64,
+}
+
+/// 账户在某一时刻的 lamports、owner 和数据
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    pub lamports: u64,
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+impl TestAccount {
+    /// 没有 lamports、没有数据、不签名、可写的账户
+    pub fn new(key: Pubkey, owner: Pubkey) -> Self {
+        TestAccount {
+            buf: layout(&key, &owner, 0, &[]),
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    /// 由 native loader 拥有的可执行只读账户，例如作为 CPI 目标传入的 System Program
+    pub fn builtin(key: Pubkey) -> Self {
+        TestAccount::new(key, native_loader::ID)
+            .readonly()
+            .executable()
+    }
+
+    pub fn with_lamports(mut self, lamports: u64) -> Self {
+        self.write(LAMPORTS, &lamports.to_le_bytes());
+        self
+    }
+
+    /// 换成 `data`，它的长度同时成为 `realloc` 计算上限用的原始长度
+    pub fn with_data(mut self, data: &[u8]) -> Self {
+        self.buf = layout(&self.key(), &self.owner(), self.lamports(), data);
+        self
+    }
+
+    pub fn signer(mut self) -> Self {
+        self.is_signer = true;
+        self
+    }
+
+    pub fn readonly(mut self) -> Self {
+        self.is_writable = false;
+        self
+    }
+
+    pub fn executable(mut self) -> Self {
+        self.executable = true;
+        self
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: buf 的字节数是 u64 个数的 8 倍
+        unsafe {
+            std::slice::from_raw_parts(
+                self.buf.as_ptr() as *const u8,
+                self.buf.len() * size_of::<u64>(),
+            )
+        }
+    }
+
+    fn write(&mut self, at: usize, value: &[u8]) {
+        let base = self.buf.as_mut_ptr() as *mut u8;
+        // SAFETY: 所有偏移都落在 DATA 之前，缓冲区至少有 DATA 字节
+        unsafe { std::ptr::copy_nonoverlapping(value.as_ptr(), base.add(at), value.len()) };
+    }
+
+    fn u64_at(&self, at: usize) -> u64 {
+        u64::from_le_bytes(self.bytes()[at..at + 8].try_into().unwrap())
+    }
+
+    fn pubkey_at(&self, at: usize) -> Pubkey {
+        Pubkey::new_from_array(self.bytes()[at..at + 32].try_into().unwrap())
+    }
+
+    pub fn key(&self) -> Pubkey {
+        self.pubkey_at(KEY)
+    }
+
+    /// 当前的 owner，程序 `assign` 之后随之改变
+    pub fn owner(&self) -> Pubkey {
+        self.pubkey_at(OWNER)
+    }
+
+    pub fn lamports(&self) -> u64 {
+        self.u64_at(LAMPORTS)
+    }
+
+    /// 当前的数据，程序 `realloc` 之后长度随之改变
+    pub fn data(&self) -> &[u8] {
+        let len = self.u64_at(DATA_LEN) as usize;
+        &self.bytes()[DATA..DATA + len]
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lamports: self.lamports(),
+            owner: self.owner(),
+            data: self.data().to_vec(),
+        }
+    }
+
+    /// 借出指向缓冲区的 `AccountInfo`
+    pub fn info(&mut self) -> AccountInfo<'_> {
+        let base = self.buf.as_mut_ptr() as *mut u8;
+        // SAFETY: 各字段的偏移都在缓冲区内，lamports 8 字节对齐；
+        // 数据后面留有 realloc 能用到的全部空间，返回值的生命周期不超过 &mut self
+        unsafe {
+            let len = *(base.add(DATA_LEN) as *const u64) as usize;
+            AccountInfo::new(
+                &*(base.add(KEY) as *const Pubkey),
+                self.is_signer,
+                self.is_writable,
+                &mut *(base.add(LAMPORTS) as *mut u64),
+                std::slice::from_raw_parts_mut(base.add(DATA), len),
+                &*(base.add(OWNER) as *const Pubkey),
+                self.executable,
+                self.rent_epoch,
+            )
+        }
+    }
+}
+
+/// 由名字派生的公钥：名字的字节右侧补零到 32 字节，清单中没有写 key 的账户用它
+pub fn name_key(name: &str) -> Pubkey {
+    let mut bytes = [0u8; 32];
+    let len = name.len().min(32);
+    bytes[..len].copy_from_slice(&name.as_bytes()[..len]);
+    Pubkey::new_from_array(bytes)
+}
+
+/// 依次借出每个账户的 `AccountInfo`
+pub fn infos(accounts: &mut [TestAccount]) -> Vec<AccountInfo<'_>> {
+    accounts.iter_mut().map(TestAccount::info).collect()
+}
+
+fn layout(key: &Pubkey, owner: &Pubkey, lamports: u64, data: &[u8]) -> Vec<u64> {
+    let mut bytes = Vec::with_capacity(DATA + data.len() + MAX_PERMITTED_DATA_INCREASE);
+    bytes.resize(ORIGINAL_DATA_LEN, 0);
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(key.as_ref());
+    bytes.extend_from_slice(owner.as_ref());
+    bytes.extend_from_slice(&lamports.to_le_bytes());
+    bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(data);
+    bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+
+    let mut buf = vec![0u64; bytes.len().div_ceil(size_of::<u64>())];
+    // SAFETY: buf 至少有 bytes.len() 字节
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), buf.as_mut_ptr() as *mut u8, bytes.len());
+    }
+    buf
+}
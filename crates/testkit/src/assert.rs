@@ -0,0 +1,86 @@
+//! 调用结果和调用后账户状态上的断言，失败时带上日志方便定位
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{Call, Failure, Snapshot, TestAccount};
+
+impl Call {
+    #[track_caller]
+    pub fn assert_ok(&self) -> &Self {
+        assert!(
+            self.result.is_ok(),
+            "expected success, got {:?}; logs: {:#?}",
+            self.result,
+            self.logs
+        );
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_err(&self, expected: ProgramError) -> &Self {
+        assert_eq!(
+            self.result,
+            Err(Failure::Error(expected)),
+            "logs: {:#?}",
+            self.logs
+        );
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_no_panic(&self) -> &Self {
+        if let Err(Failure::Panic(message)) = &self.result {
+            panic!("program {message}; logs: {:#?}", self.logs);
+        }
+        self
+    }
+
+    /// 某一行日志与 `message` 完全相同
+    #[track_caller]
+    pub fn assert_logged(&self, message: &str) -> &Self {
+        assert!(
+            self.logs.iter().any(|line| line == message),
+            "{message:?} not logged; logs: {:#?}",
+            self.logs
+        );
+        self
+    }
+
+    /// 没有一行日志与 `message` 相同
+    #[track_caller]
+    pub fn assert_not_logged(&self, message: &str) -> &Self {
+        assert!(
+            !self.logs.iter().any(|line| line == message),
+            "{message:?} logged; logs: {:#?}",
+            self.logs
+        );
+        self
+    }
+}
+
+impl TestAccount {
+    #[track_caller]
+    pub fn assert_lamports(&self, expected: u64) -> &Self {
+        assert_eq!(self.lamports(), expected, "lamports of {}", self.key());
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_owner(&self, expected: &Pubkey) -> &Self {
+        assert_eq!(self.owner(), *expected, "owner of {}", self.key());
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_data(&self, expected: &[u8]) -> &Self {
+        assert_eq!(self.data(), expected, "data of {}", self.key());
+        self
+    }
+
+    /// lamports、owner 和数据都与 `before` 相同
+    #[track_caller]
+    pub fn assert_unchanged(&self, before: &Snapshot) -> &Self {
+        assert_eq!(self.snapshot(), *before, "{} changed", self.key());
+        self
+    }
+}
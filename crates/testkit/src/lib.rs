@@ -0,0 +1,20 @@
+//! 用例 crate 在本地做单元测试时共用的支撑代码，执行器也经由这里截获 syscall。
+//!
+//! [`Env`] 给出 syscall stubs 返回的 sysvar、CPI 的处理方式和已有的 return data，
+//! 在其中执行程序得到截获了日志、CPI 和 return data 的 [`Call`]；
+//! [`TestAccount`] 按链上 loader 的布局持有账户的 lamports 和数据，
+//! 借出的 `AccountInfo` 可以 `realloc` 和 `assign`；
+//! sysvar 账户的数据由 [`clock_data`] 等函数生成，与执行器使用的相同。
+
+mod account;
+mod assert;
+mod stubs;
+mod system;
+mod sysvar;
+
+pub use account::{infos, name_key, Snapshot, TestAccount};
+pub use stubs::{invoke_locally, Call, Cpi, Env, Failure, Invoke, Processor};
+pub use sysvar::{
+    blockhash, clock_data, epoch_schedule_data, instructions_data, recent_blockhashes_data,
+    rent_data, slot_hash, slot_hashes_data,
+};
@@ -0,0 +1,260 @@
+use std::{cell::RefCell, fmt, panic, sync::Once};
+
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{ProgramResult, SUCCESS},
+    epoch_schedule::EpochSchedule,
+    instruction::Instruction,
+    program_error::{ProgramError, UNSUPPORTED_SYSVAR},
+    program_stubs::{self, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+};
+use solana_sdk_ids::system_program;
+
+use crate::{infos, system, TestAccount};
+
+pub type Processor = fn(&Pubkey, &[AccountInfo], &[u8]) -> ProgramResult;
+
+/// 处理程序发起的 CPI，参数依次是调用方程序、被调用的指令、调用方传入的全部账户和签名种子
+pub type Invoke = fn(&Pubkey, &Instruction, &[AccountInfo], &[&[&[u8]]]) -> ProgramResult;
+
+/// 缺省的 CPI 处理：对 System Program 的调用在传入的账户上执行，调用其他程序直接成功
+pub fn invoke_locally(
+    caller: &Pubkey,
+    instruction: &Instruction,
+    accounts: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    if instruction.program_id == system_program::ID {
+        system::process(caller, instruction, accounts, signers_seeds)
+    } else {
+        Ok(())
+    }
+}
+
+/// 一次本地调用的环境
+#[derive(Clone, Debug)]
+pub struct Env {
+    /// 被测程序的 id，也是 `set_return_data` 记录的程序；它派生的 PDA 可以作为 CPI 的签名者
+    pub program_id: Pubkey,
+    /// `Clock::get` 的返回值
+    pub clock: Clock,
+    /// `Rent::get` 的返回值
+    pub rent: Rent,
+    /// `EpochSchedule::get` 的返回值
+    pub epoch_schedule: EpochSchedule,
+    pub invoke: Invoke,
+    /// 调用前已有的 return data，例如上一次 CPI 的被调用方留下的
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Env::new(Pubkey::default())
+    }
+}
+
+/// 程序发起的一次跨程序调用
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cpi {
+    pub instruction: Instruction,
+    pub signers_seeds: Vec<Vec<Vec<u8>>>,
+    /// `Env::invoke` 返回的结果
+    pub result: Result<(), ProgramError>,
+}
+
+/// 调用失败的原因
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    Error(ProgramError),
+    Panic(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(err) => write!(f, "program error: {err}"),
+            Failure::Panic(message) => write!(f, "program {message}"),
+        }
+    }
+}
+
+/// 一次调用的结果
+#[derive(Clone, Debug)]
+pub struct Call {
+    pub result: Result<(), Failure>,
+    pub logs: Vec<String>,
+    pub cpis: Vec<Cpi>,
+    /// 调用结束时的 return data
+    pub return_data: Option<(Pubkey, Vec<u8>)>,
+}
+
+struct Context {
+    env: Env,
+    logs: Vec<String>,
+    cpis: Vec<Cpi>,
+    panic: Option<String>,
+}
+
+thread_local! {
+    // syscall stubs 是进程级的，按线程分发可以让测试并行执行
+    static CONTEXT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+fn with<R>(f: impl FnOnce(&mut Context) -> R) -> Option<R> {
+    CONTEXT.with(|ctx| ctx.try_borrow_mut().ok()?.as_mut().map(f))
+}
+
+fn log(message: &str) {
+    if with(|ctx| ctx.logs.push(message.to_owned())).is_none() {
+        println!("{message}");
+    }
+}
+
+/// 把 sysvar 写进 `*::get` 传入的缓冲区，没有进行中的调用时报告不支持
+fn write_sysvar<T>(var_addr: *mut u8, value: Option<T>) -> u64 {
+    match value {
+        Some(value) => {
+            // SAFETY: `*::get` 传入的是 T 大小且对齐的缓冲区
+            unsafe { std::ptr::write(var_addr as *mut T, value) };
+            SUCCESS
+        }
+        None => UNSUPPORTED_SYSVAR,
+    }
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, message: &str) {
+        log(message);
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let cpi = Cpi {
+            instruction: instruction.clone(),
+            signers_seeds: signers_seeds
+                .iter()
+                .map(|seeds| seeds.iter().map(|seed| seed.to_vec()).collect())
+                .collect(),
+            result: Ok(()),
+        };
+        // 先放开上下文再调用处理函数，处理函数里还可以记日志或再发起 CPI
+        let Some((index, caller, invoke)) = with(|ctx| {
+            ctx.cpis.push(cpi);
+            (ctx.cpis.len() - 1, ctx.env.program_id, ctx.env.invoke)
+        }) else {
+            return Ok(());
+        };
+        let result = invoke(&caller, instruction, account_infos, signers_seeds);
+        with(|ctx| ctx.cpis[index].result = result.clone());
+        result
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        write_sysvar(var_addr, with(|ctx| ctx.env.clock.clone()))
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        write_sysvar(var_addr, with(|ctx| ctx.env.rent.clone()))
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        write_sysvar(var_addr, with(|ctx| ctx.env.epoch_schedule.clone()))
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        with(|ctx| ctx.env.return_data.clone()).flatten()
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        with(|ctx| {
+            // 与运行时相同：设置空数据等于清除
+            ctx.env.return_data = (!data.is_empty()).then(|| (ctx.env.program_id, data.to_vec()));
+        });
+    }
+}
+
+static INSTALL: Once = Once::new();
+
+fn install() {
+    INSTALL.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(Stubs));
+        solana_msg::set_log_hook(Some(log));
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // 调用中的 panic 记为失败结果，不打印
+            if with(|ctx| ctx.panic = Some(info.to_string())).is_none() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+impl Env {
+    /// 缺省的 sysvar，CPI 由 [`invoke_locally`] 处理，没有 return data
+    pub fn new(program_id: Pubkey) -> Self {
+        Env {
+            program_id,
+            clock: Clock::default(),
+            rent: Rent::default(),
+            epoch_schedule: EpochSchedule::default(),
+            invoke: invoke_locally,
+            return_data: None,
+        }
+    }
+
+    /// 在当前线程上以这个环境执行 `f`
+    pub fn run(&self, f: impl FnOnce() -> ProgramResult) -> Call {
+        install();
+        CONTEXT.with(|ctx| {
+            *ctx.borrow_mut() = Some(Context {
+                env: self.clone(),
+                logs: Vec::new(),
+                cpis: Vec::new(),
+                panic: None,
+            })
+        });
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+        let mut ctx = CONTEXT
+            .with(|ctx| ctx.borrow_mut().take())
+            .expect("call context is set");
+        let result = match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(err)) => Err(Failure::Error(err)),
+            Err(_) => Err(Failure::Panic(
+                ctx.panic.take().unwrap_or_else(|| "panicked".to_owned()),
+            )),
+        };
+        Call {
+            result,
+            logs: ctx.logs,
+            cpis: ctx.cpis,
+            return_data: ctx.env.return_data,
+        }
+    }
+
+    /// 以 `accounts` 和 `data` 调用 `processor`；失败时账户的修改不会回滚
+    pub fn call(&self, processor: Processor, accounts: &mut [TestAccount], data: &[u8]) -> Call {
+        let infos = infos(accounts);
+        self.run(|| processor(&self.program_id, &infos, data))
+    }
+
+    /// 与 [`Env::call`] 相同，但账户分别放在各自的变量里，每次调用可以传入不同的组合
+    pub fn call_with(
+        &self,
+        processor: Processor,
+        accounts: &mut [&mut TestAccount],
+        data: &[u8],
+    ) -> Call {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(|a| a.info()).collect();
+        self.run(|| processor(&self.program_id, &infos, data))
+    }
+}
@@ -0,0 +1,129 @@
+use solana_bincode::limited_deserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+use solana_sdk_ids::system_program;
+use solana_system_interface::{
+    error::SystemError, instruction::SystemInstruction, MAX_PERMITTED_DATA_LENGTH,
+};
+
+/// 按 System Program 的规则执行程序发起的 CPI，直接修改调用方传入的账户
+///
+/// 支持 transfer、create_account、assign 和 allocate，其余指令返回 `InvalidInstructionData`。
+/// 账户按指令中的 key 从 `accounts` 里取出，签名和可写权限以指令为准：
+/// 调用方只能转交自己持有的签名、可写权限，以及 `caller` 由 `signers_seeds` 派生的 PDA 的签名。
+pub fn process(
+    caller: &Pubkey,
+    instruction: &Instruction,
+    accounts: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let pdas: Vec<Pubkey> = signers_seeds
+        .iter()
+        .filter_map(|seeds| Pubkey::create_program_address(seeds, caller).ok())
+        .collect();
+    let mut infos = Vec::with_capacity(instruction.accounts.len());
+    for meta in &instruction.accounts {
+        let info = accounts
+            .iter()
+            .find(|a| *a.key == meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if meta.is_signer && !info.is_signer && !pdas.contains(&meta.pubkey) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if meta.is_writable && !info.is_writable {
+            return Err(ProgramError::InvalidArgument);
+        }
+        // 被调用方看到的权限是指令中声明的权限
+        let mut info = info.clone();
+        info.is_signer = meta.is_signer;
+        info.is_writable = meta.is_writable;
+        infos.push(info);
+    }
+    let account = |index: usize| infos.get(index).ok_or(ProgramError::NotEnoughAccountKeys);
+
+    let data = &instruction.data;
+    match limited_deserialize(data, data.len() as u64)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        SystemInstruction::Transfer { lamports } => transfer(account(0)?, account(1)?, lamports),
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let (from, to) = (account(0)?, account(1)?);
+            if to.lamports() > 0 {
+                return Err(system_error(SystemError::AccountAlreadyInUse));
+            }
+            allocate(to, space)?;
+            assign(to, &owner)?;
+            transfer(from, to, lamports)
+        }
+        SystemInstruction::Assign { owner } => assign(account(0)?, &owner),
+        SystemInstruction::Allocate { space } => allocate(account(0)?, space),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn system_error(err: SystemError) -> ProgramError {
+    ProgramError::Custom(err as u32)
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // 只能从不带数据的 system 账户转出
+    if !from.data_is_empty() || *from.owner != system_program::ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if !from.is_writable || !to.is_writable {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if lamports > from.lamports() {
+        return Err(system_error(SystemError::ResultWithNegativeLamports));
+    }
+    // 转给自己时两个 AccountInfo 共用同一个 lamports，余额不变
+    if from.key == to.key {
+        return Ok(());
+    }
+    let credited = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **from.try_borrow_mut_lamports()? -= lamports;
+    **to.try_borrow_mut_lamports()? = credited;
+    Ok(())
+}
+
+fn assign(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner == owner {
+        return Ok(());
+    }
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidArgument);
+    }
+    account.assign(owner);
+    Ok(())
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !account.data_is_empty() || *account.owner != system_program::ID {
+        return Err(system_error(SystemError::AccountAlreadyInUse));
+    }
+    if space > MAX_PERMITTED_DATA_LENGTH {
+        return Err(system_error(SystemError::InvalidAccountDataLength));
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidArgument);
+    }
+    account.resize(space as usize)
+}
@@ -0,0 +1,124 @@
+//! sysvar 账户的数据，按当前时钟和交易生成；执行器和用例的单元测试用同一份实现
+
+use solana_epoch_schedule::EpochSchedule;
+use solana_instruction::{BorrowedAccountMeta, BorrowedInstruction};
+use solana_instructions_sysvar::construct_instructions_data;
+use solana_program::{
+    clock::Clock,
+    hash::{hash, hashv, Hash},
+    instruction::Instruction,
+    pubkey::Pubkey,
+    rent::Rent,
+};
+use solana_sdk_ids::sysvar;
+
+use crate::TestAccount;
+
+impl TestAccount {
+    /// 由 sysvar 程序拥有、持有 `data` 的账户，与执行器生成的 sysvar 账户相同
+    pub fn sysvar(key: Pubkey, data: &[u8]) -> Self {
+        TestAccount::new(key, sysvar::ID)
+            .with_lamports(1)
+            .with_data(data)
+    }
+}
+
+/// Clock sysvar 账户的数据（与 bincode 序列化结果一致）
+pub fn clock_data(clock: &Clock) -> Vec<u8> {
+    let mut data = Vec::with_capacity(40);
+    data.extend_from_slice(&clock.slot.to_le_bytes());
+    data.extend_from_slice(&clock.epoch_start_timestamp.to_le_bytes());
+    data.extend_from_slice(&clock.epoch.to_le_bytes());
+    data.extend_from_slice(&clock.leader_schedule_epoch.to_le_bytes());
+    data.extend_from_slice(&clock.unix_timestamp.to_le_bytes());
+    data
+}
+
+/// Rent sysvar 账户的数据：`Rent::default()` 的 bincode 序列化
+pub fn rent_data() -> Vec<u8> {
+    let rent = Rent::default();
+    let mut data = Vec::with_capacity(17);
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+/// EpochSchedule sysvar 账户的数据（与 bincode 序列化结果一致）
+pub fn epoch_schedule_data(schedule: &EpochSchedule) -> Vec<u8> {
+    let mut data = Vec::with_capacity(33);
+    data.extend_from_slice(&schedule.slots_per_epoch.to_le_bytes());
+    data.extend_from_slice(&schedule.leader_schedule_slot_offset.to_le_bytes());
+    data.push(schedule.warmup.into());
+    data.extend_from_slice(&schedule.first_normal_epoch.to_le_bytes());
+    data.extend_from_slice(&schedule.first_normal_slot.to_le_bytes());
+    data
+}
+
+/// SlotHashes 中 slot 的哈希：slot 号小端字节的 SHA-256
+pub fn slot_hash(slot: u64) -> Hash {
+    hash(&slot.to_le_bytes())
+}
+
+/// SlotHashes sysvar 账户的数据：`slot` 之前最近 512 个 slot，从新到旧排列
+pub fn slot_hashes_data(slot: u64) -> Vec<u8> {
+    let slots: Vec<u64> = (slot.saturating_sub(MAX_SLOT_HASHES)..slot).rev().collect();
+    let mut data = Vec::with_capacity(8 + slots.len() * 40);
+    data.extend_from_slice(&(slots.len() as u64).to_le_bytes());
+    for slot in slots {
+        data.extend_from_slice(&slot.to_le_bytes());
+        data.extend_from_slice(slot_hash(slot).as_ref());
+    }
+    data
+}
+
+/// RecentBlockhashes 中 slot 的 blockhash：`"blockhash"` 与 slot 号小端字节的 SHA-256
+pub fn blockhash(slot: u64) -> Hash {
+    hashv(&[b"blockhash", &slot.to_le_bytes()])
+}
+
+/// RecentBlockhashes sysvar 账户的数据：`slot` 之前最近 150 个 slot，从新到旧排列，
+/// 每项是 32 字节 blockhash 和 u64 `lamports_per_signature`
+pub fn recent_blockhashes_data(slot: u64) -> Vec<u8> {
+    let slots: Vec<u64> = (slot.saturating_sub(MAX_RECENT_BLOCKHASHES)..slot)
+        .rev()
+        .collect();
+    let mut data = Vec::with_capacity(8 + slots.len() * 40);
+    data.extend_from_slice(&(slots.len() as u64).to_le_bytes());
+    for slot in slots {
+        data.extend_from_slice(blockhash(slot).as_ref());
+        data.extend_from_slice(&LAMPORTS_PER_SIGNATURE.to_le_bytes());
+    }
+    data
+}
+
+/// Instructions sysvar 账户的数据：交易依次含 `instructions`，当前执行第 `current` 条
+pub fn instructions_data(instructions: &[Instruction], current: usize) -> Vec<u8> {
+    let borrowed: Vec<BorrowedInstruction> = instructions
+        .iter()
+        .map(|instruction| BorrowedInstruction {
+            program_id: &instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &instruction.data,
+        })
+        .collect();
+    let mut data = construct_instructions_data(&borrowed);
+    // 数据最后两个字节是当前指令的下标
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&(current as u16).to_le_bytes());
+    data
+}
+
+/// 与 `solana_slot_hashes::MAX_ENTRIES` 相同
+const MAX_SLOT_HASHES: u64 = 512;
+/// 与 `solana_sysvar::recent_blockhashes::MAX_ENTRIES` 相同
+const MAX_RECENT_BLOCKHASHES: u64 = 150;
+const LAMPORTS_PER_SIGNATURE: u64 = 5000;
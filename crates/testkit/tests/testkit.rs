@@ -0,0 +1,159 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    epoch_schedule::EpochSchedule,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::{get_return_data, invoke_signed, set_return_data},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
+};
+use solracebench_testkit::{Env, Failure, TestAccount};
+
+fn sysvars(_: &Pubkey, _: &[AccountInfo], _: &[u8]) -> ProgramResult {
+    let clock = Clock::get()?;
+    let rent = Rent::get()?;
+    let schedule = EpochSchedule::get()?;
+    msg!("slot {} at {}", clock.slot, clock.unix_timestamp);
+    msg!("rent {}", rent.minimum_balance(0));
+    msg!("{} slots per epoch", schedule.slots_per_epoch);
+    Ok(())
+}
+
+#[test]
+fn sysvars_come_from_the_env() {
+    let env = Env {
+        clock: Clock {
+            slot: 42,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        },
+        epoch_schedule: EpochSchedule::custom(64, 64, false),
+        ..Env::default()
+    };
+    let call = env.call(sysvars, &mut [], &[]);
+    call.assert_ok()
+        .assert_logged("slot 42 at 1700000000")
+        .assert_logged(&format!("rent {}", Rent::default().minimum_balance(0)))
+        .assert_logged("64 slots per epoch");
+}
+
+/// 把第一个账户扩成 8 字节写入 instruction_data、assign 给 program_id，再从它转 1 lamport 给第二个账户
+fn grow(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [account, recipient] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if !account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    account.resize(8)?;
+    account.data.borrow_mut().copy_from_slice(&data[..8]);
+    account.assign(program_id);
+    **account.try_borrow_mut_lamports()? -= 1;
+    **recipient.try_borrow_mut_lamports()? += 1;
+    Ok(())
+}
+
+#[test]
+fn accounts_can_be_reallocated_and_assigned() {
+    let program_id = Pubkey::new_unique();
+    let env = Env::new(program_id);
+    let mut accounts = [
+        TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+            .with_lamports(10)
+            .with_data(&[9])
+            .signer(),
+        TestAccount::new(Pubkey::new_unique(), program_id),
+    ];
+    let before = accounts[1].snapshot();
+    env.call(grow, &mut accounts, &7u64.to_le_bytes())
+        .assert_ok();
+    accounts[0]
+        .assert_data(&7u64.to_le_bytes())
+        .assert_owner(&program_id)
+        .assert_lamports(9);
+    accounts[1].assert_lamports(before.lamports + 1);
+
+    // 没有签名时什么都不改
+    accounts[0].is_signer = false;
+    let before = accounts[0].snapshot();
+    env.call(grow, &mut accounts, &[0; 8])
+        .assert_err(ProgramError::MissingRequiredSignature);
+    accounts[0].assert_unchanged(&before);
+}
+
+fn relay(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let instruction = Instruction {
+        program_id: *accounts[0].key,
+        accounts: vec![AccountMeta::new_readonly(*program_id, false)],
+        data: data.to_vec(),
+    };
+    invoke_signed(&instruction, accounts, &[&[b"relay"]])?;
+    let (from, returned) = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+    msg!("{} returned {:?}", from, returned);
+    set_return_data(&[returned[0] + 1]);
+    Ok(())
+}
+
+fn echo(_: &Pubkey, instruction: &Instruction, _: &[AccountInfo], _: &[&[&[u8]]]) -> ProgramResult {
+    if instruction.data.is_empty() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(())
+}
+
+#[test]
+fn cpis_are_recorded_and_handled_by_the_env() {
+    let program_id = Pubkey::new_unique();
+    let callee = Pubkey::new_unique();
+    let env = Env {
+        invoke: echo,
+        return_data: Some((callee, vec![1])),
+        ..Env::new(program_id)
+    };
+    let mut accounts = [TestAccount::new(callee, Pubkey::default()).executable()];
+    let call = env.call(relay, &mut accounts, &[5]);
+    call.assert_ok()
+        .assert_logged(&format!("{callee} returned [1]"));
+    assert_eq!(call.cpis.len(), 1);
+    assert_eq!(call.cpis[0].instruction.program_id, callee);
+    assert_eq!(call.cpis[0].signers_seeds, [[b"relay".to_vec()]]);
+    assert_eq!(call.cpis[0].result, Ok(()));
+    assert_eq!(call.return_data, Some((program_id, vec![2])));
+
+    let call = env.call(relay, &mut accounts, &[]);
+    call.assert_err(ProgramError::InvalidInstructionData);
+    assert_eq!(
+        call.cpis[0].result,
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
+
+fn index(_: &Pubkey, _: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    msg!("first byte {}", data[0]);
+    Ok(())
+}
+
+#[test]
+fn panics_are_captured_as_failures() {
+    let call = Env::default().call(index, &mut [], &[]);
+    assert!(matches!(&call.result, Err(Failure::Panic(m)) if m.contains("index out of bounds")));
+    Env::default()
+        .call(index, &mut [], &[3])
+        .assert_logged("first byte 3");
+}
+
+#[test]
+fn assert_no_panic_accepts_errors_but_not_panics() {
+    Env::default()
+        .call(grow, &mut [], &[])
+        .assert_no_panic()
+        .assert_err(ProgramError::NotEnoughAccountKeys);
+    let panicked = std::panic::catch_unwind(|| {
+        Env::default().call(index, &mut [], &[]).assert_no_panic();
+    });
+    assert!(panicked.is_err());
+}
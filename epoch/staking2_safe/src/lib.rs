@@ -0,0 +1,118 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+};
+
+/// 质押：每经过一个 epoch 累积 reward_per_epoch，任何人都可以触发结算
+#[repr(C)]
+struct Stake {
+    owner: Pubkey,
+    amount: u64,
+    reward_per_epoch: u64,
+    rewards: u64,
+    last_epoch: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let stake_account = next_account_info(accounts_iter)?;
+
+    if stake_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = stake_account.data.borrow_mut();
+    let mut stake = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Stake) };
+
+    let slot = Clock::get()?.slot;
+    let schedule = EpochSchedule::get()?;
+    // 按 epoch 编号计算经过的 epoch 数，跨过的每个边界都计一次奖励
+    let epoch = schedule.get_epoch(slot);
+    let epochs = epoch.saturating_sub(stake.last_epoch);
+    let reward = epochs * stake.reward_per_epoch;
+    stake.rewards += reward;
+    stake.last_epoch = epoch;
+    msg!("Accrued {} for {} epochs", reward, epochs);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Stake, stake) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, epoch_schedule::EpochSchedule, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn accrue() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        env.epoch_schedule = EpochSchedule::custom(8192, 8192, false);
+        let mut stake = TestAccount::new(name_key("stake"), program_id).with_data(
+            &[
+                &name_key("staker").to_bytes()[..], // owner
+                &1_000_000u64.to_le_bytes(),        // amount
+                &500u64.to_le_bytes(),              // reward_per_epoch
+                &0u64.to_le_bytes(),                // rewards
+                &10u64.to_le_bytes(),               // last_epoch
+            ]
+            .concat(),
+        );
+
+        // 质押从 epoch 10 开始计息；有人在 epoch 10 的最后一个 slot 触发结算
+        env.clock = Clock {
+            slot: 90_111,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 10,
+            leader_schedule_epoch: 11,
+            unix_timestamp: 1_700_003_276,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut stake], &[1])
+            .assert_ok();
+
+        // 下一个 slot 进入 epoch 11，再结算一次
+        env.clock = Clock {
+            slot: 90_112,
+            epoch_start_timestamp: 1_700_003_276,
+            epoch: 11,
+            leader_schedule_epoch: 12,
+            unix_timestamp: 1_700_003_276,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut stake], &[1])
+            .assert_ok();
+
+        // epoch 12 的第一个 slot 结算
+        env.clock = Clock {
+            slot: 98_304,
+            epoch_start_timestamp: 1_700_006_553,
+            epoch: 12,
+            leader_schedule_epoch: 13,
+            unix_timestamp: 1_700_006_553,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut stake], &[1])
+            .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(stake.data().as_ptr() as *const super::Stake) };
+        assert_eq!(state.rewards, 1000);
+        assert_eq!(state.last_epoch, 12);
+    }
+}
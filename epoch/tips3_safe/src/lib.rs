@@ -0,0 +1,122 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+};
+
+/// 验证者的小费池：每个 epoch 可以领取一次 per_epoch
+#[repr(C)]
+struct TipPool {
+    validator: Pubkey,
+    per_epoch: u64,
+    paid: u64,
+    last_paid_epoch: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let validator = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const TipPool) };
+    if !validator.is_signer || pool.validator != *validator.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let slot = Clock::get()?.slot;
+    let schedule = EpochSchedule::get()?;
+    let epoch = schedule.get_epoch(slot);
+    if epoch <= pool.last_paid_epoch {
+        msg!("Already paid for epoch {}", epoch);
+        return Err(ProgramError::Custom(1));
+    }
+
+    pool.paid += pool.per_epoch;
+    pool.last_paid_epoch = epoch;
+    msg!("Paid {} for epoch {}", pool.per_epoch, epoch);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut TipPool, pool) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, epoch_schedule::EpochSchedule, program_error::ProgramError, pubkey::Pubkey,
+        system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn claim_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        env.epoch_schedule = EpochSchedule::custom(8192, 4096, false);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &name_key("validator").to_bytes()[..], // validator
+                &2000u64.to_le_bytes(),                // per_epoch
+                &0u64.to_le_bytes(),                   // paid
+                &9u64.to_le_bytes(),                   // last_paid_epoch
+            ]
+            .concat(),
+        );
+        let mut validator = TestAccount::new(name_key("validator"), system_program::ID).signer();
+
+        // epoch 10 是 slot 81920 到 90111；在第一个 slot 领取
+        env.clock = Clock {
+            slot: 81_920,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 10,
+            leader_schedule_epoch: 10,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut validator],
+            &[1],
+        )
+        .assert_ok();
+
+        // 同一个 epoch 的最后一个 slot 再领一次
+        env.clock = Clock {
+            slot: 90_111,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 10,
+            leader_schedule_epoch: 11,
+            unix_timestamp: 1_700_003_276,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut validator],
+            &[1],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::TipPool) };
+        assert_eq!(state.paid, 2000);
+        assert_eq!(state.last_paid_epoch, 10);
+    }
+}
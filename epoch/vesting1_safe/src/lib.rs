@@ -0,0 +1,132 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+};
+
+/// 按 epoch 释放的锁仓：每个 epoch 可以领取一次 per_epoch，直到领完 total
+#[repr(C)]
+struct Vesting {
+    beneficiary: Pubkey,
+    total: u64,
+    per_epoch: u64,
+    released: u64,
+    last_claim_epoch: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vesting_account = next_account_info(accounts_iter)?;
+    let beneficiary = next_account_info(accounts_iter)?;
+    let schedule_account = next_account_info(accounts_iter)?;
+
+    if vesting_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = vesting_account.data.borrow_mut();
+    let mut vesting = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Vesting) };
+    if !beneficiary.is_signer || vesting.beneficiary != *beneficiary.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let clock = Clock::get()?;
+    let schedule = EpochSchedule::from_account_info(schedule_account)?;
+    // 按 EpochSchedule 换算，包括 warmup 阶段更短的 epoch
+    let epoch = schedule.get_epoch(clock.slot);
+    if epoch <= vesting.last_claim_epoch {
+        msg!("Already claimed in epoch {}", epoch);
+        return Err(ProgramError::Custom(1));
+    }
+
+    let amount = vesting.per_epoch.min(vesting.total - vesting.released);
+    vesting.released += amount;
+    vesting.last_claim_epoch = epoch;
+    msg!("Released {} in epoch {}", amount, epoch);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Vesting, vesting) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, epoch_schedule::EpochSchedule, program_error::ProgramError, pubkey::Pubkey,
+        system_program, sysvar,
+    };
+    use solracebench_testkit::{epoch_schedule_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn claim_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        env.epoch_schedule = EpochSchedule::custom(8192, 8192, true);
+        let mut vesting = TestAccount::new(name_key("vesting"), program_id).with_data(
+            &[
+                &name_key("beneficiary").to_bytes()[..], // beneficiary
+                &10_000u64.to_le_bytes(),                // total
+                &1000u64.to_le_bytes(),                  // per_epoch
+                &0u64.to_le_bytes(),                     // released
+                &0u64.to_le_bytes(),                     // last_claim_epoch
+            ]
+            .concat(),
+        );
+        let mut beneficiary =
+            TestAccount::new(name_key("beneficiary"), system_program::ID).signer();
+
+        // warmup 之后第一个正常 epoch 从 slot 8160 开始，epoch 12 是 slot 40928 到 49119；在第一个 slot 领取
+        env.clock = Clock {
+            slot: 40_928,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 12,
+            leader_schedule_epoch: 13,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut epoch_schedule = TestAccount::sysvar(
+            sysvar::epoch_schedule::ID,
+            &epoch_schedule_data(&env.epoch_schedule),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vesting, &mut beneficiary, &mut epoch_schedule],
+            &[1],
+        )
+        .assert_ok();
+
+        // 同一个 epoch 的最后一个 slot 再领一次
+        env.clock = Clock {
+            slot: 49_119,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 12,
+            leader_schedule_epoch: 13,
+            unix_timestamp: 1_700_003_276,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vesting, &mut beneficiary, &mut epoch_schedule],
+            &[1],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        let state =
+            unsafe { std::ptr::read_unaligned(vesting.data().as_ptr() as *const super::Vesting) };
+        assert_eq!(state.released, 1000);
+        assert_eq!(state.last_claim_epoch, 12);
+    }
+}
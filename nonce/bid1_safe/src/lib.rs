@@ -0,0 +1,143 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 每轮拍卖持续的秒数
+const ROUND_SECONDS: i64 = 3600;
+
+/// 按轮次进行的拍卖，结算后同一个账户开始下一轮
+#[repr(C)]
+struct Auction {
+    round: u64,
+    end_time: i64,
+    highest_bid: u64,
+    bidder: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let auction_account = next_account_info(accounts_iter)?;
+    if auction_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let now = Clock::get()?.unix_timestamp;
+
+    let mut data = auction_account.data.borrow_mut();
+    let mut auction = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Auction) };
+
+    match instruction_data[0] {
+        // 出价
+        1 => {
+            let bidder = next_account_info(accounts_iter)?;
+            if !bidder.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let round = u64::from_le_bytes(instruction_data[9..17].try_into().unwrap());
+            // 交易被扣留到签名时的那一轮结束后才落地，不能算作下一轮的出价
+            if round != auction.round {
+                msg!("Bid signed for round {}", round);
+                return Err(ProgramError::Custom(4));
+            }
+            if now > auction.end_time {
+                msg!("Auction expired");
+                return Err(ProgramError::Custom(1));
+            }
+            if amount <= auction.highest_bid {
+                return Err(ProgramError::Custom(2));
+            }
+            auction.highest_bid = amount;
+            auction.bidder = *bidder.key;
+            msg!("Bid {} in round {}", amount, auction.round);
+        }
+        // 结算当前一轮并开始下一轮
+        2 => {
+            if now <= auction.end_time {
+                return Err(ProgramError::Custom(3));
+            }
+            msg!(
+                "Round {} won by {} at {}",
+                auction.round,
+                auction.bidder,
+                auction.highest_bid
+            );
+            auction.round += 1;
+            auction.end_time = now + ROUND_SECONDS;
+            auction.highest_bid = 0;
+            auction.bidder = Pubkey::default();
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Auction, auction) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn settle_then_bid_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut auction = TestAccount::new(name_key("auction"), program_id).with_data(
+            &[
+                &1u64.to_le_bytes()[..],         // round
+                &1_700_000_600i64.to_le_bytes(), // end_time
+                &0u64.to_le_bytes(),             // highest_bid
+                &[0; 32],                        // bidder
+            ]
+            .concat(),
+        );
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+        let mut keeper = TestAccount::new(name_key("keeper"), system_program::ID).signer();
+
+        // 第 1 轮在 end_time 之后被结算，开始第 2 轮
+        env.clock = Clock {
+            slot: 3000,
+            unix_timestamp: 1_700_000_800,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut keeper],
+            &[2],
+        )
+        .assert_ok();
+
+        // victim 在 slot 1000 用 durable nonce 签名的第 1 轮 500 出价被扣留到 slot 3100 才落地
+        env.clock = Clock {
+            slot: 3100,
+            unix_timestamp: 1_700_000_840,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut auction, &mut victim],
+            &[&[1][..], &500u64.to_le_bytes(), &1u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::Custom(4));
+
+        let state =
+            unsafe { std::ptr::read_unaligned(auction.data().as_ptr() as *const super::Auction) };
+        assert_eq!(state.round, 2);
+        assert_eq!(state.end_time, 1_700_004_400);
+    }
+}
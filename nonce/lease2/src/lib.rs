@@ -0,0 +1,119 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+/// 每天的租金（lamports）
+const DAILY_RENT: u64 = 1_000_000;
+
+/// 一个租赁季内的场地租约，租期不能超过 season_end
+#[repr(C)]
+struct Lease {
+    tenant: Pubkey,
+    start_time: i64,
+    end_time: i64,
+    season_end: i64,
+    rent_owed: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let lease_account = next_account_info(accounts_iter)?;
+    let tenant = next_account_info(accounts_iter)?;
+
+    if lease_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !tenant.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let days = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let mut data = lease_account.data.borrow_mut();
+    let mut lease = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Lease) };
+    if lease.tenant != Pubkey::default() {
+        return Err(ProgramError::Custom(1));
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    if now >= lease.season_end {
+        return Err(ProgramError::Custom(2));
+    }
+    let duration = (days as i64)
+        .checked_mul(SECONDS_PER_DAY)
+        .ok_or(ProgramError::InvalidArgument)?;
+    // 租期从交易落地的时刻算起，租金却按签名时选的天数收取
+    lease.start_time = now;
+    lease.end_time = now.saturating_add(duration).min(lease.season_end);
+    lease.rent_owed = days * DAILY_RENT;
+    lease.tenant = *tenant.key;
+    msg!(
+        "Leased from {} to {} for {}",
+        lease.start_time,
+        lease.end_time,
+        lease.rent_owed
+    );
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Lease, lease) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn start_lease() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lease = TestAccount::new(name_key("lease"), program_id).with_data(
+            &[
+                &[0; 32][..],                    // tenant
+                &0i64.to_le_bytes(),             // start_time
+                &0i64.to_le_bytes(),             // end_time
+                &1_702_592_000i64.to_le_bytes(), // season_end
+                &0u64.to_le_bytes(),             // rent_owed
+            ]
+            .concat(),
+        );
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+
+        // victim 在 slot 1000 用 durable nonce 签名租 30 天，交易被扣留 20 天才落地
+        env.clock = Clock {
+            slot: 4_321_000,
+            unix_timestamp: 1_701_728_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lease, &mut victim],
+            &[&[1][..], &30u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Leased from 1701728000 to 1702592000 for 30000000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(lease.data().as_ptr() as *const super::Lease) };
+        assert_eq!(state.tenant, name_key("victim"));
+        assert_eq!(state.start_time, 1_701_728_000);
+        assert_eq!(state.end_time, 1_702_592_000);
+        assert_eq!(state.rent_owed, 30_000_000);
+    }
+}
@@ -0,0 +1,130 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+/// 每天的租金（lamports）
+const DAILY_RENT: u64 = 1_000_000;
+/// 交易最多可以比签名时选定的开始时刻晚多少秒落地
+const START_GRACE: i64 = 60;
+
+/// 一个租赁季内的场地租约，租期不能超过 season_end
+#[repr(C)]
+struct Lease {
+    tenant: Pubkey,
+    start_time: i64,
+    end_time: i64,
+    season_end: i64,
+    rent_owed: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let lease_account = next_account_info(accounts_iter)?;
+    let tenant = next_account_info(accounts_iter)?;
+
+    if lease_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !tenant.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let days = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+    let start_time = i64::from_le_bytes(instruction_data[9..17].try_into().unwrap());
+
+    let mut data = lease_account.data.borrow_mut();
+    let mut lease = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Lease) };
+    if lease.tenant != Pubkey::default() {
+        return Err(ProgramError::Custom(1));
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    // 交易被扣留得太久时拒绝，而不是让租期从落地时刻开始
+    if now > start_time.saturating_add(START_GRACE) {
+        msg!("Lease start {} missed", start_time);
+        return Err(ProgramError::Custom(3));
+    }
+    if start_time >= lease.season_end {
+        return Err(ProgramError::Custom(2));
+    }
+    let duration = (days as i64)
+        .checked_mul(SECONDS_PER_DAY)
+        .ok_or(ProgramError::InvalidArgument)?;
+    lease.start_time = start_time;
+    lease.end_time = start_time.saturating_add(duration).min(lease.season_end);
+    lease.rent_owed = days * DAILY_RENT;
+    lease.tenant = *tenant.key;
+    msg!(
+        "Leased from {} to {} for {}",
+        lease.start_time,
+        lease.end_time,
+        lease.rent_owed
+    );
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Lease, lease) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn start_lease_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut lease = TestAccount::new(name_key("lease"), program_id).with_data(
+            &[
+                &[0; 32][..],                    // tenant
+                &0i64.to_le_bytes(),             // start_time
+                &0i64.to_le_bytes(),             // end_time
+                &1_702_592_000i64.to_le_bytes(), // season_end
+                &0u64.to_le_bytes(),             // rent_owed
+            ]
+            .concat(),
+        );
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+        let before_lease = lease.snapshot();
+        let before_victim = victim.snapshot();
+
+        // victim 在 slot 1000 用 durable nonce 签名从 1700000000 起租 30 天，交易被扣留 20 天才落地
+        env.clock = Clock {
+            slot: 4_321_000,
+            unix_timestamp: 1_701_728_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut lease, &mut victim],
+            &[
+                &[1][..],
+                &30u64.to_le_bytes(),
+                &1_700_000_000i64.to_le_bytes(),
+            ]
+            .concat(),
+        )
+        .assert_err(ProgramError::Custom(3));
+
+        lease.assert_unchanged(&before_lease);
+        victim.assert_unchanged(&before_victim);
+    }
+}
@@ -0,0 +1,114 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 提款窗口关闭后提前解押的罚金比例（基点）
+const PENALTY_BPS: u64 = 1_000;
+
+/// 质押账户：window_end 之前可以免费提取，之后质押自动续期，提前解押要交罚金
+#[repr(C)]
+struct Stake {
+    owner: Pubkey,
+    amount: u64,
+    window_end: u64,
+    penalties: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let stake_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if stake_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let mut data = stake_account.data.borrow_mut();
+    let mut stake = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Stake) };
+    if !owner.is_signer || stake.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    stake.amount = stake
+        .amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    // 是否收罚金取决于交易落地的 slot，而不是 owner 签名时窗口是否还开着
+    let penalty = if Clock::get()?.slot > stake.window_end {
+        amount * PENALTY_BPS / 10_000
+    } else {
+        0
+    };
+    let payout = amount - penalty;
+    stake.penalties += penalty;
+    **stake_account.try_borrow_mut_lamports()? -= payout;
+    **owner.try_borrow_mut_lamports()? += payout;
+    msg!("Withdrew {} with penalty {}", payout, penalty);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Stake, stake) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn withdraw() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut stake = TestAccount::new(name_key("stake"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("victim").to_bytes()[..], // owner
+                    &10_000_000u64.to_le_bytes(),       // amount
+                    &2000u64.to_le_bytes(),             // window_end
+                    &0u64.to_le_bytes(),                // penalties
+                ]
+                .concat(),
+            );
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // victim 在 slot 1000 用 durable nonce 签名提取 10000000，交易被扣留到窗口关闭后的 slot 2500 才落地
+        env.clock = Clock {
+            slot: 2500,
+            unix_timestamp: 1_700_000_600,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stake, &mut victim],
+            &[&[1][..], &10_000_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Withdrew 9000000 with penalty 1000000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(stake.data().as_ptr() as *const super::Stake) };
+        assert_eq!(state.amount, 0);
+        assert_eq!(state.penalties, 1_000_000);
+        stake.assert_lamports(2_000_000);
+        victim.assert_lamports(10_000_000);
+    }
+}
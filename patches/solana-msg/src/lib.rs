@@ -0,0 +1,64 @@
+//! 上游 solana-msg 2.2.1 的替身，链上行为不变；本地执行时可以通过
+//! [`set_log_hook`] 把日志交给执行器，而不是直接 `println!`。
+
+/// Print a message to the log.
+///
+/// Supports simple strings as well as Rust [format strings][fs]. When passed a
+/// single expression it will be passed directly to [`sol_log`]. The expression
+/// must have type `&str`, and is typically used for logging static strings.
+/// When passed something other than an expression, particularly
+/// a sequence of expressions, the tokens will be passed through the
+/// [`format!`] macro before being logged with `sol_log`.
+///
+/// [fs]: https://doc.rust-lang.org/std/fmt/
+/// [`format!`]: https://doc.rust-lang.org/std/fmt/fn.format.html
+///
+/// Note that Rust's formatting machinery is relatively CPU-intensive
+/// for constrained environments like the Solana VM.
+///
+/// # Examples
+///
+/// ```
+/// use solana_msg::msg;
+///
+/// // The fast form
+/// msg!("verifying multisig");
+///
+/// // With formatting
+/// let err = "not enough signers";
+/// msg!("multisig failed: {}", err);
+/// ```
+#[macro_export]
+macro_rules! msg {
+    ($msg:expr) => {
+        $crate::sol_log($msg)
+    };
+    ($($arg:tt)*) => ($crate::sol_log(&format!($($arg)*)));
+}
+
+#[cfg(target_os = "solana")]
+pub mod syscalls;
+
+/// Print a string to the log.
+#[inline]
+pub fn sol_log(message: &str) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        syscalls::sol_log_(message.as_ptr(), message.len() as u64);
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    match *LOG_HOOK.read().unwrap_or_else(|e| e.into_inner()) {
+        Some(hook) => hook(message),
+        None => println!("{message}"),
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+static LOG_HOOK: std::sync::RwLock<Option<fn(&str)>> = std::sync::RwLock::new(None);
+
+/// 设置本地执行时接收日志的函数，`None` 恢复为打印到标准输出
+#[cfg(not(target_os = "solana"))]
+pub fn set_log_hook(hook: Option<fn(&str)>) {
+    *LOG_HOOK.write().unwrap_or_else(|e| e.into_inner()) = hook;
+}
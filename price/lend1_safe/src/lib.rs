@@ -0,0 +1,160 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const PRICE_MAGIC: u32 = 0xa1b2_c3d4;
+/// 价格最多可以是多少个 slot 之前写入的，约 60 秒
+const MAX_PRICE_AGE_SLOTS: u64 = 150;
+
+/// 借贷市场：抵押品按预言机价格估值，最多借出估值的 ltv_bps
+#[repr(C)]
+struct Market {
+    oracle: Pubkey,
+    ltv_bps: u64,
+}
+
+/// 用户的借款仓位，抵押品以最小单位计
+#[repr(C)]
+struct Obligation {
+    owner: Pubkey,
+    collateral: u64,
+    debt: u64,
+}
+
+/// Pyth 风格的价格账户：价格为 price × 10^expo
+#[repr(C)]
+struct PriceFeed {
+    magic: u32,
+    expo: i32,
+    price: i64,
+    conf: u64,
+    publish_time: i64,
+    publish_slot: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let market_account = next_account_info(accounts_iter)?;
+    let obligation_account = next_account_info(accounts_iter)?;
+    let price_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if market_account.owner != program_id || obligation_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let market =
+        unsafe { std::ptr::read_unaligned(market_account.data.borrow().as_ptr() as *const Market) };
+    let feed = unsafe {
+        std::ptr::read_unaligned(price_account.data.borrow().as_ptr() as *const PriceFeed)
+    };
+    if *price_account.key != market.oracle || feed.magic != PRICE_MAGIC || feed.price <= 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut data = obligation_account.data.borrow_mut();
+    let mut obligation = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Obligation) };
+    if !owner.is_signer || obligation.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 价格年龄按 slot 计算，leader 无法像时间戳那样调整
+    let age = Clock::get()?.slot.saturating_sub(feed.publish_slot);
+    if age > MAX_PRICE_AGE_SLOTS {
+        msg!("Price is {} slots old", age);
+        return Err(ProgramError::Custom(1));
+    }
+
+    let value =
+        obligation.collateral as u128 * feed.price as u128 / 10u128.pow(feed.expo.unsigned_abs());
+    let limit = (value * market.ltv_bps as u128 / 10_000) as u64;
+    let debt = obligation.debt + amount;
+    if debt > limit {
+        msg!("Debt {} exceeds limit {}", debt, limit);
+        return Err(ProgramError::Custom(2));
+    }
+    obligation.debt = debt;
+    msg!("Borrowed {} against collateral worth {}", amount, value);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Obligation, obligation) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn borrow_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut market = TestAccount::new(name_key("market"), program_id).with_data(
+            &[
+                &name_key("feed").to_bytes()[..], // oracle
+                &5000u64.to_le_bytes(),           // ltv_bps
+            ]
+            .concat(),
+        );
+        let mut obligation = TestAccount::new(name_key("obligation"), program_id).with_data(
+            &[
+                &name_key("borrower").to_bytes()[..], // owner
+                &1000u64.to_le_bytes(),               // collateral
+                &0u64.to_le_bytes(),                  // debt
+            ]
+            .concat(),
+        );
+        let mut feed = TestAccount::new(name_key("feed"), name_key("price_program")).with_data(
+            &[
+                &0xa1b2_c3d4u32.to_le_bytes()[..], // magic
+                &(-6i32).to_le_bytes(),            // expo
+                &25_000_000i64.to_le_bytes(),      // price
+                &10_000u64.to_le_bytes(),          // conf
+                &1_700_085_800i64.to_le_bytes(),   // publish_time
+                &297_090_756u64.to_le_bytes(),     // publish_slot
+            ]
+            .concat(),
+        );
+        let mut borrower = TestAccount::new(name_key("borrower"), system_program::ID).signer();
+        let before_market = market.snapshot();
+        let before_obligation = obligation.snapshot();
+
+        // 价格在 1500 个 slot 之前写入；leader 的时间戳比 epoch 起点按 PoH 推算的时刻慢 560 秒
+        env.clock = Clock {
+            slot: 297_092_256,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 700,
+            leader_schedule_epoch: 701,
+            unix_timestamp: 1_700_085_840,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut market, &mut obligation, &mut feed, &mut borrower],
+            &[&[1][..], &12_500u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        market.assert_unchanged(&before_market);
+        obligation.assert_unchanged(&before_obligation);
+    }
+}
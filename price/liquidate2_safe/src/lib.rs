@@ -0,0 +1,165 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const PRICE_MAGIC: u32 = 0xa1b2_c3d4;
+/// 价格最多可以是多少个 slot 之前写入的，约 60 秒
+const MAX_PRICE_AGE_SLOTS: u64 = 150;
+
+/// 借贷市场：抵押品估值乘以 threshold_bps 低于债务的仓位可以被清算
+#[repr(C)]
+struct Market {
+    oracle: Pubkey,
+    threshold_bps: u64,
+}
+
+/// 借款仓位；被清算后抵押品归清算人，liquidator 记录清算人
+#[repr(C)]
+struct Position {
+    owner: Pubkey,
+    collateral: u64,
+    debt: u64,
+    liquidator: Pubkey,
+}
+
+/// Pyth 风格的价格账户：价格为 price × 10^expo
+#[repr(C)]
+struct PriceFeed {
+    magic: u32,
+    expo: i32,
+    price: i64,
+    conf: u64,
+    publish_time: i64,
+    publish_slot: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let market_account = next_account_info(accounts_iter)?;
+    let position_account = next_account_info(accounts_iter)?;
+    let price_account = next_account_info(accounts_iter)?;
+    let liquidator = next_account_info(accounts_iter)?;
+
+    if market_account.owner != program_id || position_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !liquidator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let market =
+        unsafe { std::ptr::read_unaligned(market_account.data.borrow().as_ptr() as *const Market) };
+    let feed = unsafe {
+        std::ptr::read_unaligned(price_account.data.borrow().as_ptr() as *const PriceFeed)
+    };
+    if *price_account.key != market.oracle || feed.magic != PRICE_MAGIC || feed.price <= 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 价格年龄按 slot 计算，leader 无法像时间戳那样调整
+    let age = Clock::get()?.slot.saturating_sub(feed.publish_slot);
+    if age > MAX_PRICE_AGE_SLOTS {
+        msg!("Price is {} slots old", age);
+        return Err(ProgramError::Custom(1));
+    }
+
+    let mut data = position_account.data.borrow_mut();
+    let mut position = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Position) };
+    let value =
+        position.collateral as u128 * feed.price as u128 / 10u128.pow(feed.expo.unsigned_abs());
+    if value * market.threshold_bps as u128 / 10_000 >= position.debt as u128 {
+        msg!("Position is healthy at {}", value);
+        return Err(ProgramError::Custom(2));
+    }
+    msg!(
+        "Liquidated {} collateral worth {} for debt {}",
+        position.collateral,
+        value,
+        position.debt
+    );
+    position.collateral = 0;
+    position.debt = 0;
+    position.liquidator = *liquidator.key;
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Position, position) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn liquidate_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut market = TestAccount::new(name_key("market"), program_id).with_data(
+            &[
+                &name_key("feed").to_bytes()[..], // oracle
+                &8000u64.to_le_bytes(),           // threshold_bps
+            ]
+            .concat(),
+        );
+        let mut position = TestAccount::new(name_key("position"), program_id).with_data(
+            &[
+                &name_key("borrower").to_bytes()[..], // owner
+                &1000u64.to_le_bytes(),               // collateral
+                &17_000u64.to_le_bytes(),             // debt
+                &[0; 32],                             // liquidator
+            ]
+            .concat(),
+        );
+        let mut feed = TestAccount::new(name_key("feed"), name_key("price_program")).with_data(
+            &[
+                &0xa1b2_c3d4u32.to_le_bytes()[..], // magic
+                &(-6i32).to_le_bytes(),            // expo
+                &20_000_000i64.to_le_bytes(),      // price
+                &10_000u64.to_le_bytes(),          // conf
+                &1_700_082_800i64.to_le_bytes(),   // publish_time
+                &297_083_256u64.to_le_bytes(),     // publish_slot
+            ]
+            .concat(),
+        );
+        let mut liquidator = TestAccount::new(name_key("liquidator"), system_program::ID).signer();
+        let before_market = market.snapshot();
+        let before_position = position.snapshot();
+
+        // 价格停留在一小时前下跌时写入的 20；liquidator 在时间戳准确的 slot 清算
+        env.clock = Clock {
+            slot: 297_092_256,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 700,
+            leader_schedule_epoch: 701,
+            unix_timestamp: 1_700_086_400,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut market, &mut position, &mut feed, &mut liquidator],
+            &[1],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        market.assert_unchanged(&before_market);
+        position.assert_unchanged(&before_position);
+    }
+}
@@ -0,0 +1,148 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const PRICE_MAGIC: u32 = 0xa1b2_c3d4;
+
+/// 现金结算的看涨期权：到期后按预言机价格向持有人支付 size × (价格 - strike)
+#[repr(C)]
+struct Contract {
+    oracle: Pubkey,
+    holder: Pubkey,
+    strike: i64,
+    expiry: i64,
+    size: u64,
+    settle_price: i64,
+    payout: u64,
+}
+
+/// Pyth 风格的价格账户：价格为 price × 10^expo
+#[repr(C)]
+struct PriceFeed {
+    magic: u32,
+    expo: i32,
+    price: i64,
+    conf: u64,
+    publish_time: i64,
+    publish_slot: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let contract_account = next_account_info(accounts_iter)?;
+    let price_account = next_account_info(accounts_iter)?;
+    let holder = next_account_info(accounts_iter)?;
+
+    if contract_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = contract_account.data.borrow_mut();
+    let mut contract = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Contract) };
+    if !holder.is_signer || contract.holder != *holder.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let feed = unsafe {
+        std::ptr::read_unaligned(price_account.data.borrow().as_ptr() as *const PriceFeed)
+    };
+    if *price_account.key != contract.oracle || feed.magic != PRICE_MAGIC || feed.price <= 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if contract.settle_price != 0 {
+        return Err(ProgramError::Custom(1));
+    }
+
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < contract.expiry {
+        msg!("Contract expires at {}", contract.expiry);
+        return Err(ProgramError::Custom(2));
+    }
+    // 只要价格发布在当前 epoch 开始之后就当作新鲜，一个 epoch 长达两天
+    if feed.publish_time < clock.epoch_start_timestamp {
+        msg!("Price was published before this epoch");
+        return Err(ProgramError::Custom(3));
+    }
+
+    let intrinsic = feed.price.saturating_sub(contract.strike).max(0) as u128;
+    contract.payout =
+        (contract.size as u128 * intrinsic / 10u128.pow(feed.expo.unsigned_abs())) as u64;
+    contract.settle_price = feed.price;
+    msg!("Settled at {} paying {}", feed.price, contract.payout);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Contract, contract) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn settle() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut contract = TestAccount::new(name_key("contract"), program_id).with_data(
+            &[
+                &name_key("feed").to_bytes()[..], // oracle
+                &name_key("holder").to_bytes(),   // holder
+                &25_000_000i64.to_le_bytes(),     // strike
+                &1_700_086_000i64.to_le_bytes(),  // expiry
+                &1000u64.to_le_bytes(),           // size
+                &0i64.to_le_bytes(),              // settle_price
+                &0u64.to_le_bytes(),              // payout
+            ]
+            .concat(),
+        );
+        let mut feed = TestAccount::new(name_key("feed"), name_key("price_program")).with_data(
+            &[
+                &0xa1b2_c3d4u32.to_le_bytes()[..], // magic
+                &(-6i32).to_le_bytes(),            // expo
+                &30_000_000i64.to_le_bytes(),      // price
+                &10_000u64.to_le_bytes(),          // conf
+                &1_700_064_800i64.to_le_bytes(),   // publish_time
+                &297_038_256u64.to_le_bytes(),     // publish_slot
+            ]
+            .concat(),
+        );
+        let mut holder = TestAccount::new(name_key("holder"), system_program::ID).signer();
+
+        // 期权在 1700086000 到期；价格停留在六小时前写入的 30，holder 在到期后结算
+        env.clock = Clock {
+            slot: 297_092_256,
+            epoch_start_timestamp: 1_700_000_000,
+            epoch: 700,
+            leader_schedule_epoch: 701,
+            unix_timestamp: 1_700_086_400,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut contract, &mut feed, &mut holder],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Settled at 30000000 paying 5000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(contract.data().as_ptr() as *const super::Contract) };
+        assert_eq!(state.settle_price, 30_000_000);
+        assert_eq!(state.payout, 5000);
+    }
+}
@@ -0,0 +1,109 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+/// 每次抽奖有 10% 的概率中头奖，每局只能抽一次
+const WIN_PERCENT: u64 = 10;
+
+#[repr(C)]
+struct Game {
+    jackpot: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+    let recent_blockhashes_account = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !sysvar::recent_blockhashes::check_id(recent_blockhashes_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+    if game.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    game.open = 0;
+
+    // 取最新的 blockhash：u64 条目数之后依次是 (32 字节 blockhash, u64 lamports_per_signature)
+    let blockhashes = recent_blockhashes_account.data.borrow();
+    let entropy = u64::from_le_bytes(blockhashes[8..16].try_into().unwrap());
+    if entropy % 100 < WIN_PERCENT {
+        **game_account.try_borrow_mut_lamports()? -= game.jackpot;
+        **player.try_borrow_mut_lamports()? += game.jackpot;
+        msg!("Jackpot {}", game.jackpot);
+    } else {
+        msg!("No luck");
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{name_key, recent_blockhashes_data, Env, TestAccount};
+
+    #[test]
+    fn play() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &10_000_000u64.to_le_bytes()[..], // jackpot
+                    &[1],                             // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // slot 1001 提交
+        env.clock = Clock {
+            slot: 1001,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut recent_blockhashes = TestAccount::sysvar(
+            sysvar::recent_blockhashes::ID,
+            &recent_blockhashes_data(env.clock.slot),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player, &mut recent_blockhashes],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Jackpot 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.open, 0);
+        game.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,150 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{self, clock::Clock, Sysvar},
+};
+
+/// 下注之后至少等待的 slot 数
+const REVEAL_DELAY: u64 = 2;
+
+/// 先下注猜正反面，等待若干 slot 后再开奖；猜中拿走奖池
+#[repr(C)]
+struct Bet {
+    bettor: [u8; 32],
+    prize: u64,
+    bet_slot: u64,
+    /// 1 可下注，2 等待开奖，0 已结束
+    state: u8,
+    guess: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let bet_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = bet_account.data.borrow_mut();
+    let mut bet = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Bet) };
+    let clock = Clock::get()?;
+
+    match instruction_data[0] {
+        0 => {
+            if bet.state != 1 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            bet.bettor = player.key.to_bytes();
+            bet.bet_slot = clock.slot;
+            bet.guess = instruction_data[1];
+            bet.state = 2;
+            msg!("Bet {} at slot {}", bet.guess, clock.slot);
+        }
+        1 => {
+            let slot_hashes_account = next_account_info(accounts_iter)?;
+            if !sysvar::slot_hashes::check_id(slot_hashes_account.key) {
+                return Err(ProgramError::InvalidArgument);
+            }
+            if bet.state != 2 || player.key.to_bytes() != bet.bettor {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            // 下注时还不存在的 slot 哈希，下注者无法提前知道
+            if clock.slot < bet.bet_slot + REVEAL_DELAY {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let slot_hashes = slot_hashes_account.data.borrow();
+            let side = slot_hashes[16] % 2;
+            bet.state = 0;
+            if bet.guess == side {
+                **bet_account.try_borrow_mut_lamports()? -= bet.prize;
+                **player.try_borrow_mut_lamports()? += bet.prize;
+                msg!("Won {}", bet.prize);
+            } else {
+                msg!("Lost");
+            }
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Bet, bet) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{name_key, slot_hashes_data, Env, TestAccount};
+
+    #[test]
+    fn bet_then_reveal() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut bet = TestAccount::new(name_key("bet"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &[0; 32][..],                 // bettor
+                    &10_000_000u64.to_le_bytes(), // prize
+                    &0u64.to_le_bytes(),          // bet_slot
+                    &[1],                         // state
+                    &[0],                         // guess
+                    &[0; 6],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // slot 1000 猜 1
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut bet, &mut player],
+            &[0, 1],
+        )
+        .assert_ok();
+
+        // 等到 slot 1003 的最新哈希为奇数再开奖
+        env.clock = Clock {
+            slot: 1003,
+            unix_timestamp: 1_700_000_001,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut bet, &mut player, &mut slot_hashes],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Won 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(bet.data().as_ptr() as *const super::Bet) };
+        assert_eq!(state.bettor, name_key("player").to_bytes());
+        assert_eq!(state.bet_slot, 1000);
+        assert_eq!(state.state, 0);
+        assert_eq!(state.guess, 1);
+        bet.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
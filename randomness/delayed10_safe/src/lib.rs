@@ -0,0 +1,152 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 先下注猜正反面，由庄家揭示开局前承诺的 secret 开奖；猜中拿走奖池
+#[repr(C)]
+struct Bet {
+    house: [u8; 32],
+    commitment: [u8; 32],
+    bettor: [u8; 32],
+    prize: u64,
+    bet_slot: u64,
+    /// 1 可下注，2 等待开奖，0 已结束
+    state: u8,
+    guess: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let bet_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = bet_account.data.borrow_mut();
+    let mut bet = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Bet) };
+
+    match instruction_data[0] {
+        0 => {
+            if bet.state != 1 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let clock = Clock::get()?;
+            bet.bettor = signer.key.to_bytes();
+            bet.bet_slot = clock.slot;
+            bet.guess = instruction_data[1];
+            bet.state = 2;
+            msg!("Bet {} at slot {}", bet.guess, clock.slot);
+        }
+        1 => {
+            let bettor = next_account_info(accounts_iter)?;
+            if signer.key.to_bytes() != bet.house {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if bet.state != 2 || bettor.key.to_bytes() != bet.bettor {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            // 结果只取决于下注前承诺的 secret，下注者无法通过选择开奖时刻影响它
+            let secret = &instruction_data[1..33];
+            if hash(secret).to_bytes() != bet.commitment {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let side = hashv(&[secret, &bet.bet_slot.to_le_bytes()]).to_bytes()[0] % 2;
+            bet.state = 0;
+            if bet.guess == side {
+                **bet_account.try_borrow_mut_lamports()? -= bet.prize;
+                **bettor.try_borrow_mut_lamports()? += bet.prize;
+                msg!("Won {}", bet.prize);
+            } else {
+                msg!("Lost");
+            }
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Bet, bet) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn bet_then_reveal() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut bet = TestAccount::new(name_key("bet"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("house").to_bytes()[..], // house
+                    &[
+                        0x99, 0x85, 0xb2, 0xe4, 0xb9, 0xb7, 0x1b, 0x28, 0xce, 0x59, 0xea, 0x5c,
+                        0xe7, 0x7f, 0x0b, 0x11, 0x06, 0x37, 0xb6, 0x25, 0xc6, 0xd7, 0xf4, 0x1e,
+                        0x98, 0x2c, 0x95, 0x10, 0x94, 0x0a, 0x20, 0x56,
+                    ], // commitment
+                    &[0; 32],                          // bettor
+                    &10_000_000u64.to_le_bytes(),      // prize
+                    &0u64.to_le_bytes(),               // bet_slot
+                    &[1],                              // state
+                    &[0],                              // guess
+                    &[0; 6],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut house = TestAccount::new(name_key("house"), system_program::ID).signer();
+
+        // slot 1000 猜 1
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut bet, &mut player],
+            &[0, 1],
+        )
+        .assert_ok();
+
+        // 庄家揭示 secret 开奖
+        env.clock = Clock {
+            slot: 1002,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut bet, &mut house, &mut player],
+            &[&[1][..], &[0x5e; 32]].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe { std::ptr::read_unaligned(bet.data().as_ptr() as *const super::Bet) };
+        assert_eq!(state.bettor, name_key("player").to_bytes());
+        assert_eq!(state.bet_slot, 1000);
+        assert_eq!(state.state, 0);
+        assert_eq!(state.guess, 1);
+    }
+}
@@ -0,0 +1,101 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::hash,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 掷骰子：猜中点数就拿走奖池，每局只能猜一次
+#[repr(C)]
+struct Game {
+    prize: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let guess = instruction_data[0];
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+    if game.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    game.open = 0;
+
+    // 对时间戳做一次哈希，看起来足够随机
+    let clock = Clock::get()?;
+    let roll = hash(&clock.unix_timestamp.to_le_bytes()).to_bytes()[0] % 6;
+    if guess == roll {
+        **game_account.try_borrow_mut_lamports()? -= game.prize;
+        **player.try_borrow_mut_lamports()? += game.prize;
+        msg!("Rolled {}, won {}", roll, game.prize);
+    } else {
+        msg!("Rolled {}", roll);
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn play() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &10_000_000u64.to_le_bytes()[..], // prize
+                    &[1],                             // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // slot 1000 猜 0
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player],
+            &[0],
+        )
+        .assert_ok()
+        .assert_logged("Rolled 0, won 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.open, 0);
+        game.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,113 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 每次抽奖有 5% 的概率中头奖，每局只能抽一次
+const WIN_PERCENT: u64 = 5;
+/// 哈希轮数
+const ROUNDS: usize = 16;
+
+#[repr(C)]
+struct Game {
+    jackpot: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+    if game.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    game.open = 0;
+
+    // 多轮哈希混合 Clock 的各个字段
+    let clock = Clock::get()?;
+    let mut state = hash(&clock.epoch_start_timestamp.to_le_bytes());
+    for round in 0..ROUNDS {
+        state = hashv(&[
+            state.as_ref(),
+            &clock.slot.to_le_bytes(),
+            &clock.unix_timestamp.to_le_bytes(),
+            &[round as u8],
+        ]);
+    }
+    let entropy = u64::from_le_bytes(state.to_bytes()[..8].try_into().unwrap());
+    if entropy % 100 < WIN_PERCENT {
+        **game_account.try_borrow_mut_lamports()? -= game.jackpot;
+        **player.try_borrow_mut_lamports()? += game.jackpot;
+        msg!("Jackpot {}", game.jackpot);
+    } else {
+        msg!("No luck");
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn play() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &10_000_000u64.to_le_bytes()[..], // jackpot
+                    &[1],                             // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // slot 1033 提交
+        env.clock = Clock {
+            slot: 1033,
+            unix_timestamp: 1_700_000_013,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Jackpot 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.open, 0);
+        game.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
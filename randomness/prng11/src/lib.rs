@@ -0,0 +1,110 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 猜数字：猜中 0..10 中的开奖号就拿走奖池；每猜一次伪随机数生成器前进一步
+#[repr(C)]
+struct Game {
+    /// xorshift64 的内部状态，创建时由庄家随机选取
+    seed: u64,
+    prize: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+fn xorshift64(mut x: u64) -> u64 {
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    x
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let guess = instruction_data[0];
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+    if game.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 种子存放在任何人都能读取的账户里
+    game.seed = xorshift64(game.seed);
+    let number = (game.seed % 10) as u8;
+    if guess == number {
+        game.open = 0;
+        **game_account.try_borrow_mut_lamports()? -= game.prize;
+        **player.try_borrow_mut_lamports()? += game.prize;
+        msg!("Drew {}, won {}", number, game.prize);
+    } else {
+        msg!("Drew {}", number);
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn play() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &2_685_821_657_736_338_717u64.to_le_bytes()[..], // seed
+                    &10_000_000u64.to_le_bytes(),                    // prize
+                    &[1],                                            // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // 按种子算出的开奖号猜 1
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Drew 1, won 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.seed, 9_181_757_771_948_286_951);
+        assert_eq!(state.open, 0);
+        game.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
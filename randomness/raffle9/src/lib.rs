@@ -0,0 +1,107 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const TICKETS: usize = 4;
+
+/// 售出全部彩票后任何人都可以开奖，奖池付给抽中的持有者
+#[repr(C)]
+struct Raffle {
+    holders: [[u8; 32]; TICKETS],
+    prize: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let raffle_account = next_account_info(accounts_iter)?;
+    let winner = next_account_info(accounts_iter)?;
+
+    let mut data = raffle_account.data.borrow_mut();
+    let mut raffle = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Raffle) };
+    if raffle.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 开奖时刻由调用者决定
+    let clock = Clock::get()?;
+    let draw = hashv(&[
+        &clock.slot.to_le_bytes(),
+        &clock.unix_timestamp.to_le_bytes(),
+    ]);
+    let index = draw.to_bytes()[0] as usize % TICKETS;
+    if winner.key.to_bytes() != raffle.holders[index] {
+        return Err(ProgramError::InvalidArgument);
+    }
+    raffle.open = 0;
+
+    **raffle_account.try_borrow_mut_lamports()? -= raffle.prize;
+    **winner.try_borrow_mut_lamports()? += raffle.prize;
+    msg!("Ticket {} won {}", index, raffle.prize);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Raffle, raffle) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn draw() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut raffle = TestAccount::new(name_key("raffle"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("alice").to_bytes()[..], // holders[0]
+                    &name_key("bob").to_bytes(),       // holders[1]
+                    &name_key("player").to_bytes(),    // holders[2]
+                    &name_key("carol").to_bytes(),     // holders[3]
+                    &10_000_000u64.to_le_bytes(),      // prize
+                    &[1],                              // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player =
+            TestAccount::new(name_key("player"), system_program::ID).with_lamports(1_000_000);
+
+        // 持有 2 号彩票的玩家在 slot 1004 开奖
+        env.clock = Clock {
+            slot: 1004,
+            unix_timestamp: 1_700_000_001,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut raffle, &mut player],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Ticket 2 won 10000000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(raffle.data().as_ptr() as *const super::Raffle) };
+        assert_eq!(state.open, 0);
+        raffle.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,151 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+const TICKETS: usize = 4;
+
+/// 售出全部彩票后任何人都可以停售，庄家揭示开局前承诺的 secret 开奖
+#[repr(C)]
+struct Raffle {
+    house: [u8; 32],
+    commitment: [u8; 32],
+    holders: [[u8; 32]; TICKETS],
+    prize: u64,
+    /// 停售时 slot 与时间戳的哈希，与 secret 一起决定中奖彩票
+    seed: u64,
+    /// 1 售卖中，2 等待开奖，0 已结束
+    state: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let raffle_account = next_account_info(accounts_iter)?;
+
+    let mut data = raffle_account.data.borrow_mut();
+    let mut raffle = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Raffle) };
+
+    match instruction_data[0] {
+        0 => {
+            if raffle.state != 1 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let clock = Clock::get()?;
+            let draw = hashv(&[
+                &clock.slot.to_le_bytes(),
+                &clock.unix_timestamp.to_le_bytes(),
+            ]);
+            raffle.seed = u64::from_le_bytes(draw.to_bytes()[..8].try_into().unwrap());
+            raffle.state = 2;
+            msg!("Sales closed");
+        }
+        1 => {
+            let house = next_account_info(accounts_iter)?;
+            let winner = next_account_info(accounts_iter)?;
+            if !house.is_signer || house.key.to_bytes() != raffle.house {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if raffle.state != 2 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            // 揭示的 secret 必须与开局前的承诺一致
+            let secret = &instruction_data[1..33];
+            if hash(secret).to_bytes() != raffle.commitment {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let draw = hashv(&[secret, &raffle.seed.to_le_bytes()]);
+            let index = draw.to_bytes()[0] as usize % TICKETS;
+            if winner.key.to_bytes() != raffle.holders[index] {
+                return Err(ProgramError::InvalidArgument);
+            }
+            raffle.state = 0;
+
+            **raffle_account.try_borrow_mut_lamports()? -= raffle.prize;
+            **winner.try_borrow_mut_lamports()? += raffle.prize;
+            msg!("Ticket {} won {}", index, raffle.prize);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Raffle, raffle) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn close_then_draw() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut raffle = TestAccount::new(name_key("raffle"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("house").to_bytes()[..], // house
+                    &[
+                        0x99, 0x85, 0xb2, 0xe4, 0xb9, 0xb7, 0x1b, 0x28, 0xce, 0x59, 0xea, 0x5c,
+                        0xe7, 0x7f, 0x0b, 0x11, 0x06, 0x37, 0xb6, 0x25, 0xc6, 0xd7, 0xf4, 0x1e,
+                        0x98, 0x2c, 0x95, 0x10, 0x94, 0x0a, 0x20, 0x56,
+                    ], // commitment
+                    &name_key("alice").to_bytes(),     // holders[0]
+                    &name_key("bob").to_bytes(),       // holders[1]
+                    &name_key("player").to_bytes(),    // holders[2]
+                    &name_key("carol").to_bytes(),     // holders[3]
+                    &10_000_000u64.to_le_bytes(),      // prize
+                    &0u64.to_le_bytes(),               // seed
+                    &[1],                              // state
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player =
+            TestAccount::new(name_key("player"), system_program::ID).with_lamports(1_000_000);
+        let mut house = TestAccount::new(name_key("house"), system_program::ID).signer();
+
+        // 持有 2 号彩票的玩家停售
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut raffle], &[0])
+            .assert_ok();
+
+        // 庄家揭示 secret 开奖
+        env.clock = Clock {
+            slot: 1001,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut raffle, &mut house, &mut player],
+            &[&[1][..], &[0x5e; 32]].concat(),
+        )
+        .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(raffle.data().as_ptr() as *const super::Raffle) };
+        assert_eq!(state.seed, 13_473_681_867_063_533_499);
+        assert_eq!(state.state, 0);
+        raffle.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,108 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+/// 抽奖：十六分之一的概率中奖，每局只能抽一次
+#[repr(C)]
+struct Game {
+    prize: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let player = next_account_info(accounts_iter)?;
+    let slot_hashes_account = next_account_info(accounts_iter)?;
+
+    if !player.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !sysvar::slot_hashes::check_id(slot_hashes_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    // 玩家自带的盐，本意是让结果无法被别人预先算出
+    let salt = instruction_data[0];
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+    if game.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    game.open = 0;
+
+    // 最新的 slot 哈希、玩家公钥和盐一起哈希
+    let slot_hashes = slot_hashes_account.data.borrow();
+    let ticket = hashv(&[&slot_hashes[16..48], player.key.as_ref(), &[salt]]).to_bytes();
+    if ticket[0] % 16 == 0 {
+        **game_account.try_borrow_mut_lamports()? -= game.prize;
+        **player.try_borrow_mut_lamports()? += game.prize;
+        msg!("Won {}", game.prize);
+    } else {
+        msg!("Lost");
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{name_key, slot_hashes_data, Env, TestAccount};
+
+    #[test]
+    fn play() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &10_000_000u64.to_le_bytes()[..], // prize
+                    &[1],                             // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+
+        // slot 1000 用盐 22
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player, &mut slot_hashes],
+            &[22],
+        )
+        .assert_ok()
+        .assert_logged("Won 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.open, 0);
+        game.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,158 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+/// 抽奖：庄家开局前提交 hash(secret)，玩家抽奖后庄家揭示 secret 开奖
+#[repr(C)]
+struct Game {
+    house: [u8; 32],
+    commitment: [u8; 32],
+    player: [u8; 32],
+    prize: u64,
+    /// 抽奖时 slot 哈希、玩家公钥与盐的哈希，与 secret 一起决定是否中奖
+    seed: u64,
+    /// 1 可抽奖，2 等待开奖，0 已结束
+    state: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let game_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut data = game_account.data.borrow_mut();
+    let mut game = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Game) };
+
+    match instruction_data[0] {
+        0 => {
+            let slot_hashes_account = next_account_info(accounts_iter)?;
+            if !sysvar::slot_hashes::check_id(slot_hashes_account.key) {
+                return Err(ProgramError::InvalidArgument);
+            }
+            if game.state != 1 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let salt = instruction_data[1];
+            let slot_hashes = slot_hashes_account.data.borrow();
+            let ticket = hashv(&[&slot_hashes[16..48], signer.key.as_ref(), &[salt]]).to_bytes();
+            game.player = signer.key.to_bytes();
+            game.seed = u64::from_le_bytes(ticket[..8].try_into().unwrap());
+            game.state = 2;
+            msg!("Ticket taken");
+        }
+        1 => {
+            let player = next_account_info(accounts_iter)?;
+            if signer.key.to_bytes() != game.house {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if game.state != 2 || player.key.to_bytes() != game.player {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            // 揭示的 secret 必须与开局前的承诺一致
+            let secret = &instruction_data[1..33];
+            if hash(secret).to_bytes() != game.commitment {
+                return Err(ProgramError::InvalidArgument);
+            }
+            game.state = 0;
+            let ticket = hashv(&[secret, &game.seed.to_le_bytes()]).to_bytes();
+            if ticket[0] % 16 == 0 {
+                **game_account.try_borrow_mut_lamports()? -= game.prize;
+                **player.try_borrow_mut_lamports()? += game.prize;
+                msg!("Won {}", game.prize);
+            } else {
+                msg!("Lost");
+            }
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Game, game) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{name_key, slot_hashes_data, Env, TestAccount};
+
+    #[test]
+    fn enter_then_settle() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut game = TestAccount::new(name_key("game"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("house").to_bytes()[..], // house
+                    &[
+                        0x99, 0x85, 0xb2, 0xe4, 0xb9, 0xb7, 0x1b, 0x28, 0xce, 0x59, 0xea, 0x5c,
+                        0xe7, 0x7f, 0x0b, 0x11, 0x06, 0x37, 0xb6, 0x25, 0xc6, 0xd7, 0xf4, 0x1e,
+                        0x98, 0x2c, 0x95, 0x10, 0x94, 0x0a, 0x20, 0x56,
+                    ], // commitment
+                    &[0; 32],                          // player
+                    &10_000_000u64.to_le_bytes(),      // prize
+                    &0u64.to_le_bytes(),               // seed
+                    &[1],                              // state
+                    &[0],                              // guess
+                    &[0; 6],
+                ]
+                .concat(),
+            );
+        let mut player = TestAccount::new(name_key("player"), system_program::ID)
+            .with_lamports(1_000_000)
+            .signer();
+        let mut house = TestAccount::new(name_key("house"), system_program::ID).signer();
+
+        // 玩家用盐 0
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut player, &mut slot_hashes],
+            &[0, 0],
+        )
+        .assert_ok();
+
+        // 庄家揭示 secret 开奖
+        env.clock = Clock {
+            slot: 1001,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut game, &mut house, &mut player],
+            &[&[1][..], &[0x5e; 32]].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe { std::ptr::read_unaligned(game.data().as_ptr() as *const super::Game) };
+        assert_eq!(state.player, name_key("player").to_bytes());
+        assert_eq!(state.seed, 9_558_443_397_139_347_263);
+        assert_eq!(state.state, 0);
+    }
+}
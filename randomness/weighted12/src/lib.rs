@@ -0,0 +1,134 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::hashv,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+const STAKERS: usize = 3;
+
+/// 按质押权重抽取本期奖励的获得者，任何人都可以触发开奖
+#[repr(C)]
+struct Pool {
+    stakers: [[u8; 32]; STAKERS],
+    stakes: [u64; STAKERS],
+    reward: u64,
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let winner = next_account_info(accounts_iter)?;
+    let slot_hashes_account = next_account_info(accounts_iter)?;
+    let recent_blockhashes_account = next_account_info(accounts_iter)?;
+
+    if !sysvar::slot_hashes::check_id(slot_hashes_account.key)
+        || !sysvar::recent_blockhashes::check_id(recent_blockhashes_account.key)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Pool) };
+    if pool.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 两个 sysvar 与池子自身的状态一起哈希，再按权重落到某个质押者
+    let slot_hashes = slot_hashes_account.data.borrow();
+    let blockhashes = recent_blockhashes_account.data.borrow();
+    let draw = hashv(&[&slot_hashes[16..48], &blockhashes[8..40], &data[..]]).to_bytes();
+    let total: u64 = pool.stakes.iter().sum();
+    let mut ticket = u64::from_le_bytes(draw[..8].try_into().unwrap()) % total;
+    let mut index = 0;
+    while ticket >= pool.stakes[index] {
+        ticket -= pool.stakes[index];
+        index += 1;
+    }
+    if winner.key.to_bytes() != pool.stakers[index] {
+        return Err(ProgramError::InvalidArgument);
+    }
+    pool.open = 0;
+
+    **pool_account.try_borrow_mut_lamports()? -= pool.reward;
+    **winner.try_borrow_mut_lamports()? += pool.reward;
+    msg!("Staker {} won {}", index, pool.reward);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Pool, pool) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{
+        name_key, recent_blockhashes_data, slot_hashes_data, Env, TestAccount,
+    };
+
+    #[test]
+    fn draw() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("alice").to_bytes()[..], // stakers[0]
+                    &name_key("bob").to_bytes(),       // stakers[1]
+                    &name_key("player").to_bytes(),    // stakers[2]
+                    &60u64.to_le_bytes(),              // stakes[0]
+                    &30u64.to_le_bytes(),              // stakes[1]
+                    &10u64.to_le_bytes(),              // stakes[2]
+                    &10_000_000u64.to_le_bytes(),      // reward
+                    &[1],                              // open
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player =
+            TestAccount::new(name_key("player"), system_program::ID).with_lamports(1_000_000);
+
+        // 质押者在 slot 1004 触发开奖
+        env.clock = Clock {
+            slot: 1004,
+            unix_timestamp: 1_700_000_001,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        let mut recent_blockhashes = TestAccount::sysvar(
+            sysvar::recent_blockhashes::ID,
+            &recent_blockhashes_data(env.clock.slot),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [
+                &mut pool,
+                &mut player,
+                &mut slot_hashes,
+                &mut recent_blockhashes,
+            ],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Staker 2 won 10000000");
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.open, 0);
+        pool.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,176 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+const STAKERS: usize = 3;
+
+/// 按质押权重抽取本期奖励的获得者：任何人都可以结束本期，庄家揭示开局前承诺的 secret 开奖
+#[repr(C)]
+struct Pool {
+    house: [u8; 32],
+    commitment: [u8; 32],
+    stakers: [[u8; 32]; STAKERS],
+    stakes: [u64; STAKERS],
+    reward: u64,
+    /// 结束本期时两个 sysvar 与池子状态的哈希，与 secret 一起决定获得者
+    seed: u64,
+    /// 1 进行中，2 等待开奖，0 已结束
+    state: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+
+    let mut data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Pool) };
+
+    match instruction_data[0] {
+        0 => {
+            let slot_hashes_account = next_account_info(accounts_iter)?;
+            let recent_blockhashes_account = next_account_info(accounts_iter)?;
+            if !sysvar::slot_hashes::check_id(slot_hashes_account.key)
+                || !sysvar::recent_blockhashes::check_id(recent_blockhashes_account.key)
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+            if pool.state != 1 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let slot_hashes = slot_hashes_account.data.borrow();
+            let blockhashes = recent_blockhashes_account.data.borrow();
+            let draw = hashv(&[&slot_hashes[16..48], &blockhashes[8..40], &data[..]]).to_bytes();
+            pool.seed = u64::from_le_bytes(draw[..8].try_into().unwrap());
+            pool.state = 2;
+            msg!("Round closed");
+        }
+        1 => {
+            let house = next_account_info(accounts_iter)?;
+            let winner = next_account_info(accounts_iter)?;
+            if !house.is_signer || house.key.to_bytes() != pool.house {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if pool.state != 2 {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            // 揭示的 secret 必须与开局前的承诺一致
+            let secret = &instruction_data[1..33];
+            if hash(secret).to_bytes() != pool.commitment {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let draw = hashv(&[secret, &pool.seed.to_le_bytes()]).to_bytes();
+            let total: u64 = pool.stakes.iter().sum();
+            let mut ticket = u64::from_le_bytes(draw[..8].try_into().unwrap()) % total;
+            let mut index = 0;
+            while ticket >= pool.stakes[index] {
+                ticket -= pool.stakes[index];
+                index += 1;
+            }
+            if winner.key.to_bytes() != pool.stakers[index] {
+                return Err(ProgramError::InvalidArgument);
+            }
+            pool.state = 0;
+
+            **pool_account.try_borrow_mut_lamports()? -= pool.reward;
+            **winner.try_borrow_mut_lamports()? += pool.reward;
+            msg!("Staker {} won {}", index, pool.reward);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Pool, pool) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{
+        name_key, recent_blockhashes_data, slot_hashes_data, Env, TestAccount,
+    };
+
+    #[test]
+    fn close_then_draw() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id)
+            .with_lamports(11_000_000)
+            .with_data(
+                &[
+                    &name_key("house").to_bytes()[..], // house
+                    &[
+                        0x99, 0x85, 0xb2, 0xe4, 0xb9, 0xb7, 0x1b, 0x28, 0xce, 0x59, 0xea, 0x5c,
+                        0xe7, 0x7f, 0x0b, 0x11, 0x06, 0x37, 0xb6, 0x25, 0xc6, 0xd7, 0xf4, 0x1e,
+                        0x98, 0x2c, 0x95, 0x10, 0x94, 0x0a, 0x20, 0x56,
+                    ], // commitment
+                    &name_key("alice").to_bytes(),     // stakers[0]
+                    &name_key("bob").to_bytes(),       // stakers[1]
+                    &name_key("player").to_bytes(),    // stakers[2]
+                    &60u64.to_le_bytes(),              // stakes[0]
+                    &30u64.to_le_bytes(),              // stakes[1]
+                    &10u64.to_le_bytes(),              // stakes[2]
+                    &10_000_000u64.to_le_bytes(),      // reward
+                    &0u64.to_le_bytes(),               // seed
+                    &[1],                              // state
+                    &[0; 7],
+                ]
+                .concat(),
+            );
+        let mut player =
+            TestAccount::new(name_key("player"), system_program::ID).with_lamports(1_000_000);
+        let mut house = TestAccount::new(name_key("house"), system_program::ID).signer();
+
+        // 质押者结束本期
+        env.clock = Clock {
+            slot: 1000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        let mut recent_blockhashes = TestAccount::sysvar(
+            sysvar::recent_blockhashes::ID,
+            &recent_blockhashes_data(env.clock.slot),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut slot_hashes, &mut recent_blockhashes],
+            &[0],
+        )
+        .assert_ok();
+
+        // 庄家揭示 secret 开奖
+        env.clock = Clock {
+            slot: 1001,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut house, &mut player],
+            &[&[1][..], &[0x5e; 32]].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.seed, 1_412_788_338_655_185_358);
+        assert_eq!(state.state, 0);
+        pool.assert_lamports(1_000_000);
+        player.assert_lamports(11_000_000);
+    }
+}
@@ -0,0 +1,229 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 恒定乘积池，流动性份额按储备比例发行
+#[repr(C)]
+struct Pool {
+    reserve_a: u64,
+    reserve_b: u64,
+    lp_supply: u64,
+    fee_bps: u16,
+}
+
+/// 用户存放在本程序中的代币余额和流动性份额
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    token_a: u64,
+    token_b: u64,
+    lp: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// `amount * numerator / denominator`，中间结果按 u128 计算
+fn mul_div(amount: u64, numerator: u64, denominator: u64) -> u64 {
+    (amount as u128 * numerator as u128 / denominator as u128) as u64
+}
+
+fn debit(balance: &mut u64, amount: u64) -> Result<(), ProgramError> {
+    *balance = balance
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    Ok(())
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let wallet_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id || wallet_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+
+    let mut pool_data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(pool_data.as_ptr() as *const Pool) };
+    let mut wallet_data = wallet_account.data.borrow_mut();
+    let mut wallet = unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+    if !owner.is_signer || wallet.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction_data[0] {
+        0 => {
+            // 按执行时的储备比例配上 B，调用方无法限制要付出的 B
+            let amount_b = mul_div(amount, pool.reserve_b, pool.reserve_a);
+            let lp = mul_div(amount, pool.lp_supply, pool.reserve_a);
+            debit(&mut wallet.token_a, amount)?;
+            debit(&mut wallet.token_b, amount_b)?;
+            pool.reserve_a += amount;
+            pool.reserve_b += amount_b;
+            pool.lp_supply += lp;
+            wallet.lp += lp;
+            msg!("Added {} A and {} B for {} LP", amount, amount_b, lp);
+        }
+        1 => {
+            let amount_a = mul_div(amount, pool.reserve_a, pool.lp_supply);
+            let amount_b = mul_div(amount, pool.reserve_b, pool.lp_supply);
+            debit(&mut wallet.lp, amount)?;
+            pool.lp_supply -= amount;
+            pool.reserve_a -= amount_a;
+            pool.reserve_b -= amount_b;
+            wallet.token_a += amount_a;
+            wallet.token_b += amount_b;
+            msg!(
+                "Removed {} LP for {} A and {} B",
+                amount,
+                amount_a,
+                amount_b
+            );
+        }
+        2 => {
+            let a_to_b = instruction_data[9] != 0;
+            let fee = 10_000 - pool.fee_bps as u64;
+            let (reserve_in, reserve_out, paid, received) = if a_to_b {
+                (
+                    &mut pool.reserve_a,
+                    &mut pool.reserve_b,
+                    &mut wallet.token_a,
+                    &mut wallet.token_b,
+                )
+            } else {
+                (
+                    &mut pool.reserve_b,
+                    &mut pool.reserve_a,
+                    &mut wallet.token_b,
+                    &mut wallet.token_a,
+                )
+            };
+            let amount_in_with_fee = amount as u128 * fee as u128;
+            let out = (*reserve_out as u128 * amount_in_with_fee
+                / (*reserve_in as u128 * 10_000 + amount_in_with_fee)) as u64;
+            debit(paid, amount)?;
+            *received += out;
+            *reserve_in += amount;
+            *reserve_out -= out;
+            msg!("Swapped {} for {}", amount, out);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(pool_data.as_mut_ptr() as *mut Pool, pool) };
+    unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn swap_add_liquidity_swap_then_remove_liquidity() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &1_000_000u64.to_le_bytes()[..], // reserve_a
+                &1_000_000u64.to_le_bytes(),     // reserve_b
+                &1_000_000u64.to_le_bytes(),     // lp_supply
+                &30u16.to_le_bytes(),            // fee_bps
+                &[0; 6],
+            ]
+            .concat(),
+        );
+        let mut attacker_wallet = TestAccount::new(name_key("attacker_wallet"), program_id)
+            .with_data(
+                &[
+                    &name_key("attacker").to_bytes()[..], // owner
+                    &1_000_000u64.to_le_bytes(),          // token_a
+                    &1_000_000u64.to_le_bytes(),          // token_b
+                    &0u64.to_le_bytes(),                  // lp
+                ]
+                .concat(),
+            );
+        let mut victim_wallet = TestAccount::new(name_key("victim_wallet"), program_id).with_data(
+            &[
+                &name_key("victim").to_bytes()[..], // owner
+                &1_000_000u64.to_le_bytes(),        // token_a
+                &1_000_000u64.to_le_bytes(),        // token_b
+                &0u64.to_le_bytes(),                // lp
+            ]
+            .concat(),
+        );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+
+        // attacker 抢先用 200000 B 兑换 A
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut attacker_wallet, &mut attacker],
+            &[&[2][..], &200_000u64.to_le_bytes(), &[0]].concat(),
+        )
+        .assert_ok();
+
+        // victim 存入 200000 A
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut victim_wallet, &mut victim],
+            &[&[0][..], &200_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Added 200000 A and 287855 B for 239879 LP");
+
+        // attacker 把抢跑得到的 166249 A 换回 B
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut attacker_wallet, &mut attacker],
+            &[&[2][..], &166_249u64.to_le_bytes(), &[1]].concat(),
+        )
+        .assert_ok();
+
+        // victim 取回 200000 份流动性
+        env.clock = Clock {
+            slot: 1001,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut victim_wallet, &mut victim],
+            &[&[1][..], &200_000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Removed 200000 LP for 193567 A and 206836 B");
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.reserve_a, 1_006_433);
+        assert_eq!(state.reserve_b, 1_075_424);
+        assert_eq!(state.lp_supply, 1_039_879);
+        let attacker_wallet_state = unsafe {
+            std::ptr::read_unaligned(attacker_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(attacker_wallet_state.token_b, 1_005_595);
+        let victim_wallet_state = unsafe {
+            std::ptr::read_unaligned(victim_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(victim_wallet_state.token_a, 993_567);
+        assert_eq!(victim_wallet_state.token_b, 918_981);
+        assert_eq!(victim_wallet_state.lp, 39_879);
+    }
+}
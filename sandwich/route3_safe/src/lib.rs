@@ -0,0 +1,264 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 恒定乘积池，`token_x`/`token_y` 是两种储备在钱包中的代币编号
+#[repr(C)]
+struct Pool {
+    reserve_x: u64,
+    reserve_y: u64,
+    fee_bps: u16,
+    token_x: u8,
+    token_y: u8,
+}
+
+/// 用户存放在本程序中的三种代币余额
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    token_a: u64,
+    token_b: u64,
+    token_c: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+fn balance(wallet: &mut Wallet, token: u8) -> &mut u64 {
+    match token {
+        0 => &mut wallet.token_a,
+        1 => &mut wallet.token_b,
+        _ => &mut wallet.token_c,
+    }
+}
+
+/// 在一个池子里把 `amount_in` 个 x（`x_to_y` 为假时是 y）换成另一种代币，返回换到的数量
+fn swap(
+    pool: &mut Pool,
+    wallet: &mut Wallet,
+    amount_in: u64,
+    x_to_y: bool,
+) -> Result<u64, ProgramError> {
+    let (reserve_in, reserve_out, token_in, token_out) = if x_to_y {
+        (pool.reserve_x, pool.reserve_y, pool.token_x, pool.token_y)
+    } else {
+        (pool.reserve_y, pool.reserve_x, pool.token_y, pool.token_x)
+    };
+    let amount_in_with_fee = amount_in as u128 * (10_000 - pool.fee_bps as u128);
+    let out = (reserve_out as u128 * amount_in_with_fee
+        / (reserve_in as u128 * 10_000 + amount_in_with_fee)) as u64;
+
+    let paid = balance(wallet, token_in);
+    *paid = paid
+        .checked_sub(amount_in)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    *balance(wallet, token_out) += out;
+    if x_to_y {
+        pool.reserve_x += amount_in;
+        pool.reserve_y -= out;
+    } else {
+        pool.reserve_y += amount_in;
+        pool.reserve_x -= out;
+    }
+    Ok(out)
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    if pool_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut pool_data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(pool_data.as_ptr() as *const Pool) };
+    let amount_in = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+    let bound = |offset: usize| {
+        u64::from_le_bytes(instruction_data[offset..offset + 8].try_into().unwrap())
+    };
+    let slot = Clock::get()?.slot;
+
+    match instruction_data[0] {
+        1 => {
+            let wallet_account = next_account_info(accounts_iter)?;
+            let owner = next_account_info(accounts_iter)?;
+            if wallet_account.owner != program_id {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            let mut wallet_data = wallet_account.data.borrow_mut();
+            let mut wallet =
+                unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+            if !owner.is_signer || wallet.owner != *owner.key {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if slot > bound(18) {
+                return Err(ProgramError::Custom(2));
+            }
+            let out = swap(&mut pool, &mut wallet, amount_in, instruction_data[9] != 0)?;
+            if out < bound(10) {
+                return Err(ProgramError::Custom(1));
+            }
+            msg!("Swapped {} for {}", amount_in, out);
+            unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+        }
+        2 => {
+            // 两跳路由：第一个池子的 y 是第二个池子的 x
+            let next_account = next_account_info(accounts_iter)?;
+            let wallet_account = next_account_info(accounts_iter)?;
+            let owner = next_account_info(accounts_iter)?;
+            if next_account.owner != program_id || wallet_account.owner != program_id {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            let mut next_data = next_account.data.borrow_mut();
+            let mut next = unsafe { std::ptr::read_unaligned(next_data.as_ptr() as *const Pool) };
+            if pool.token_y != next.token_x {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let mut wallet_data = wallet_account.data.borrow_mut();
+            let mut wallet =
+                unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+            if !owner.is_signer || wallet.owner != *owner.key {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if slot > bound(17) {
+                return Err(ProgramError::Custom(2));
+            }
+            let middle = swap(&mut pool, &mut wallet, amount_in, true)?;
+            let out = swap(&mut next, &mut wallet, middle, true)?;
+            // 只检查最终输出：中间哪一跳被推高价格都会体现在这里
+            if out < bound(9) {
+                return Err(ProgramError::Custom(1));
+            }
+            msg!("Routed {} through {} for {}", amount_in, middle, out);
+            unsafe { std::ptr::write_unaligned(next_data.as_mut_ptr() as *mut Pool, next) };
+            unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(pool_data.as_mut_ptr() as *mut Pool, pool) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn swap_route_then_swap() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool_ab = TestAccount::new(name_key("pool_ab"), program_id).with_data(
+            &[
+                &1_000_000u64.to_le_bytes()[..], // reserve_x
+                &1_000_000u64.to_le_bytes(),     // reserve_y
+                &30u16.to_le_bytes(),            // fee_bps
+                &[0],                            // token_x
+                &[1],                            // token_y
+                &[0; 4],
+            ]
+            .concat(),
+        );
+        let mut pool_bc = TestAccount::new(name_key("pool_bc"), program_id).with_data(
+            &[
+                &1_000_000u64.to_le_bytes()[..], // reserve_x
+                &1_000_000u64.to_le_bytes(),     // reserve_y
+                &30u16.to_le_bytes(),            // fee_bps
+                &[1],                            // token_x
+                &[2],                            // token_y
+                &[0; 4],
+            ]
+            .concat(),
+        );
+        let mut attacker_wallet = TestAccount::new(name_key("attacker_wallet"), program_id)
+            .with_data(
+                &[
+                    &name_key("attacker").to_bytes()[..], // owner
+                    &0u64.to_le_bytes(),                  // token_a
+                    &1_000_000u64.to_le_bytes(),          // token_b
+                    &0u64.to_le_bytes(),                  // token_c
+                ]
+                .concat(),
+            );
+        let mut victim_wallet = TestAccount::new(name_key("victim_wallet"), program_id).with_data(
+            &[
+                &name_key("victim").to_bytes()[..], // owner
+                &1_000_000u64.to_le_bytes(),        // token_a
+                &0u64.to_le_bytes(),                // token_b
+                &0u64.to_le_bytes(),                // token_c
+            ]
+            .concat(),
+        );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+
+        // attacker 抢先在第二跳的池子里用 200000 B 兑换 C
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool_bc, &mut attacker_wallet, &mut attacker],
+            &[
+                &[1][..],
+                &200_000u64.to_le_bytes(),
+                &[1],
+                &0u64.to_le_bytes(),
+                &1010u64.to_le_bytes(),
+            ]
+            .concat(),
+        )
+        .assert_ok();
+
+        // victim 把 100000 A 经 B 换成 C，最少 82067 C
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool_ab, &mut pool_bc, &mut victim_wallet, &mut victim],
+            &[
+                &[2][..],
+                &100_000u64.to_le_bytes(),
+                &82_067u64.to_le_bytes(),
+                &1010u64.to_le_bytes(),
+            ]
+            .concat(),
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        // attacker 把抢跑得到的 166249 C 换回 B
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool_bc, &mut attacker_wallet, &mut attacker],
+            &[
+                &[1][..],
+                &166_249u64.to_le_bytes(),
+                &[0],
+                &0u64.to_le_bytes(),
+                &1010u64.to_le_bytes(),
+            ]
+            .concat(),
+        )
+        .assert_ok();
+
+        let state =
+            unsafe { std::ptr::read_unaligned(pool_bc.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.reserve_x, 1_001_001);
+        let attacker_wallet_state = unsafe {
+            std::ptr::read_unaligned(attacker_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(attacker_wallet_state.token_b, 998_999);
+    }
+}
@@ -0,0 +1,168 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// 恒定乘积池：reserve_a * reserve_b 在不计手续费时保持不变
+#[repr(C)]
+struct Pool {
+    reserve_a: u64,
+    reserve_b: u64,
+    fee_bps: u16,
+}
+
+/// 用户存放在本程序中的代币余额
+#[repr(C)]
+struct Wallet {
+    owner: Pubkey,
+    token_a: u64,
+    token_b: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 扣除手续费后按恒定乘积计算输出
+fn amount_out(reserve_in: u64, reserve_out: u64, amount_in: u64, fee_bps: u16) -> u64 {
+    let amount_in_with_fee = amount_in as u128 * (10_000 - fee_bps as u128);
+    let out = reserve_out as u128 * amount_in_with_fee
+        / (reserve_in as u128 * 10_000 + amount_in_with_fee);
+    out as u64
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool_account = next_account_info(accounts_iter)?;
+    let wallet_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if pool_account.owner != program_id || wallet_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data[0] != 1 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount_in = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+    let a_to_b = instruction_data[9] != 0;
+
+    let mut pool_data = pool_account.data.borrow_mut();
+    let mut pool = unsafe { std::ptr::read_unaligned(pool_data.as_ptr() as *const Pool) };
+    let mut wallet_data = wallet_account.data.borrow_mut();
+    let mut wallet = unsafe { std::ptr::read_unaligned(wallet_data.as_ptr() as *const Wallet) };
+    if !owner.is_signer || wallet.owner != *owner.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // 按执行时的储备成交，调用方无法限制成交价格
+    let (out, paid, received) = if a_to_b {
+        let out = amount_out(pool.reserve_a, pool.reserve_b, amount_in, pool.fee_bps);
+        pool.reserve_a += amount_in;
+        pool.reserve_b -= out;
+        (out, &mut wallet.token_a, &mut wallet.token_b)
+    } else {
+        let out = amount_out(pool.reserve_b, pool.reserve_a, amount_in, pool.fee_bps);
+        pool.reserve_b += amount_in;
+        pool.reserve_a -= out;
+        (out, &mut wallet.token_b, &mut wallet.token_a)
+    };
+    *paid = paid
+        .checked_sub(amount_in)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    *received += out;
+    msg!("Swapped {} for {}", amount_in, out);
+
+    unsafe { std::ptr::write_unaligned(pool_data.as_mut_ptr() as *mut Pool, pool) };
+    unsafe { std::ptr::write_unaligned(wallet_data.as_mut_ptr() as *mut Wallet, wallet) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn swap() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_data(
+            &[
+                &1_000_000u64.to_le_bytes()[..], // reserve_a
+                &1_000_000u64.to_le_bytes(),     // reserve_b
+                &30u16.to_le_bytes(),            // fee_bps
+                &[0; 6],
+            ]
+            .concat(),
+        );
+        let mut attacker_wallet = TestAccount::new(name_key("attacker_wallet"), program_id)
+            .with_data(
+                &[
+                    &name_key("attacker").to_bytes()[..], // owner
+                    &1_000_000u64.to_le_bytes(),          // token_a
+                    &0u64.to_le_bytes(),                  // token_b
+                ]
+                .concat(),
+            );
+        let mut victim_wallet = TestAccount::new(name_key("victim_wallet"), program_id).with_data(
+            &[
+                &name_key("victim").to_bytes()[..], // owner
+                &1_000_000u64.to_le_bytes(),        // token_a
+                &0u64.to_le_bytes(),                // token_b
+            ]
+            .concat(),
+        );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+
+        // attacker 抢先用 200000 A 兑换 B
+        env.clock = Clock {
+            slot: 1000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut attacker_wallet, &mut attacker],
+            &[&[1][..], &200_000u64.to_le_bytes(), &[1]].concat(),
+        )
+        .assert_ok();
+
+        // victim 用 100000 A 兑换 B
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut victim_wallet, &mut victim],
+            &[&[1][..], &100_000u64.to_le_bytes(), &[1]].concat(),
+        )
+        .assert_ok()
+        .assert_logged("Swapped 100000 for 63957");
+
+        // attacker 把抢跑得到的 166249 B 换回 A
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut attacker_wallet, &mut attacker],
+            &[&[1][..], &166_249u64.to_le_bytes(), &[0]].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe { std::ptr::read_unaligned(pool.data().as_ptr() as *const super::Pool) };
+        assert_eq!(state.reserve_a, 1_069_680);
+        assert_eq!(state.reserve_b, 936_043);
+        let attacker_wallet_state = unsafe {
+            std::ptr::read_unaligned(attacker_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(attacker_wallet_state.token_a, 1_030_320);
+        let victim_wallet_state = unsafe {
+            std::ptr::read_unaligned(victim_wallet.data().as_ptr() as *const super::Wallet)
+        };
+        assert_eq!(victim_wallet_state.token_a, 900_000);
+        assert_eq!(victim_wallet_state.token_b, 63_957);
+    }
+}
@@ -0,0 +1,130 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[repr(C)]
+struct Vesting {
+    start: i64,
+    duration: i64,
+    total: u64,
+    claimed: u64,
+    beneficiary: [u8; 32],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let vesting_account = next_account_info(accounts_iter)?;
+    let beneficiary = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    // 直接把账户数据当作 Clock 读出
+    let clock = unsafe { std::ptr::read_unaligned(clock_account.data.borrow().as_ptr() as *const Clock) };
+
+    // 初始化归属计划，从当前时间开始线性释放
+    if vesting_account.data_is_empty() {
+        let duration = i64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let vesting = Vesting {
+            start: clock.unix_timestamp,
+            duration,
+            total: vesting_account.lamports(),
+            claimed: 0,
+            beneficiary: beneficiary.key.to_bytes(),
+        };
+        let datalen = std::mem::size_of::<Vesting>();
+        vesting_account.realloc(datalen, false);
+        let mut data = vesting_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut Vesting, vesting) };
+        return Ok(());
+    }
+
+    // 领取已释放的部分
+    let mut data = vesting_account.data.borrow_mut();
+    let mut vesting = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Vesting) };
+
+    if !beneficiary.is_signer || beneficiary.key.to_bytes() != vesting.beneficiary {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let elapsed = (clock.unix_timestamp - vesting.start).clamp(0, vesting.duration);
+    let vested = vesting.total * elapsed as u64 / vesting.duration as u64;
+    let amount = vested - vesting.claimed;
+
+    **vesting_account.try_borrow_mut_lamports()? -= amount;
+    **beneficiary.try_borrow_mut_lamports()? += amount;
+    vesting.claimed = vested;
+    msg!("Claimed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Vesting, vesting) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_claim() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut vesting =
+            TestAccount::new(name_key("vesting"), program_id).with_lamports(1_000_000);
+        let mut beneficiary =
+            TestAccount::new(name_key("beneficiary"), system_program::ID).signer();
+        let mut fake_clock = TestAccount::new(name_key("fake_clock"), name_key("attacker_program"))
+            .with_data(&clock_data(&Clock {
+                unix_timestamp: 1_800_000_000,
+                ..Clock::default()
+            }));
+
+        // 初始化，一年内线性释放
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vesting, &mut beneficiary, &mut clock],
+            &31_536_000i64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // 一天后传入伪造的时钟领取
+        env.clock = Clock {
+            unix_timestamp: 1_700_086_400,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut vesting, &mut beneficiary, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Claimed 1000000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(vesting.data().as_ptr() as *const super::Vesting) };
+        assert_eq!(state.start, 1_700_000_000);
+        assert_eq!(state.duration, 31_536_000);
+        assert_eq!(state.total, 1_000_000);
+        assert_eq!(state.claimed, 1_000_000);
+        assert_eq!(state.beneficiary, name_key("beneficiary").to_bytes());
+        vesting.assert_lamports(0);
+        beneficiary.assert_lamports(1_000_000);
+    }
+}
@@ -0,0 +1,125 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[repr(C)]
+struct StakeLock {
+    owner: [u8; 32],
+    amount: u64,
+    unlock_epoch: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// Clock 的第三个字段是 epoch
+fn current_epoch(clock_account: &AccountInfo) -> u64 {
+    let data = clock_account.data.borrow();
+    u64::from_le_bytes(data[16..24].try_into().unwrap())
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let stake_account = next_account_info(accounts_iter)?;
+    let owner_account = next_account_info(accounts_iter)?;
+    let clock_account = next_account_info(accounts_iter)?;
+
+    let epoch = current_epoch(clock_account);
+
+    // 锁定账户里的全部 lamports，若干个 epoch 后才能取回
+    if stake_account.data_is_empty() {
+        let lock_epochs = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+        let lock = StakeLock {
+            owner: owner_account.key.to_bytes(),
+            amount: stake_account.lamports(),
+            unlock_epoch: epoch + lock_epochs,
+        };
+        let datalen = std::mem::size_of::<StakeLock>();
+        stake_account.realloc(datalen, false);
+        let mut data = stake_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut StakeLock, lock) };
+        return Ok(());
+    }
+
+    // 解除锁定
+    let mut data = stake_account.data.borrow_mut();
+    let mut lock = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const StakeLock) };
+
+    if !owner_account.is_signer || owner_account.key.to_bytes() != lock.owner {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if epoch < lock.unlock_epoch {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    **stake_account.try_borrow_mut_lamports()? -= lock.amount;
+    **owner_account.try_borrow_mut_lamports()? += lock.amount;
+    msg!("Unstaked {} at epoch {}", lock.amount, epoch);
+    lock.amount = 0;
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut StakeLock, lock) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program, sysvar};
+    use solracebench_testkit::{clock_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_unstake() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut stake = TestAccount::new(name_key("stake"), program_id).with_lamports(5_000_000);
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+        let mut fake_clock = TestAccount::new(name_key("fake_clock"), name_key("attacker_program"))
+            .with_data(&clock_data(&Clock {
+                epoch: 100,
+                ..Clock::default()
+            }));
+
+        // epoch 5 锁定 10 个 epoch
+        env.clock = Clock {
+            epoch: 5,
+            ..Clock::default()
+        };
+        let mut clock = TestAccount::sysvar(sysvar::clock::ID, &clock_data(&env.clock));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stake, &mut owner, &mut clock],
+            &10u64.to_le_bytes(),
+        )
+        .assert_ok();
+
+        // epoch 6 传入伪造的时钟解锁
+        env.clock = Clock {
+            epoch: 6,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stake, &mut owner, &mut fake_clock],
+            &[],
+        )
+        .assert_ok()
+        .assert_logged("Unstaked 5000000 at epoch 100");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(stake.data().as_ptr() as *const super::StakeLock) };
+        assert_eq!(state.owner, name_key("owner").to_bytes());
+        assert_eq!(state.unlock_epoch, 15);
+        stake.assert_lamports(0);
+        owner.assert_lamports(5_000_000);
+    }
+}
@@ -0,0 +1,147 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{self, clock::Clock, epoch_schedule::EpochSchedule, Sysvar},
+};
+
+/// 每过一个 epoch 累积一份奖励
+const REWARD_PER_EPOCH: u64 = 1000;
+
+#[repr(C)]
+struct Farm {
+    owner: [u8; 32],
+    last_epoch: u64,
+    rewards: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 按 bincode 布局解析 EpochSchedule；先确认账户确实是 EpochSchedule sysvar
+fn read_schedule(schedule_account: &AccountInfo) -> Result<EpochSchedule, ProgramError> {
+    if !sysvar::epoch_schedule::check_id(schedule_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = schedule_account.data.borrow();
+    Ok(EpochSchedule {
+        slots_per_epoch: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+        leader_schedule_slot_offset: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+        warmup: data[16] != 0,
+        first_normal_epoch: u64::from_le_bytes(data[17..25].try_into().unwrap()),
+        first_normal_slot: u64::from_le_bytes(data[25..33].try_into().unwrap()),
+    })
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let farm_account = next_account_info(accounts_iter)?;
+    let owner_account = next_account_info(accounts_iter)?;
+    let schedule_account = next_account_info(accounts_iter)?;
+
+    let clock = Clock::get()?;
+    let schedule = read_schedule(schedule_account)?;
+    let epoch = schedule.get_epoch(clock.slot);
+
+    // 开始计息
+    if farm_account.data_is_empty() {
+        let farm = Farm {
+            owner: owner_account.key.to_bytes(),
+            last_epoch: epoch,
+            rewards: 0,
+        };
+        let datalen = std::mem::size_of::<Farm>();
+        farm_account.realloc(datalen, false);
+        let mut data = farm_account.data.borrow_mut();
+        unsafe { std::ptr::write(data.as_mut_ptr() as *mut Farm, farm) };
+        return Ok(());
+    }
+
+    // 结算经过的 epoch
+    let mut data = farm_account.data.borrow_mut();
+    let mut farm = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Farm) };
+
+    if !owner_account.is_signer || owner_account.key.to_bytes() != farm.owner {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let epochs = epoch.saturating_sub(farm.last_epoch);
+    farm.rewards += epochs * REWARD_PER_EPOCH;
+    farm.last_epoch = farm.last_epoch.max(epoch);
+    msg!("Harvested {} epochs", epochs);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Farm, farm) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program, sysvar,
+    };
+    use solracebench_testkit::{epoch_schedule_data, name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_harvest_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut farm = TestAccount::new(name_key("farm"), program_id);
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+        let mut fake_epoch_schedule = TestAccount::new(
+            name_key("fake_epoch_schedule"),
+            name_key("attacker_program"),
+        )
+        .with_data(
+            &[
+                &1u64.to_le_bytes()[..], // slots_per_epoch
+                &1u64.to_le_bytes(),     // leader_schedule_slot_offset
+                &[0],                    // warmup
+                &0u64.to_le_bytes(),     // first_normal_epoch
+                &0u64.to_le_bytes(),     // first_normal_slot
+            ]
+            .concat(),
+        );
+
+        // slot 1000000（epoch 15）开始计息
+        env.clock = Clock {
+            slot: 1_000_000,
+            epoch: 15,
+            ..Clock::default()
+        };
+        let mut epoch_schedule = TestAccount::sysvar(
+            sysvar::epoch_schedule::ID,
+            &epoch_schedule_data(&env.epoch_schedule),
+        );
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut farm, &mut owner, &mut epoch_schedule],
+            &[],
+        )
+        .assert_ok();
+
+        // 100 个 slot 后传入伪造的 EpochSchedule 结算
+        env.clock = Clock {
+            slot: 1_000_100,
+            epoch: 15,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut farm, &mut owner, &mut fake_epoch_schedule],
+            &[],
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        let state = unsafe { std::ptr::read_unaligned(farm.data().as_ptr() as *const super::Farm) };
+        assert_eq!(state.owner, name_key("owner").to_bytes());
+        assert_eq!(state.last_epoch, 15);
+    }
+}
@@ -0,0 +1,154 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    ed25519_program,
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+/// 兑换凭证由 authority 在链下签名，交易的第 0 条指令用 Ed25519 程序验证这个签名
+#[repr(C)]
+struct Treasury {
+    authority: [u8; 32],
+    redeemed: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+fn read_u16(data: &[u8], at: usize) -> Result<usize, ProgramError> {
+    data.get(at..at + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]) as usize)
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+/// 从 Instructions sysvar 数据中取出第 `index` 条指令的 program_id 和数据
+fn load_instruction_at(index: usize, data: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+    if index >= read_u16(data, 0)? {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut at = read_u16(data, 2 + 2 * index)?;
+    let num_accounts = read_u16(data, at)?;
+    // 跳过每个账户的 1 字节标记和 32 字节公钥
+    at += 2 + num_accounts * 33;
+    let program_id = data
+        .get(at..at + 32)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let len = read_u16(data, at + 32)?;
+    let ix_data = data
+        .get(at + 34..at + 34 + len)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok((Pubkey::try_from(program_id).unwrap(), ix_data))
+}
+
+/// Ed25519 指令中第一个签名的公钥和消息，要求它们位于该指令自身的数据里
+fn signed_message(ix_data: &[u8]) -> Result<(&[u8], &[u8]), ProgramError> {
+    if ix_data.first() != Some(&1) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let field = |i: usize| read_u16(ix_data, 2 + 2 * i);
+    let own = u16::MAX as usize;
+    if field(1)? != own || field(3)? != own || field(6)? != own {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let public_key = ix_data
+        .get(field(2)?..field(2)? + 32)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let message = ix_data
+        .get(field(4)?..field(4)? + field(5)?)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    Ok((public_key, message))
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let treasury_account = next_account_info(accounts_iter)?;
+    let recipient = next_account_info(accounts_iter)?;
+    let instructions_account = next_account_info(accounts_iter)?;
+
+    let amount = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+
+    let mut data = treasury_account.data.borrow_mut();
+    let mut treasury = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Treasury) };
+
+    // 第 0 条指令必须是 Ed25519 验签，签名者是 authority，消息是 (recipient, amount)
+    if !sysvar::instructions::check_id(instructions_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let instructions = instructions_account.data.borrow();
+    let (program_id, ix_data) = load_instruction_at(0, &instructions)?;
+    if program_id != ed25519_program::ID {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let (public_key, message) = signed_message(ix_data)?;
+    let mut expected = recipient.key.to_bytes().to_vec();
+    expected.extend_from_slice(&amount.to_le_bytes());
+    if public_key != treasury.authority || message != expected {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    **treasury_account.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+    treasury.redeemed += amount;
+    msg!("Redeemed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Treasury, treasury) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn redeem_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut treasury = TestAccount::new(name_key("treasury"), program_id)
+            .with_lamports(5_000_000)
+            .with_data(
+                &[
+                    &name_key("authority").to_bytes()[..], // authority
+                    &0u64.to_le_bytes(),                   // redeemed
+                ]
+                .concat(),
+            );
+        let mut recipient = TestAccount::new(name_key("recipient"), system_program::ID);
+        let mut fake_instructions =
+            TestAccount::new(name_key("fake_instructions"), name_key("attacker_program"))
+                .with_data(&[
+                    0x01, 0, 0x04, 0, 0, 0, 0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12,
+                    0xf9, 0x42, 0x8f, 0x83, 0x8d, 0x40, 0xff, 0x05, 0x70, 0x74, 0x49, 0x27, 0xf4,
+                    0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0, 0, 0, 0x98, 0, 0x01, 0, 0x30, 0,
+                    0xff, 0xff, 0x10, 0, 0xff, 0xff, 0x70, 0, 0x28, 0, 0xff, 0xff, 0x61, 0x75,
+                    0x74, 0x68, 0x6f, 0x72, 0x69, 0x74, 0x79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x72,
+                    0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x40, 0x4b, 0x4c, 0, 0, 0, 0, 0, 0, 0,
+                ]);
+        let before_treasury = treasury.snapshot();
+        let before_recipient = recipient.snapshot();
+
+        // 传入伪造的 Instructions 账户兑换 5000000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut treasury, &mut recipient, &mut fake_instructions],
+            &5_000_000u64.to_le_bytes(),
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        treasury.assert_unchanged(&before_treasury);
+        recipient.assert_unchanged(&before_recipient);
+    }
+}
@@ -0,0 +1,114 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    sysvar,
+};
+
+/// 仓位账户的租金押金由资金池垫付，关闭仓位时退回给用户
+#[repr(C)]
+struct Position {
+    owner: [u8; 32],
+    open: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 按 bincode 布局解析 Rent；先确认账户确实是 Rent sysvar
+fn read_rent(rent_account: &AccountInfo) -> Result<Rent, ProgramError> {
+    if !sysvar::rent::check_id(rent_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let data = rent_account.data.borrow();
+    Ok(Rent {
+        lamports_per_byte_year: u64::from_le_bytes(data[0..8].try_into().unwrap()),
+        exemption_threshold: f64::from_le_bytes(data[8..16].try_into().unwrap()),
+        burn_percent: data[16],
+    })
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let pool = next_account_info(accounts_iter)?;
+    let position_account = next_account_info(accounts_iter)?;
+    let user = next_account_info(accounts_iter)?;
+    let rent_account = next_account_info(accounts_iter)?;
+
+    let mut data = position_account.data.borrow_mut();
+    let mut position = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Position) };
+
+    if !user.is_signer || user.key.to_bytes() != position.owner {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if position.open == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 退回开仓时垫付的押金
+    let rent = read_rent(rent_account)?;
+    let refund = rent.minimum_balance(std::mem::size_of::<Position>());
+    let remaining = pool
+        .lamports()
+        .checked_sub(refund)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **pool.try_borrow_mut_lamports()? = remaining;
+    **user.try_borrow_mut_lamports()? += refund;
+    position.open = 0;
+    msg!("Refunded {}", refund);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Position, position) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn close_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut pool = TestAccount::new(name_key("pool"), program_id).with_lamports(16_100_000);
+        let mut position = TestAccount::new(name_key("position"), program_id).with_data(
+            &[
+                &name_key("user").to_bytes()[..], // owner
+                &[1],                             // open
+            ]
+            .concat(),
+        );
+        let mut user = TestAccount::new(name_key("user"), system_program::ID).signer();
+        let mut fake_rent = TestAccount::new(name_key("fake_rent"), name_key("attacker_program"))
+            .with_data(
+                &[
+                    &100_000u64.to_le_bytes()[..], // lamports_per_byte_year
+                    &1.0f64.to_le_bytes(),         // exemption_threshold
+                    &[50],                         // burn_percent
+                ]
+                .concat(),
+            );
+        let before_pool = pool.snapshot();
+        let before_position = position.snapshot();
+
+        // 传入每字节年租 100000、豁免倍数 1 的伪造 Rent 关闭仓位
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut pool, &mut position, &mut user, &mut fake_rent],
+            &[],
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        pool.assert_unchanged(&before_pool);
+        position.assert_unchanged(&before_position);
+    }
+}
@@ -0,0 +1,142 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar,
+};
+
+/// 每次心跳付给 keeper 的奖励
+const KEEPER_REWARD: u64 = 100;
+
+/// keeper 每个 slot 可以心跳一次，心跳必须引用一个仍在 SlotHashes 中的 (slot, hash)
+#[repr(C)]
+struct Heartbeat {
+    last_slot: u64,
+    rewards: u64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 在 SlotHashes 数据中查找 slot 对应的哈希：u64 条目数，随后是 (u64 slot, 32 字节哈希)
+fn find_slot_hash(data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+    data[8..]
+        .chunks_exact(40)
+        .take(len)
+        .find(|entry| u64::from_le_bytes(entry[..8].try_into().unwrap()) == slot)
+        .map(|entry| entry[8..].try_into().unwrap())
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let heartbeat_account = next_account_info(accounts_iter)?;
+    let slot_hashes_account = next_account_info(accounts_iter)?;
+
+    if !sysvar::slot_hashes::check_id(slot_hashes_account.key) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let slot = u64::from_le_bytes(instruction_data[..8].try_into().unwrap());
+    let hash: [u8; 32] = instruction_data[8..40].try_into().unwrap();
+
+    let mut data = heartbeat_account.data.borrow_mut();
+    let mut heartbeat = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Heartbeat) };
+
+    // 引用的 slot 必须比上一次心跳新，并且哈希与 SlotHashes 中的一致
+    if slot <= heartbeat.last_slot {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if find_slot_hash(&slot_hashes_account.data.borrow(), slot) != Some(hash) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    heartbeat.last_slot = slot;
+    heartbeat.rewards += KEEPER_REWARD;
+    msg!("Heartbeat at slot {}", slot);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Heartbeat, heartbeat) };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, program_error::ProgramError, pubkey::Pubkey, sysvar};
+    use solracebench_testkit::{name_key, slot_hash, slot_hashes_data, Env, TestAccount};
+
+    #[test]
+    fn heartbeat() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut heartbeat = TestAccount::new(name_key("heartbeat"), program_id).with_data(
+            &[
+                &0u64.to_le_bytes()[..], // last_slot
+                &0u64.to_le_bytes(),     // rewards
+            ]
+            .concat(),
+        );
+        let mut fake_slot_hashes =
+            TestAccount::new(name_key("fake_slot_hashes"), name_key("attacker_program")).with_data(
+                &[
+                    &1u64.to_le_bytes()[..],         // len
+                    &1_000_000_000u64.to_le_bytes(), // slot
+                    &[0x11; 32],                     // hash
+                ]
+                .concat(),
+            );
+
+        // slot 100 引用 slot 99 的哈希心跳
+        env.clock = Clock {
+            slot: 100,
+            ..Clock::default()
+        };
+        let mut slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut heartbeat, &mut slot_hashes],
+            &[&99u64.to_le_bytes()[..], &slot_hash(99).to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // slot 101 引用伪造的 slot 1000000000 心跳
+        env.clock = Clock {
+            slot: 101,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut heartbeat, &mut fake_slot_hashes],
+            &[&1_000_000_000u64.to_le_bytes()[..], &[0x11; 32]].concat(),
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        // slot 102 引用 slot 101 的哈希心跳
+        env.clock = Clock {
+            slot: 102,
+            ..Clock::default()
+        };
+        slot_hashes =
+            TestAccount::sysvar(sysvar::slot_hashes::ID, &slot_hashes_data(env.clock.slot));
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut heartbeat, &mut slot_hashes],
+            &[&101u64.to_le_bytes()[..], &slot_hash(101).to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = unsafe {
+            std::ptr::read_unaligned(heartbeat.data().as_ptr() as *const super::Heartbeat)
+        };
+        assert_eq!(state.last_slot, 101);
+        assert_eq!(state.rewards, 200);
+    }
+}
@@ -0,0 +1,122 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 两次领取之间至少间隔一天
+const COOLDOWN: i64 = 86_400;
+const DRIP_AMOUNT: u64 = 100;
+/// 从未领取过
+const NEVER: i64 = i64::MIN;
+
+/// 水龙头：每个用户一个账户，按冷却时间领取
+#[repr(C)]
+struct Drip {
+    owner: Pubkey,
+    balance: u64,
+    last_claim: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let drip_account = next_account_info(accounts_iter)?;
+    let owner = next_account_info(accounts_iter)?;
+
+    if drip_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let mut data = drip_account.data.borrow_mut();
+    let mut drip = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Drip) };
+
+    match instruction_data {
+        [0] => {
+            if drip.owner != Pubkey::default() {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            drip.owner = *owner.key;
+            drip.last_claim = NEVER;
+        }
+        [1] => {
+            if drip.owner != *owner.key {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let now = Clock::get()?.unix_timestamp;
+            // 从未领取时 last_claim 是 i64::MIN，now - i64::MIN 溢出：首次领取永远失败
+            if now - drip.last_claim < COOLDOWN {
+                msg!("Cooling down since {}", drip.last_claim);
+                return Err(ProgramError::Custom(1));
+            }
+            drip.balance += DRIP_AMOUNT;
+            drip.last_claim = now;
+            msg!("Dripped {}", DRIP_AMOUNT);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Drip, drip) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, Failure, TestAccount};
+
+    #[test]
+    fn open_then_claim_panics() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut drip = TestAccount::new(name_key("drip"), program_id).with_data(
+            &[
+                &[0; 32][..],        // owner
+                &0u64.to_le_bytes(), // balance
+                &0i64.to_le_bytes(), // last_claim
+            ]
+            .concat(),
+        );
+        let mut user = TestAccount::new(name_key("user"), system_program::ID).signer();
+
+        // 开户，last_claim 记为 i64::MIN
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut drip, &mut user],
+            &[0],
+        )
+        .assert_ok();
+
+        // 首次领取
+        let call = env.call_with(
+            super::process_instruction,
+            &mut [&mut drip, &mut user],
+            &[1],
+        );
+        assert!(
+            matches!(call.result, Err(Failure::Panic(_))),
+            "{:?}",
+            call.result
+        );
+
+        let state = unsafe { std::ptr::read_unaligned(drip.data().as_ptr() as *const super::Drip) };
+        assert_eq!(state.owner, name_key("user"));
+        assert_eq!(state.last_claim, -9_223_372_036_854_775_808);
+    }
+}
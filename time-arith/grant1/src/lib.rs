@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 线性归属：从 start 起 duration 秒内按时间比例归属 total
+#[repr(C)]
+struct Grant {
+    beneficiary: Pubkey,
+    total: u64,
+    claimed: u64,
+    start: i64,
+    duration: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let grant_account = next_account_info(accounts_iter)?;
+    let beneficiary = next_account_info(accounts_iter)?;
+
+    if grant_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if instruction_data != [1] {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let mut data = grant_account.data.borrow_mut();
+    let mut grant = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Grant) };
+    if !beneficiary.is_signer || grant.beneficiary != *beneficiary.key {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    // 开始之前 now - start 是负数，转成 u64 后变成极大值，按已经全部归属计算
+    let elapsed = (now - grant.start) as u64;
+    let vested = grant.total as u128 * elapsed.min(grant.duration as u64) as u128
+        / grant.duration as u128;
+    let amount = vested as u64 - grant.claimed;
+    if amount == 0 {
+        msg!("Nothing to claim");
+        return Err(ProgramError::Custom(1));
+    }
+    grant.claimed += amount;
+    msg!("Claimed {}", amount);
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Grant, grant) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{clock::Clock, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn claim() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut grant = TestAccount::new(name_key("grant"), program_id).with_data(
+            &[
+                &name_key("beneficiary").to_bytes()[..], // beneficiary
+                &1000u64.to_le_bytes(),                  // total
+                &0u64.to_le_bytes(),                     // claimed
+                &1_700_000_000i64.to_le_bytes(),         // start
+                &1_000_000i64.to_le_bytes(),             // duration
+            ]
+            .concat(),
+        );
+        let mut scheduled = TestAccount::new(name_key("scheduled"), program_id).with_data(
+            &[
+                &name_key("beneficiary").to_bytes()[..], // beneficiary
+                &1000u64.to_le_bytes(),                  // total
+                &0u64.to_le_bytes(),                     // claimed
+                &1_702_592_000i64.to_le_bytes(),         // start
+                &1_000_000i64.to_le_bytes(),             // duration
+            ]
+            .concat(),
+        );
+        let mut beneficiary =
+            TestAccount::new(name_key("beneficiary"), system_program::ID).signer();
+
+        // 第一份归属已经过了十分之一
+        env.clock = Clock {
+            unix_timestamp: 1_700_100_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut grant, &mut beneficiary],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Claimed 100");
+
+        // 第二份归属 30 天后才开始
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut scheduled, &mut beneficiary],
+            &[1],
+        )
+        .assert_ok()
+        .assert_logged("Claimed 1000");
+
+        let state =
+            unsafe { std::ptr::read_unaligned(grant.data().as_ptr() as *const super::Grant) };
+        assert_eq!(state.claimed, 100);
+        let scheduled_state =
+            unsafe { std::ptr::read_unaligned(scheduled.data().as_ptr() as *const super::Grant) };
+        assert_eq!(scheduled_state.claimed, 1000);
+    }
+}
@@ -0,0 +1,125 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+/// 过期一小时后任何人都可以清理订单
+const GRACE: i64 = 3_600;
+
+/// 挂单：expires_at 为 i64::MAX 表示长期有效
+#[repr(C)]
+struct Order {
+    maker: Pubkey,
+    amount: u64,
+    expires_at: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let order_account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if order_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let mut data = order_account.data.borrow_mut();
+    let mut order = unsafe { std::ptr::read_unaligned(data.as_ptr() as *const Order) };
+    if order.amount == 0 {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    let now = Clock::get()?.unix_timestamp;
+
+    match instruction_data {
+        // 成交
+        [1] => {
+            if now > order.expires_at {
+                return Err(ProgramError::Custom(2));
+            }
+            msg!("Filled {}", order.amount);
+        }
+        // 清理过期订单
+        [2] => {
+            // 宽限期按饱和加法计算，长期有效的订单永远不能清理
+            if now <= order.expires_at.saturating_add(GRACE) {
+                msg!("Order expires at {}", order.expires_at);
+                return Err(ProgramError::Custom(1));
+            }
+            msg!("Swept {}", order.amount);
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    order.amount = 0;
+
+    unsafe { std::ptr::write_unaligned(data.as_mut_ptr() as *mut Order, order) };
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn sweep_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut stale = TestAccount::new(name_key("stale"), program_id).with_data(
+            &[
+                &name_key("maker").to_bytes()[..], // maker
+                &1000u64.to_le_bytes(),            // amount
+                &1_699_990_000i64.to_le_bytes(),   // expires_at
+            ]
+            .concat(),
+        );
+        let mut gtc = TestAccount::new(name_key("gtc"), program_id).with_data(
+            &[
+                &name_key("maker").to_bytes()[..],           // maker
+                &1000u64.to_le_bytes(),                      // amount
+                &9_223_372_036_854_775_807i64.to_le_bytes(), // expires_at
+            ]
+            .concat(),
+        );
+        let mut sweeper = TestAccount::new(name_key("sweeper"), system_program::ID).signer();
+
+        // 清理过期超过一小时的订单
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut stale, &mut sweeper],
+            &[2],
+        )
+        .assert_ok();
+
+        // 清理长期有效的订单
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut gtc, &mut sweeper],
+            &[2],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        let state =
+            unsafe { std::ptr::read_unaligned(stale.data().as_ptr() as *const super::Order) };
+        assert_eq!(state.amount, 0);
+    }
+}
@@ -21,9 +21,10 @@ pub enum BankInstruction {
     Double,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -75,3 +76,32 @@ fn process_instruction(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn increment_double_then_increment() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // Double
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // Increment
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::BankAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.balance, 1);
+    }
+}
@@ -17,9 +17,10 @@ struct TimeSeriesAccount {
     duration: i64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -64,4 +65,43 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_record_end_then_record_start() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 结束
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_100,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 开始
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::TimeSeriesAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.start_time, 1_700_000_100);
+        assert_eq!(state.end_time, 1_700_000_100);
+        assert_eq!(state.duration, 1_700_000_100);
+    }
+}
@@ -0,0 +1,112 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct TimeSeriesAccount {
+    start_time: Option<i64>,
+    end_time: Option<i64>,
+    /// 起止时间都记录之后才计算，先记哪一个都一样
+    duration: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = TimeSeriesAccount {
+            start_time: None,
+            end_time: None,
+            duration: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Account initialized");
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = TimeSeriesAccount::try_from_slice(&account.data.borrow())?;
+    let clock = Clock::get()?; // 获取系统时间
+
+    match instruction {
+        0 => { // 记录开始时间
+            data.start_time = Some(clock.unix_timestamp);
+            msg!("Start time recorded");
+        },
+        1 => { // 记录结束时间
+            data.end_time = Some(clock.unix_timestamp);
+            msg!("End time recorded");
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    if let (Some(start), Some(end)) = (data.start_time, data.end_time) {
+        if end < start {
+            return Err(ProgramError::InvalidArgument);
+        }
+        data.duration = end - start;
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{clock::Clock, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_record_end_then_record_start() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 结束
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_100,
+            ..Clock::default()
+        };
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 开始
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::TimeSeriesAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.start_time, Some(1_700_000_100));
+        assert_eq!(state.end_time, Some(1_700_000_100));
+    }
+}
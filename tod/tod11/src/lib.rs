@@ -14,9 +14,10 @@ struct AuthAccount {
     admin: Pubkey,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -65,4 +66,48 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_set_admin() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut victim],
+            &[0],
+        )
+        .assert_ok();
+
+        // victim 设置管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut victim],
+            &[0],
+        )
+        .assert_ok();
+
+        // attacker 设置管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut attacker],
+            &[0],
+        )
+        .assert_ok();
+
+        let state = super::AuthAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.flags, 1);
+        assert_eq!(state.admin, name_key("attacker"));
+    }
+}
@@ -0,0 +1,119 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct AuthAccount {
+    flags: u8,
+    admin: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 唯一可以执行管理操作的账户
+const ADMIN: Pubkey = solana_program::pubkey!("7ZBNBT9i4nTGTnzpbVd2C3SfKr9h7mGN2CcifvbSAYAb");
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = AuthAccount {
+            flags: 0b00000000,
+            admin: Pubkey::default(),
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Auth account initialized");
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = AuthAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 设置管理员
+            if !signer.is_signer || signer.key != &ADMIN {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            data.admin = *signer.key;
+            data.flags |= 0b00000001; // 设置最低位为管理员标志
+            msg!("Admin set");
+        },
+        1 => { // 切换特权模式（需要管理员权限）
+            if data.flags & 0b00000001 != 0 { // 检查管理员标志
+                data.flags ^= 0b10000000; // 切换最高位
+                msg!("Privilege mode toggled");
+            } else {
+                msg!("No admin rights");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_set_admin_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+        let mut victim = TestAccount::new(name_key("victim"), system_program::ID).signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut victim],
+            &[0],
+        )
+        .assert_ok();
+
+        // victim 设置管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut victim],
+            &[0],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        // attacker 设置管理员
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut attacker],
+            &[0],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        let state = super::AuthAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.flags, 0);
+        assert_eq!(state.admin, Pubkey::default());
+    }
+}
@@ -15,9 +15,10 @@ struct CryptoAccount {
     hash: [u8; 32],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -63,4 +64,35 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use sha2::{Digest, Sha256};
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_xor_then_hash() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // XOR
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 哈希
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        let state = super::CryptoAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.value, [0x55; 32]);
+        assert_eq!(state.hash[..], Sha256::digest(state.value)[..]);
+    }
+}
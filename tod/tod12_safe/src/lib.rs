@@ -0,0 +1,102 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use sha2::{Sha256, Digest};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct CryptoAccount {
+    value: [u8; 32],
+    /// 始终是当前 value 的哈希，由改写 value 的指令同步更新
+    hash: [u8; 32],
+}
+
+fn digest(value: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(value);
+    hasher.finalize().into()
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = CryptoAccount {
+            value: [0xFF; 32],
+            hash: [0; 32],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Crypto account initialized");
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = CryptoAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // XOR运算
+            for byte in &mut data.value {
+                *byte ^= 0xAA;
+            }
+            data.hash = digest(&data.value);
+            msg!("XOR applied");
+        },
+        1 => { // 计算哈希
+            data.hash = digest(&data.value);
+            msg!("Hash computed");
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_xor_then_hash() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // XOR
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 哈希
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        let state = super::CryptoAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.value, [0x55; 32]);
+        assert_eq!(state.hash, super::digest(&state.value));
+    }
+}
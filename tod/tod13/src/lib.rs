@@ -14,9 +14,10 @@ struct FinanceAccount {
     interest_count: u32,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -59,4 +60,38 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_apply_interest_then_deposit() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 计息
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 存款 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::FinanceAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.balance, 1000);
+        assert_eq!(state.interest_count, 1);
+    }
+}
@@ -0,0 +1,123 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct FinanceAccount {
+    balance: u64,
+    interest_count: u32,
+    /// 利息已经结算到的计息周期
+    accrued_period: i64,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 计息周期的秒数，每个周期 5%
+const PERIOD_SECS: i64 = 86_400;
+
+impl FinanceAccount {
+    /// 按经过的完整周期复利结算；同一周期内的存款从下一个周期开始计息，与计息指令的先后无关
+    fn accrue(&mut self, clock: &Clock) -> Result<(), ProgramError> {
+        let period = clock.unix_timestamp.div_euclid(PERIOD_SECS);
+        // 余额非零时不到一千个周期就会溢出，循环次数有界
+        for _ in self.accrued_period..period {
+            if self.balance == 0 {
+                break;
+            }
+            self.balance = u64::try_from(u128::from(self.balance) * 105 / 100)
+                .map_err(|_| ProgramError::ArithmeticOverflow)?;
+        }
+        self.accrued_period = self.accrued_period.max(period);
+        Ok(())
+    }
+}
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let clock = Clock::get()?;
+
+    if account.data_len() == 0 {
+        let init_data = FinanceAccount {
+            balance: 0,
+            interest_count: 0,
+            accrued_period: clock.unix_timestamp.div_euclid(PERIOD_SECS),
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = FinanceAccount::try_from_slice(&account.data.borrow())?;
+    data.accrue(&clock)?;
+
+    match instruction {
+        0 => { // 存款操作
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            data.balance = data.balance.saturating_add(amount);
+            msg!("Deposited: {}", amount);
+        },
+        1 => { // 利息计算：结算已在上面完成，这里只计数
+            data.interest_count += 1;
+            msg!("Interest applied");
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_apply_interest_then_deposit() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 计息
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 存款 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::FinanceAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.balance, 1000);
+        assert_eq!(state.interest_count, 1);
+    }
+}
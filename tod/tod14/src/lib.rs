@@ -15,9 +15,10 @@ struct GameState {
     status: String,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -67,4 +68,39 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_move_then_attack() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 移动 (11, 0)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &11i32.to_le_bytes(), &0i32.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 攻击
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        let state = super::GameState::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.position, (11, 0));
+        assert_eq!(state.health, 70);
+        assert_eq!(state.status, "High ground attack");
+    }
+}
@@ -0,0 +1,121 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct GameState {
+    position: (i32, i32),
+    health: u8,
+    /// 每个 slot 是一个回合，回合内的攻击按回合开始时的位置结算
+    turn_slot: u64,
+    turn_position: (i32, i32),
+    move_status: String,
+    attack_status: String,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let clock = Clock::get()?;
+
+    if account.data_len() == 0 {
+        let init_data = GameState {
+            position: (0, 0),
+            health: 100,
+            turn_slot: clock.slot,
+            turn_position: (0, 0),
+            move_status: "active".to_string(),
+            attack_status: "active".to_string(),
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = GameState::try_from_slice(&account.data.borrow())?;
+    if clock.slot > data.turn_slot {
+        data.turn_slot = clock.slot;
+        data.turn_position = data.position;
+    }
+
+    match instruction {
+        0 => { // 移动操作
+            let x = i32::from_le_bytes(instruction_data[1..5].try_into().unwrap());
+            let y = i32::from_le_bytes(instruction_data[5..9].try_into().unwrap());
+            data.position.0 = data.position.0.saturating_add(x);
+            data.position.1 = data.position.1.saturating_add(y);
+            data.move_status = format!("Moved to ({}, {})", data.position.0, data.position.1);
+        },
+        1 => { // 攻击操作
+            if data.turn_position.0 > 10 {
+                data.health = data.health.saturating_sub(30);
+                data.attack_status = "High ground attack".to_string();
+            } else {
+                data.health = data.health.saturating_sub(10);
+                data.attack_status = "Normal attack".to_string();
+            }
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_move_then_attack() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 移动 (11, 0)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &11i32.to_le_bytes(), &0i32.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 攻击
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        let state = super::GameState::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.position, (11, 0));
+        assert_eq!(state.health, 90);
+        assert_eq!(state.move_status, "Moved to (11, 0)");
+        assert_eq!(state.attack_status, "Normal attack");
+    }
+}
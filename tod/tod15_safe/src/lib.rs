@@ -0,0 +1,113 @@
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct DataPipeline {
+    raw_data: Vec<u8>,
+    /// 总是由 raw_data 按固定的流水线顺序（先压缩后加密）重新生成
+    processed_data: Vec<u8>,
+    processing_steps: u8,
+}
+
+const ENCRYPT: u8 = 0b00000001;
+const COMPRESS: u8 = 0b00000010;
+
+impl DataPipeline {
+    fn rebuild(&mut self) {
+        let mut out = self.raw_data.clone();
+        if self.processing_steps & COMPRESS != 0 {
+            out.retain(|&b| b != 0);
+        }
+        if self.processing_steps & ENCRYPT != 0 {
+            for byte in &mut out {
+                *byte ^= 0b10101010;
+            }
+        }
+        self.processed_data = out;
+    }
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = DataPipeline {
+            raw_data: vec![0; 32],
+            processed_data: vec![0; 32],
+            processing_steps: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = DataPipeline::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 加密操作
+            data.processing_steps |= ENCRYPT;
+            msg!("XOR encryption applied");
+        },
+        1 => { // 压缩操作
+            data.processing_steps |= COMPRESS;
+            msg!("Zero-byte compression applied");
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    data.rebuild();
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_compress_then_encrypt() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 压缩
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 加密
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::DataPipeline::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.processing_steps, 3);
+    }
+}
@@ -14,9 +14,10 @@ struct VotingAccount {
     last_voter: Pubkey,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -60,4 +61,46 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_lock_then_vote_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+        let mut voter = TestAccount::new(name_key("voter"), system_program::ID);
+
+        // 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[0],
+        )
+        .assert_ok();
+
+        // 锁定
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[1],
+        )
+        .assert_ok();
+
+        // 投票
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[0],
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        let state = super::VotingAccount::try_from_slice(account.data()).unwrap();
+        assert!(state.is_locked);
+    }
+}
@@ -0,0 +1,113 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct VotingAccount {
+    total_votes: u64,
+    is_locked: bool,
+    last_voter: Pubkey,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 唯一可以执行管理操作的账户
+const ADMIN: Pubkey = solana_program::pubkey!("7ZBNBT9i4nTGTnzpbVd2C3SfKr9h7mGN2CcifvbSAYAb");
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let voter = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = VotingAccount {
+            total_votes: 0,
+            is_locked: false,
+            last_voter: Pubkey::default(),
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = VotingAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 投票操作
+            if data.is_locked {
+                return Err(ProgramError::Custom(1));
+            }
+            data.total_votes += 1;
+            data.last_voter = *voter.key;
+        },
+        1 => { // 锁定状态
+            if !voter.is_signer || voter.key != &ADMIN {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            data.is_locked = true;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_lock_then_vote() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+        let mut voter = TestAccount::new(name_key("voter"), system_program::ID);
+
+        // 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[0],
+        )
+        .assert_ok();
+
+        // 锁定
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[1],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        // 投票
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut voter],
+            &[0],
+        )
+        .assert_ok();
+
+        let state = super::VotingAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.total_votes, 1);
+        assert_eq!(state.last_voter, name_key("voter"));
+    }
+}
@@ -0,0 +1,124 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct LogisticsAccount {
+    /// 最近两次定位，按设备上报的时间排列，而不是按交易到达的先后
+    locations: [String; 2],
+    reported_at: [i64; 2],
+    status: u8, // 0=待发 1=运输中 2=已签收
+    verification_code: u32,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = LogisticsAccount {
+            locations: ["".to_string(), "".to_string()],
+            reported_at: [i64::MIN; 2],
+            status: 0,
+            verification_code: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = LogisticsAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 更新位置
+            let reported_at = i64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let location = String::from_utf8(instruction_data[9..].to_vec())
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+            let fix = (reported_at, location);
+            let latest = (data.reported_at[1], data.locations[1].clone());
+            let previous = (data.reported_at[0], data.locations[0].clone());
+            // 比两条记录都旧的定位不再影响轨迹
+            if fix > latest {
+                data.reported_at[0] = latest.0;
+                data.locations[0] = latest.1;
+                data.reported_at[1] = fix.0;
+                data.locations[1] = fix.1;
+            } else if fix > previous && fix != latest {
+                data.reported_at[0] = fix.0;
+                data.locations[0] = fix.1;
+            }
+        },
+        1 => { // 状态验证
+            let code = u32::from_le_bytes(instruction_data[1..5].try_into().unwrap());
+            if code == data.verification_code {
+                data.status = data.status.saturating_add(1);
+            }
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn update_location() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::LogisticsAccount {
+                locations: ["A".to_string(), "B".to_string()],
+                reported_at: [1, 2],
+                status: 0,
+                verification_code: 0,
+            })
+            .unwrap(),
+        );
+
+        // 更新位置 C，上报时间 3
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &3i64.to_le_bytes(), b"C"].concat(),
+        )
+        .assert_ok();
+
+        // 更新位置 D，上报时间 4
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &4i64.to_le_bytes(), b"D"].concat(),
+        )
+        .assert_ok();
+
+        let state = super::LogisticsAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.locations, ["C".to_string(), "D".to_string()]);
+        assert_eq!(state.reported_at, [3, 4]);
+    }
+}
@@ -14,9 +14,10 @@ struct LogisticsAccount {
     verification_code: u32,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -61,4 +62,44 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn update_location() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::LogisticsAccount {
+                locations: ["A".to_string(), "B".to_string()],
+                status: 0,
+                verification_code: 0,
+            })
+            .unwrap(),
+        );
+
+        // 更新位置 C
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], b"C"].concat(),
+        )
+        .assert_ok();
+
+        // 更新位置 D
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], b"D"].concat(),
+        )
+        .assert_ok();
+
+        let state = super::LogisticsAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.locations, ["C".to_string(), "D".to_string()]);
+    }
+}
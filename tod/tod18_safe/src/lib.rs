@@ -0,0 +1,120 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct LogisticsAccount {
+    /// 发货时登记的路线，顺序固定
+    route: Vec<String>,
+    /// 已经到达的检查点，第 i 位对应 route[i]
+    reached: u8,
+    status: u8, // 0=待发 1=运输中 2=已签收
+    verification_code: u32,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = LogisticsAccount {
+            route: Vec::new(),
+            reached: 0,
+            status: 0,
+            verification_code: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = LogisticsAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 更新位置：只勾选路线上的检查点，轨迹就是路线本身
+            let location = String::from_utf8(instruction_data[1..].to_vec())
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+            let index = data
+                .route
+                .iter()
+                .take(8)
+                .position(|checkpoint| *checkpoint == location)
+                .ok_or(ProgramError::InvalidArgument)?;
+            data.reached |= 1 << index;
+        },
+        1 => { // 状态验证
+            let code = u32::from_le_bytes(instruction_data[1..5].try_into().unwrap());
+            if code == data.verification_code {
+                data.status = data.status.saturating_add(1);
+            }
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn update_location() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::LogisticsAccount {
+                route: vec![
+                    "A".to_string(),
+                    "B".to_string(),
+                    "C".to_string(),
+                    "D".to_string(),
+                ],
+                reached: 3,
+                status: 0,
+                verification_code: 0,
+            })
+            .unwrap(),
+        );
+
+        // 到达 C
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], b"C"].concat(),
+        )
+        .assert_ok();
+
+        // 到达 D
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], b"D"].concat(),
+        )
+        .assert_ok();
+
+        let state = super::LogisticsAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.reached, 15);
+    }
+}
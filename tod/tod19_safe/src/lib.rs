@@ -0,0 +1,110 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use sha2::{Sha256, Digest};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct ArtAccount {
+    pixels: [[u8; 3]; 64], // RGB像素阵列
+    generation_steps: u8,
+    /// 翻转前后视为同一作品：哈希取两种配色中字典序较小的一种
+    art_hash: [u8; 32],
+}
+
+fn canonical(pixels: &[[u8; 3]; 64]) -> Vec<u8> {
+    let plain = pixels.concat();
+    let inverted: Vec<u8> = plain.iter().map(|c| 255 - c).collect();
+    plain.min(inverted)
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = ArtAccount {
+            pixels: [[0; 3]; 64],
+            generation_steps: 0,
+            art_hash: [0; 32],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = ArtAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 颜色翻转
+            for pixel in &mut data.pixels {
+                pixel[0] = 255 - pixel[0]; // R
+                pixel[1] = 255 - pixel[1]; // G
+                pixel[2] = 255 - pixel[2]; // B
+            }
+            data.generation_steps += 1;
+        },
+        1 => { // 生成哈希
+            let mut hasher = Sha256::new();
+            hasher.update(&canonical(&data.pixels));
+            data.art_hash.copy_from_slice(&hasher.finalize());
+            data.generation_steps += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use sha2::{Digest, Sha256};
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_invert_colors_then_hash() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 颜色翻转
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 生成哈希
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        let state = super::ArtAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.pixels, [[0xff; 3]; 64]);
+        assert_eq!(state.generation_steps, 2);
+        assert_eq!(
+            state.art_hash[..],
+            Sha256::digest(super::canonical(&state.pixels))[..]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+// 定义账户数据结构
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct BankAccount {
+    pub balance: u64,
+    /// 尚未结算的 Increment 次数
+    pub increments: u64,
+    /// 尚未结算的 Double 次数
+    pub doublings: u64,
+}
+
+// 定义支持的指令
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum BankInstruction {
+    Increment,
+    Double,
+    /// 按先加后乘的固定顺序结算挂起的操作
+    Settle,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // 解析指令
+    let instruction = BankInstruction::try_from_slice(instruction_data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    // 获取账户迭代器
+    let accounts_iter = &mut accounts.iter();
+
+    // 获取目标账户
+    let account = next_account_info(accounts_iter)?;
+
+    // 验证账户可写
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = BankAccount {
+            balance: 0,
+            increments: 0,
+            doublings: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Account initialized");
+        return Ok(());
+    }
+    // 反序列化账户数据
+    let mut bank_account = BankAccount::try_from_slice(&account.data.borrow())?;
+
+    // Increment 与 Double 只登记次数，同一批内到达的先后不影响结算结果
+    match instruction {
+        BankInstruction::Increment => {
+            msg!("Executing Increment instruction");
+            bank_account.increments = bank_account.increments.checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        BankInstruction::Double => {
+            msg!("Executing Double instruction");
+            bank_account.doublings = bank_account.doublings.checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        BankInstruction::Settle => {
+            msg!("Executing Settle instruction");
+            let mut balance = bank_account.balance.checked_add(bank_account.increments)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            for _ in 0..bank_account.doublings {
+                balance = balance.checked_mul(2)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+            }
+            bank_account.balance = balance;
+            bank_account.increments = 0;
+            bank_account.doublings = 0;
+        }
+    }
+
+    // 序列化并保存修改后的数据
+    bank_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn increment_double_then_increment() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // Double
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // Increment
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::BankAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.increments, 1);
+        assert_eq!(state.doublings, 1);
+    }
+}
@@ -20,9 +20,10 @@ pub enum WalletInstruction {
     Transfer(u64),
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -71,4 +72,40 @@ fn process_instruction(
 
     wallet.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn set_fee_then_transfer() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Wallet {
+                balance: 1000,
+                fee_rate: 0,
+            })
+            .unwrap(),
+        );
+
+        // SetFee(100)
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 100])
+            .assert_ok();
+
+        // Transfer(100)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &100u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::Wallet::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.balance, 800);
+        assert_eq!(state.fee_rate, 100);
+    }
+}
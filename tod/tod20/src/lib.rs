@@ -14,9 +14,10 @@ struct PricingAccount {
     transaction_count: u32,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -59,4 +60,45 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn adjust_demand() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::PricingAccount {
+                base_price: 100,
+                dynamic_factor: 0.1,
+                transaction_count: 0,
+            })
+            .unwrap(),
+        );
+
+        // 调整 0.3
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &0.3f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 调整 0.7
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &0.7f64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::PricingAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.dynamic_factor, 0.221);
+        assert_eq!(state.transaction_count, 2);
+    }
+}
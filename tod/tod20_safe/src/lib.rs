@@ -0,0 +1,109 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct PricingAccount {
+    base_price: u64,
+    /// 需求调整以基点累加，价格系数是 (10000 + demand_bps) / 10000；整数加法与顺序无关
+    demand_bps: i64,
+    transaction_count: u32,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = PricingAccount {
+            base_price: 100,
+            demand_bps: 0,
+            transaction_count: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = PricingAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 需求调整
+            let adjustment = i64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            data.demand_bps = data
+                .demand_bps
+                .checked_add(adjustment)
+                .filter(|bps| *bps > -10_000)
+                .ok_or(ProgramError::InvalidArgument)?;
+            data.transaction_count += 1;
+        },
+        1 => { // 基础价格更新
+            let new_price = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            data.base_price = new_price;
+            data.transaction_count += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn adjust_demand() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::PricingAccount {
+                base_price: 100,
+                demand_bps: 1000,
+                transaction_count: 0,
+            })
+            .unwrap(),
+        );
+
+        // 调整 3000 基点
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &3000i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 调整 7000 基点
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &7000i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::PricingAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.demand_bps, 11_000);
+        assert_eq!(state.transaction_count, 2);
+    }
+}
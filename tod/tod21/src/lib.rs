@@ -14,9 +14,10 @@ struct BiometricAccount {
     access_log: [u64; 2],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -62,4 +63,42 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_set_threshold() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 阈值 0.9
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &0.8999999761581421f32.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 阈值 0.1
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &0.10000000149011612f32.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::BiometricAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.verification_threshold, 0.1);
+        assert_eq!(state.access_log, [0, 2]);
+    }
+}
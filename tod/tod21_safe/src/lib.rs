@@ -0,0 +1,122 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct BiometricAccount {
+    /// 模板的主人，只有他能改模板和阈值
+    owner: Pubkey,
+    template: Vec<u8>,
+    /// 以基点保存，避免 f32 的舍入和 NaN
+    verification_threshold_bps: u16,
+    access_log: [u64; 2],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = BiometricAccount {
+            owner: *signer.key,
+            template: vec![0; 256],
+            verification_threshold_bps: 8500,
+            access_log: [0; 2],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = BiometricAccount::try_from_slice(&account.data.borrow())?;
+    if !signer.is_signer || *signer.key != data.owner {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    match instruction {
+        0 => { // 更新模板
+            let new_template = instruction_data[1..].to_vec();
+            if new_template.len() == 256 {
+                data.template = new_template;
+                data.access_log[0] += 1;
+            }
+        },
+        1 => { // 调整阈值
+            let new_threshold = u16::from_le_bytes(instruction_data[1..3].try_into().unwrap());
+            if new_threshold > 10_000 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            data.verification_threshold_bps = new_threshold;
+            data.access_log[1] += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_set_threshold_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+
+        // owner 初始化
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[0],
+        )
+        .assert_ok();
+
+        // owner 把阈值设为 9000 基点
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[&[1][..], &9000u16.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // attacker 把阈值设为 1000 基点
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut attacker],
+            &[&[1][..], &1000u16.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        let state = super::BiometricAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.owner, name_key("owner"));
+        assert_eq!(state.verification_threshold_bps, 9000);
+        assert_eq!(state.access_log, [0, 1]);
+    }
+}
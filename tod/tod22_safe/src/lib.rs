@@ -0,0 +1,155 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct RenderAccount {
+    /// 模型坐标，从不原地改写
+    model: [[f32; 3]; 4],
+    /// 总是 transform_matrix 作用于 model 的结果
+    vertices: [[f32; 3]; 4],
+    transform_matrix: [[f32; 4]; 4],
+    render_count: u32,
+}
+
+impl RenderAccount {
+    fn render(&mut self) {
+        for (vertex, point) in self.vertices.iter_mut().zip(&self.model) {
+            for i in 0..3 {
+                vertex[i] = self.transform_matrix[i][0] * point[0] +
+                            self.transform_matrix[i][1] * point[1] +
+                            self.transform_matrix[i][2] * point[2] +
+                            self.transform_matrix[i][3];
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let model = [
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0]
+        ];
+        let init_data = RenderAccount {
+            model,
+            vertices: model,
+            transform_matrix: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0]
+            ],
+            render_count: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = RenderAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 顶点变换：按当前矩阵从模型坐标重新计算
+            data.render();
+            data.render_count += 1;
+        },
+        1 => { // 矩阵更新，每个字节是矩阵元素的整数值
+            let new_matrix: [[f32; 4]; 4] = instruction_data[1..]
+                .chunks_exact(16)
+                .next()
+                .map(|chunk| {
+                    let mut matrix = [[0.0; 4]; 4];
+                    for (i, row) in chunk.chunks_exact(4).enumerate() {
+                        for (j, &byte) in row.iter().enumerate() {
+                            matrix[i][j] = f32::from(byte);
+                        }
+                    }
+                    matrix
+                })
+                .unwrap_or(data.transform_matrix);
+            data.transform_matrix = new_matrix;
+            data.render();
+            data.render_count += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_set_matrix_then_transform() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 矩阵更新为放大两倍
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[
+                0x01, 0x02, 0, 0, 0, 0, 0x02, 0, 0, 0, 0, 0x02, 0, 0, 0, 0, 0x01,
+            ],
+        )
+        .assert_ok();
+
+        // 顶点变换
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::RenderAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(
+            state.model,
+            [[0.0; 3], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        );
+        assert_eq!(
+            state.vertices,
+            [[0.0; 3], [2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0]]
+        );
+        assert_eq!(
+            state.transform_matrix,
+            [
+                [2.0, 0.0, 0.0, 0.0],
+                [0.0, 2.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0]
+            ]
+        );
+        assert_eq!(state.render_count, 2);
+    }
+}
@@ -14,9 +14,10 @@ struct ModelAccount {
     update_count: u32,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -66,4 +67,39 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_decay_learning_rate_then_apply_gradients() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 学习率衰减
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 梯度更新 (1, 1, 1)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[0, 0, 0, 0x80, 0x3f, 0, 0, 0x80, 0x3f, 0, 0, 0x80, 0x3f],
+        )
+        .assert_ok();
+
+        let state = super::ModelAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.weights, [0.491; 3]);
+        assert_eq!(state.learning_rate, 0.009);
+        assert_eq!(state.update_count, 2);
+    }
+}
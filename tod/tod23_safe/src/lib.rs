@@ -0,0 +1,125 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct ModelAccount {
+    weights: [f32; 3],
+    /// 本轮所有梯度更新共用的学习率
+    learning_rate: f32,
+    update_count: u32,
+    round: u64,
+    /// 本轮登记的衰减次数，下一轮开始时才作用于学习率
+    pending_decays: u32,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 每轮训练的 slot 数
+const ROUND_SLOTS: u64 = 150;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let round = Clock::get()?.slot / ROUND_SLOTS;
+
+    if account.data_len() == 0 {
+        let init_data = ModelAccount {
+            weights: [0.5; 3],
+            learning_rate: 0.01,
+            update_count: 0,
+            round,
+            pending_decays: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = ModelAccount::try_from_slice(&account.data.borrow())?;
+    if round > data.round {
+        for _ in 0..data.pending_decays {
+            data.learning_rate *= 0.9;
+        }
+        data.round = round;
+        data.pending_decays = 0;
+    }
+
+    match instruction {
+        0 => { // 梯度更新
+            let gradients: [f32; 3] = instruction_data[1..13]
+                .chunks_exact(4)
+                .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            
+            for (w, g) in data.weights.iter_mut().zip(gradients.iter()) {
+                *w -= data.learning_rate * g;
+            }
+            data.update_count += 1;
+        },
+        1 => { // 学习率衰减，下一轮生效
+            data.pending_decays += 1;
+            data.update_count += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_decay_learning_rate_then_apply_gradients() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 学习率衰减
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 梯度更新 (1, 1, 1)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[0, 0, 0, 0x80, 0x3f, 0, 0, 0x80, 0x3f, 0, 0, 0x80, 0x3f],
+        )
+        .assert_ok();
+
+        let state = super::ModelAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.weights, [0.49; 3]);
+        assert_eq!(state.learning_rate, 0.01);
+        assert_eq!(state.update_count, 2);
+        assert_eq!(state.pending_decays, 1);
+    }
+}
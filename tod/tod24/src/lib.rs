@@ -15,9 +15,10 @@ struct DexAccount {
     price_history: [f64; 2],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -71,4 +72,52 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_add_liquidity_then_swap() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 添加流动性 (1000, 1000)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes(), &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // attacker 添加流动性 (3000, 1000)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &3000u64.to_le_bytes(), &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 交易 100
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &100u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::DexAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.token_a, 3900);
+        assert_eq!(state.token_b, 2200);
+        assert_eq!(state.fee_rate, 3);
+        assert_eq!(state.price_history, [1.7727272727272727, 2.0]);
+    }
+}
@@ -0,0 +1,129 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct DexAccount {
+    token_a: u64,
+    token_b: u64,
+    fee_rate: u8,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = DexAccount {
+            token_a: 0,
+            token_b: 0,
+            fee_rate: 3, // 0.3%
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = DexAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 添加流动性
+            let a = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            let b = u64::from_le_bytes(instruction_data[9..17].try_into().unwrap());
+            // 池子非空时只能按当前比例添加，添加流动性不改变价格
+            let pool_empty = data.token_a == 0 && data.token_b == 0;
+            if !pool_empty && u128::from(a) * u128::from(data.token_b) != u128::from(b) * u128::from(data.token_a) {
+                return Err(ProgramError::InvalidArgument);
+            }
+            data.token_a = data.token_a.checked_add(a).ok_or(ProgramError::ArithmeticOverflow)?;
+            data.token_b = data.token_b.checked_add(b).ok_or(ProgramError::ArithmeticOverflow)?;
+        },
+        1 => { // 执行交易，价格按整数比例计算
+            let amount = u64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            if data.token_b == 0 {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let fee = amount
+                .checked_mul(data.fee_rate as u64)
+                .ok_or(ProgramError::ArithmeticOverflow)?
+                / 1000;
+            let actual_amount = amount - fee;
+            let received = u64::try_from(
+                u128::from(actual_amount) * u128::from(data.token_a) / u128::from(data.token_b),
+            )
+            .map_err(|_| ProgramError::ArithmeticOverflow)?;
+
+            data.token_a = data.token_a.checked_sub(actual_amount).ok_or(ProgramError::InsufficientFunds)?;
+            data.token_b = data.token_b.checked_add(received).ok_or(ProgramError::ArithmeticOverflow)?;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_add_liquidity_then_swap() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 添加流动性 (1000, 1000)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes(), &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // attacker 添加流动性 (3000, 1000)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &3000u64.to_le_bytes(), &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        // 交易 100
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &100u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::DexAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.token_a, 900);
+        assert_eq!(state.token_b, 1100);
+        assert_eq!(state.fee_rate, 3);
+    }
+}
@@ -14,9 +14,10 @@ struct MusicAccount {
     generation: u32,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -69,4 +70,35 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_apply_effects_then_add_note() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 反转
+        env.call_with(super::process_instruction, &mut [&mut account], &[1, 2])
+            .assert_ok();
+
+        // 添加音符 64
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 64])
+            .assert_ok();
+
+        let state = super::MusicAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.notes, vec![60, 60, 60, 60, 60, 60, 60, 60, 64]);
+        assert_eq!(state.effects, 2);
+        assert_eq!(state.generation, 2);
+    }
+}
@@ -0,0 +1,112 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MusicAccount {
+    notes: Vec<u8>,
+    /// 已经生效的效果；之后添加的音符按这些效果写入，结果与添加和效果的先后无关
+    effects: u8,
+    generation: u32,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = MusicAccount {
+            notes: vec![60; 8], // 初始C4音符
+            effects: 0b00000000,
+            generation: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = MusicAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 添加音符
+            let mut new_note = instruction_data[1];
+            if data.effects & 0b00000001 != 0 {
+                new_note = new_note.saturating_add(12);
+            }
+            if data.effects & 0b00000010 != 0 {
+                data.notes.insert(0, new_note);
+            } else {
+                data.notes.push(new_note);
+            }
+            data.generation += 1;
+        },
+        1 => { // 应用效果，每种效果只生效一次
+            let effect_mask = instruction_data[1] & !data.effects;
+            data.effects |= effect_mask;
+            
+            if effect_mask & 0b00000001 != 0 { // 移调效果
+                for note in &mut data.notes {
+                    *note = note.saturating_add(12);
+                }
+            }
+            if effect_mask & 0b00000010 != 0 { // 反转序列
+                data.notes.reverse();
+            }
+            data.generation += 1;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_apply_effects_then_add_note() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 反转
+        env.call_with(super::process_instruction, &mut [&mut account], &[1, 2])
+            .assert_ok();
+
+        // 添加音符 64
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 64])
+            .assert_ok();
+
+        let state = super::MusicAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.notes, vec![64, 60, 60, 60, 60, 60, 60, 60, 60]);
+        assert_eq!(state.effects, 2);
+        assert_eq!(state.generation, 2);
+    }
+}
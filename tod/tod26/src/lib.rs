@@ -14,9 +14,10 @@ struct SupplyChainAccount {
     history: [String; 2],
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -66,4 +67,37 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn ship_then_quality_check() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::SupplyChainAccount {
+                production_stage: 0,
+                quality_check: false,
+                history: ["质检结果: true".to_string(), "未通过质检发货".to_string()],
+            })
+            .unwrap(),
+        );
+
+        // 发货
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 质检通过
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 1])
+            .assert_ok();
+
+        let state = super::SupplyChainAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.production_stage, 2);
+        assert!(state.quality_check);
+    }
+}
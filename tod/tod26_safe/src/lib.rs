@@ -0,0 +1,115 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct SupplyChainAccount {
+    production_stage: u8, // 0=原材料 1=生产 2=质检 3=发货
+    quality_check: bool,
+    /// 发货请求先登记，质检通过后才真正发货
+    ship_requested: bool,
+    history: [String; 2],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    // 验证账户所有权
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // 初始化空账户
+    if account.data_len() == 0 {
+        let init_data = SupplyChainAccount {
+            production_stage: 0,
+            quality_check: false,
+            ship_requested: false,
+            history: ["".to_string(), "".to_string()],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = SupplyChainAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 质检操作
+            if data.production_stage < 2 {
+                data.quality_check = instruction_data[1] != 0;
+                data.production_stage = 2;
+                data.history[0] = format!("质检结果: {}", data.quality_check);
+            }
+        },
+        1 => { // 发货请求
+            data.ship_requested = true;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    // 质检和发货请求哪个先到都一样：两者齐备时发货
+    if data.ship_requested && data.production_stage == 2 && data.quality_check {
+        data.production_stage = 3;
+        data.history[1] = "已发货".to_string();
+    }
+
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn ship_then_quality_check() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::SupplyChainAccount {
+                production_stage: 0,
+                quality_check: false,
+                ship_requested: false,
+                history: ["".to_string(), "".to_string()],
+            })
+            .unwrap(),
+        );
+
+        // 发货
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 质检通过
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 1])
+            .assert_ok();
+
+        let state = super::SupplyChainAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.production_stage, 3);
+        assert!(state.quality_check);
+        assert!(state.ship_requested);
+        assert_eq!(
+            state.history,
+            ["质检结果: true".to_string(), "已发货".to_string()]
+        );
+    }
+}
@@ -14,9 +14,10 @@ struct PredictionMarketAccount {
     bets: [u64; 2], // [支持, 反对]
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -64,4 +65,33 @@ fn process_instruction(
     account.realloc(datalen, false);
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_then_resolve() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // attacker 解析为反对
+        env.call_with(super::process_instruction, &mut [&mut account], &[1, 0])
+            .assert_ok();
+
+        // 解析为支持
+        env.call_with(super::process_instruction, &mut [&mut account], &[1, 1])
+            .assert_ok();
+
+        let state = super::PredictionMarketAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.outcome, Some(false));
+    }
+}
@@ -15,9 +15,10 @@ struct AuctionAccount {
     status: u8, // 0=进行中 1=结束
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -69,4 +70,43 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_set_reserve_price_then_bid() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 保留价改为 500
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &500u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // 出价 800
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &800u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::AuctionAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.highest_bid, 800);
+        assert_eq!(state.reserve_price, 500);
+        assert_eq!(state.bid_count, 1);
+    }
+}
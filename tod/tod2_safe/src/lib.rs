@@ -0,0 +1,115 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Wallet {
+    pub balance: u64,
+    fee_rate: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum WalletInstruction {
+    SetFee(u8),
+    /// 转账金额与调用方接受的最高费率
+    Transfer(u64, u8),
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    // 验证账户所有者
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 初始化检测
+    if account.data.borrow().is_empty() {
+        let init_account = Wallet {
+            balance: 0,
+            fee_rate: 0,
+        };
+        let datalen = borsh::object_length(&init_account).unwrap();
+        account.realloc(datalen, false);
+        init_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let mut wallet = Wallet::try_from_slice(&account.data.borrow())?;
+    let instruction = WalletInstruction::try_from_slice(instruction_data)?;
+
+    match instruction {
+        WalletInstruction::SetFee(rate) => {
+            wallet.fee_rate = rate.min(100);
+        }
+        WalletInstruction::Transfer(amount, max_fee_rate) => {
+            if wallet.fee_rate > max_fee_rate {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let fee = amount.checked_mul(wallet.fee_rate as u64)
+                .and_then(|v| v.checked_div(100))
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            
+            wallet.balance = wallet.balance
+                .checked_sub(amount)
+                .and_then(|v| v.checked_sub(fee))
+                .ok_or(ProgramError::InsufficientFunds)?;
+        }
+    }
+
+    wallet.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn set_fee_then_transfer_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Wallet {
+                balance: 1000,
+                fee_rate: 0,
+            })
+            .unwrap(),
+        );
+
+        // SetFee(100)
+        env.call_with(super::process_instruction, &mut [&mut account], &[0, 100])
+            .assert_ok();
+
+        // Transfer(100)，最高接受费率 0
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &100u64.to_le_bytes(), &[0]].concat(),
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        let state = super::Wallet::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.balance, 1000);
+        assert_eq!(state.fee_rate, 100);
+    }
+}
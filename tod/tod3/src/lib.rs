@@ -20,9 +20,10 @@ pub enum MarketInstruction {
     SetPrice(u64),
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -66,4 +67,39 @@ fn process_instruction(
 
     market.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn set_price_then_apply_discount() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Marketplace {
+                price: 300,
+                discount_threshold: 100,
+            })
+            .unwrap(),
+        );
+
+        // SetPrice(300)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &300u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // ApplyDiscount
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::Marketplace::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.discount_threshold, 600);
+    }
+}
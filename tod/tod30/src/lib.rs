@@ -17,9 +17,10 @@ struct LeaseAccount {
     state: u8, // 0=空闲 1=已出租 2=逾期
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -80,4 +81,56 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn start_lease_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::LeaseAccount {
+                tenant: Pubkey::default(),
+                start_time: 0,
+                end_time: 0,
+                state: 0,
+            })
+            .unwrap(),
+        );
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+        let mut bob = TestAccount::new(name_key("bob"), system_program::ID).signer();
+
+        // bob 开始租赁 30 天
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut bob],
+            &[&[0][..], &30i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // alice 开始租赁 30 天
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut alice],
+            &[&[0][..], &30i64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::Custom(1));
+
+        let state = super::LeaseAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.tenant, name_key("bob"));
+        assert_eq!(state.start_time, 1_700_000_000);
+        assert_eq!(state.end_time, 1_702_592_000);
+        assert_eq!(state.state, 1);
+    }
+}
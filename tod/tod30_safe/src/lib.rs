@@ -0,0 +1,148 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    clock::Clock,
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct LeaseAccount {
+    tenant: Pubkey,
+    start_time: i64,
+    end_time: i64,
+    state: u8, // 0=空闲 1=已出租 2=逾期
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 单次租赁最长的天数
+const MAX_LEASE_DAYS: i64 = 365;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+
+    // 验证账户所有权
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // 初始化空账户
+    if account.data_len() == 0 {
+        let init_data = LeaseAccount {
+            tenant: Pubkey::default(),
+            start_time: 0,
+            end_time: 0,
+            state: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = LeaseAccount::try_from_slice(&account.data.borrow())?;
+    let clock = Clock::get()?;
+
+    match instruction {
+        0 => { // 开始租赁
+            // 空闲的 lease 只能由登记的租户签名开始；先查身份，非租户无论何时调用都得到同一个错误
+            if !payer.is_signer || data.tenant != *payer.key {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if data.state != 0 {
+                return Err(ProgramError::Custom(1));
+            }
+            let duration = i64::from_le_bytes(instruction_data[1..9].try_into().unwrap());
+            if !(1..=MAX_LEASE_DAYS).contains(&duration) {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+
+            data.tenant = *payer.key;
+            data.start_time = clock.unix_timestamp;
+            data.end_time = data
+                .start_time
+                .checked_add(duration * 86400) // 天数转秒
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            data.state = 1;
+        },
+        1 => { // 结束租赁
+            if data.state != 1 {
+                return Err(ProgramError::Custom(2));
+            }
+            
+            if clock.unix_timestamp > data.end_time {
+                data.state = 2; // 标记逾期
+            } else {
+                // 归还后 tenant 仍是登记的租户
+                data.state = 0;
+            }
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{
+        clock::Clock, program_error::ProgramError, pubkey::Pubkey, system_program,
+    };
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn start_lease() {
+        let program_id = Pubkey::new_unique();
+        let mut env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::LeaseAccount {
+                tenant: name_key("alice"),
+                start_time: 0,
+                end_time: 0,
+                state: 0,
+            })
+            .unwrap(),
+        );
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+        let mut bob = TestAccount::new(name_key("bob"), system_program::ID).signer();
+
+        // bob 开始租赁 30 天
+        env.clock = Clock {
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut bob],
+            &[&[0][..], &30i64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        // alice 开始租赁 30 天
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut alice],
+            &[&[0][..], &30i64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::LeaseAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.start_time, 1_700_000_000);
+        assert_eq!(state.end_time, 1_702_592_000);
+        assert_eq!(state.state, 1);
+    }
+}
@@ -15,9 +15,10 @@ struct MultisigAccount {
     executed: bool,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -73,4 +74,51 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn sign_then_execute() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::MultisigAccount {
+                signers: [
+                    name_key("signer_a"),
+                    name_key("signer_b"),
+                    name_key("signer_c"),
+                ],
+                required: 2,
+                signed: [true, false, false],
+                executed: false,
+            })
+            .unwrap(),
+        );
+        let mut signer_b = TestAccount::new(name_key("signer_b"), system_program::ID).signer();
+
+        // signer_b 签名
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut signer_b],
+            &[0],
+        )
+        .assert_ok();
+
+        // 执行
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut signer_b],
+            &[1],
+        )
+        .assert_ok();
+
+        let state = super::MultisigAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.signed, [true, true, false]);
+        assert!(state.executed);
+    }
+}
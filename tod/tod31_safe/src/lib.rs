@@ -0,0 +1,129 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MultisigAccount {
+    signers: [Pubkey; 3],
+    required: u8,
+    signed: [bool; 3],
+    executed: bool,
+    /// 执行请求先登记，签名数达到门限时才真正执行
+    execute_requested: bool,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = MultisigAccount {
+            signers: [Pubkey::default(); 3],
+            required: 2,
+            signed: [false; 3],
+            executed: false,
+            execute_requested: false,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = MultisigAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 添加签名
+            if !signer.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            for (i, s) in data.signers.iter().enumerate() {
+                if s == signer.key {
+                    data.signed[i] = true;
+                    break;
+                }
+            }
+        },
+        1 => { // 请求执行交易
+            data.execute_requested = true;
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    // 签名与执行请求哪个先到都一样：门限满足且已请求时执行一次
+    let signed_count = data.signed.iter().filter(|&&s| s).count();
+    if data.execute_requested && !data.executed && signed_count >= data.required as usize {
+        // 执行转账等业务逻辑
+        data.executed = true;
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn sign_then_execute() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::MultisigAccount {
+                signers: [
+                    name_key("signer_a"),
+                    name_key("signer_b"),
+                    name_key("signer_c"),
+                ],
+                required: 2,
+                signed: [true, false, false],
+                executed: false,
+                execute_requested: false,
+            })
+            .unwrap(),
+        );
+        let mut signer_b = TestAccount::new(name_key("signer_b"), system_program::ID).signer();
+
+        // signer_b 签名
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut signer_b],
+            &[0],
+        )
+        .assert_ok();
+
+        // 执行
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut signer_b],
+            &[1],
+        )
+        .assert_ok();
+
+        let state = super::MultisigAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.signed, [true, true, false]);
+        assert!(state.executed);
+        assert!(state.execute_requested);
+    }
+}
@@ -14,9 +14,10 @@ struct DiscountAccount {
     last_purchase: u64,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -63,4 +64,42 @@ fn process_instruction(
 
     data.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn update_discount_rate_then_purchase() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::DiscountAccount {
+                purchase_count: 5,
+                discount_rate: 0.0,
+                last_purchase: 0,
+            })
+            .unwrap(),
+        );
+
+        // 更新折扣率
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 应用折扣 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::DiscountAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.purchase_count, 6);
+        assert_eq!(state.discount_rate, 0.1);
+        assert_eq!(state.last_purchase, 900);
+    }
+}
@@ -0,0 +1,129 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct DiscountAccount {
+    purchase_count: u32,
+    /// 折扣率以万分之一为单位，避免浮点误差
+    discount_bps: u16,
+    /// 最近一次购买的原价，成交价总是按当前折扣率从它重新计算
+    last_price: u64,
+    last_purchase: u64,
+}
+
+impl DiscountAccount {
+    fn settle(&mut self) -> Result<(), ProgramError> {
+        let paid = u128::from(self.last_price) * u128::from(10_000 - self.discount_bps) / 10_000;
+        self.last_purchase = u64::try_from(paid).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = DiscountAccount {
+            purchase_count: 0,
+            discount_bps: 0,
+            last_price: 0,
+            last_purchase: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = DiscountAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 应用折扣
+            let original_price = instruction_data
+                .get(1..9)
+                .and_then(|b| b.try_into().ok())
+                .map(u64::from_le_bytes)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            data.last_price = original_price;
+        },
+        1 => { // 更新折扣率
+            data.purchase_count = data
+                .purchase_count
+                .checked_add(1)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            data.discount_bps = if data.purchase_count > 10 {
+                2000
+            } else if data.purchase_count > 5 {
+                1000
+            } else {
+                0
+            };
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    // 购买与折扣率更新的先后不影响成交价
+    data.settle()?;
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn update_discount_rate_then_purchase() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::DiscountAccount {
+                purchase_count: 5,
+                discount_bps: 0,
+                last_price: 0,
+                last_purchase: 0,
+            })
+            .unwrap(),
+        );
+
+        // 更新折扣率
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 应用折扣 1000
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[0][..], &1000u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::DiscountAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.purchase_count, 6);
+        assert_eq!(state.discount_bps, 1000);
+        assert_eq!(state.last_price, 1000);
+        assert_eq!(state.last_purchase, 900);
+    }
+}
@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Marketplace {
+    price: u64,
+    discount_threshold: u64,
+    /// 折扣只记为开关，成交价在报价时按当时的价格和门槛计算
+    discounted: bool,
+}
+
+impl Marketplace {
+    fn sale_price(&self) -> u64 {
+        if self.discounted && self.price > self.discount_threshold {
+            (u128::from(self.price) * 75 / 100) as u64
+        } else {
+            self.price
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum MarketInstruction {
+    ApplyDiscount,
+    SetPrice(u64),
+    Quote,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 初始化检测
+    if account.data.borrow().is_empty() {
+        let init_account = Marketplace {
+            price: 0,
+            discount_threshold: 100,
+            discounted: false,
+        };
+        let datalen = borsh::object_length(&init_account).unwrap();
+        account.realloc(datalen, false);
+        init_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let mut market = Marketplace::try_from_slice(&account.data.borrow())?;
+    let instruction = MarketInstruction::try_from_slice(instruction_data)?;
+
+    match instruction {
+        MarketInstruction::ApplyDiscount => {
+            market.discounted = true;
+        }
+        MarketInstruction::SetPrice(new_price) => {
+            market.price = new_price;
+            market.discount_threshold = new_price
+                .checked_mul(2)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        MarketInstruction::Quote => {
+            msg!("sale price: {}", market.sale_price());
+        }
+    }
+
+    market.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn set_price_then_apply_discount() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Marketplace {
+                price: 300,
+                discount_threshold: 100,
+                discounted: false,
+            })
+            .unwrap(),
+        );
+
+        // SetPrice(300)
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account],
+            &[&[1][..], &300u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // ApplyDiscount
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::Marketplace::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.discount_threshold, 600);
+        assert!(state.discounted);
+    }
+}
@@ -22,9 +22,10 @@ pub enum DaoInstruction {
     ConfirmOwnership,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -80,4 +81,53 @@ fn process_instruction(
     account.realloc(datalen, false);
     dao.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn propose_owner_then_confirm_ownership_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::DAO {
+                current_owner: name_key("owner"),
+                pending_owner: None,
+            })
+            .unwrap(),
+        );
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+
+        // owner 提名 alice
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[&[0][..], &name_key("alice").to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // owner 改为提名 bob
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[&[0][..], &name_key("bob").to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // alice 确认
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut alice],
+            &[1],
+        )
+        .assert_err(ProgramError::InvalidArgument);
+
+        let state = super::DAO::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.pending_owner, Some(name_key("bob")));
+    }
+}
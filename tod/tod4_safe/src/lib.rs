@@ -0,0 +1,150 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+// 头部导入与示例1相同
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DAO {
+    current_owner: Pubkey,
+    pending_owner: Option<Pubkey>,
+    /// 提名在这个 slot 之前有效，期间不能改提名
+    nomination_deadline: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum DaoInstruction {
+    ProposeOwner(Pubkey),
+    ConfirmOwnership,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 被提名者确认所有权的期限，约一天
+const NOMINATION_SLOTS: u64 = 216_000;
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let signer = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 初始化检测
+    if account.data.borrow().is_empty() {
+        let init_account = DAO {
+            current_owner: *program_id, // 初始所有者设为程序本身
+            pending_owner: None,
+            nomination_deadline: 0,
+        };
+        let datalen = borsh::object_length(&init_account).unwrap();
+        account.realloc(datalen, false);
+        init_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let mut dao = DAO::try_from_slice(&account.data.borrow())?;
+    let instruction = DaoInstruction::try_from_slice(instruction_data)?;
+
+    if !signer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let clock = Clock::get()?;
+    let nomination_open = dao.pending_owner.is_some() && clock.slot < dao.nomination_deadline;
+
+    match instruction {
+        DaoInstruction::ProposeOwner(new_owner) => {
+            // 提名一经发出，所有者就交出提名权，直到提名被确认或过期
+            if *signer.key != dao.current_owner || nomination_open {
+                return Err(ProgramError::IllegalOwner);
+            }
+            dao.pending_owner = Some(new_owner);
+            dao.nomination_deadline = clock
+                .slot
+                .checked_add(NOMINATION_SLOTS)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+        DaoInstruction::ConfirmOwnership => {
+            if let Some(pending) = dao.pending_owner {
+                if *signer.key != pending || !nomination_open {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                dao.current_owner = pending;
+                dao.pending_owner = None;
+                dao.nomination_deadline = 0;
+            }
+        }
+    }
+    let datalen = borsh::object_length(&dao).unwrap();
+    account.realloc(datalen, false);
+    dao.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn propose_owner_then_confirm_ownership() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::DAO {
+                current_owner: name_key("owner"),
+                pending_owner: None,
+                nomination_deadline: 0,
+            })
+            .unwrap(),
+        );
+        let mut owner = TestAccount::new(name_key("owner"), system_program::ID).signer();
+        let mut alice = TestAccount::new(name_key("alice"), system_program::ID).signer();
+
+        // owner 提名 alice
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[&[0][..], &name_key("alice").to_bytes()].concat(),
+        )
+        .assert_ok();
+
+        // owner 改为提名 bob
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut owner],
+            &[&[0][..], &name_key("bob").to_bytes()].concat(),
+        )
+        .assert_err(ProgramError::IllegalOwner);
+
+        // alice 确认
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut alice],
+            &[1],
+        )
+        .assert_ok();
+
+        let state = super::DAO::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.current_owner, name_key("alice"));
+    }
+}
@@ -20,9 +20,10 @@ pub enum AuctionInstruction {
     CloseAuction,
 }
 
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
-fn process_instruction(
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -74,3 +75,44 @@ fn process_instruction(
     auction.serialize(&mut &mut account.data.borrow_mut()[..])?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn close_auction_then_place_bid_is_rejected() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Auction {
+                highest_bid: 0,
+                is_active: true,
+            })
+            .unwrap(),
+        );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID).signer();
+
+        // attacker 关闭拍卖
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut attacker],
+            &[1],
+        )
+        .assert_ok();
+
+        // bidder 出价 500
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut bidder],
+            &[&[0][..], &500u64.to_le_bytes()].concat(),
+        )
+        .assert_err(ProgramError::InvalidAccountData);
+
+        let state = super::Auction::try_from_slice(account.data()).unwrap();
+        assert!(!state.is_active);
+    }
+}
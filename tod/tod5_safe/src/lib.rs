@@ -0,0 +1,124 @@
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Auction {
+    highest_bid: u64,
+    is_active: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum AuctionInstruction {
+    PlaceBid(u64),
+    CloseAuction,
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+/// 唯一可以执行管理操作的账户
+const ADMIN: Pubkey = solana_program::pubkey!("7ZBNBT9i4nTGTnzpbVd2C3SfKr9h7mGN2CcifvbSAYAb");
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+    let bidder = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if !account.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // 初始化检测
+    if account.data.borrow().is_empty() {
+        let init_account = Auction {
+            highest_bid: 0,
+            is_active: true, // 默认开启拍卖
+        };
+        let datalen = borsh::object_length(&init_account).unwrap();
+        account.realloc(datalen, false);
+        init_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    if !bidder.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut auction = Auction::try_from_slice(&account.data.borrow())?;
+    let instruction = AuctionInstruction::try_from_slice(instruction_data)?;
+
+    match instruction {
+        AuctionInstruction::PlaceBid(amount) => {
+            if !auction.is_active {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if amount > auction.highest_bid {
+                auction.highest_bid = amount;
+            }
+        }
+        AuctionInstruction::CloseAuction => {
+            if !bidder.is_signer || bidder.key != &ADMIN {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            auction.is_active = false;
+        }
+    }
+
+    auction.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::{program_error::ProgramError, pubkey::Pubkey, system_program};
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn close_auction_then_place_bid() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id).with_data(
+            &borsh::to_vec(&super::Auction {
+                highest_bid: 0,
+                is_active: true,
+            })
+            .unwrap(),
+        );
+        let mut attacker = TestAccount::new(name_key("attacker"), system_program::ID).signer();
+        let mut bidder = TestAccount::new(name_key("bidder"), system_program::ID).signer();
+
+        // attacker 关闭拍卖
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut attacker],
+            &[1],
+        )
+        .assert_err(ProgramError::MissingRequiredSignature);
+
+        // bidder 出价 500
+        env.call_with(
+            super::process_instruction,
+            &mut [&mut account, &mut bidder],
+            &[&[0][..], &500u64.to_le_bytes()].concat(),
+        )
+        .assert_ok();
+
+        let state = super::Auction::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.highest_bid, 500);
+    }
+}
@@ -16,6 +16,7 @@ pub struct DataAccount {
 }
 
 // 声明程序入口点
+#[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
 pub fn process_instruction(
@@ -77,4 +78,34 @@ pub fn process_instruction(
     data_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn operation_a_operation_b_then_operation_a() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化并执行 A
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // B
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // A
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::DataAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.value, 30);
+        assert_eq!(state.last_operation, "OperationA");
+    }
+}
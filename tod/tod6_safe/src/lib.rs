@@ -0,0 +1,125 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+// 定义数据结构
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DataAccount {
+    /// 操作 A 累加到基数上
+    pub base: u64,
+    /// 操作 B 把倍率翻倍；对外的值是 `base * multiplier`，与 A、B 的先后无关
+    pub multiplier: u64,
+    pub a_count: u64,
+    pub b_count: u64,
+}
+
+impl DataAccount {
+    pub fn value(&self) -> u64 {
+        self.base.wrapping_mul(self.multiplier)
+    }
+}
+
+// 声明程序入口点
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // 解析指令类型（0或1）
+    let instruction = instruction_data[0];
+    
+    // 获取账户迭代器
+    let accounts_iter = &mut accounts.iter();
+    
+    // 获取目标账户
+    let account = next_account_info(accounts_iter)?;
+
+    // 验证程序拥有该账户
+    if account.owner != program_id {
+        msg!("Account does not have the correct program id");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // 初始化检查（数据长度为0时初始化）
+    if account.data_len() == 0 {
+        msg!("Initializing account");
+        let mut data = DataAccount {
+            base: 0,
+            multiplier: 1,
+            a_count: 0,
+            b_count: 0,
+        };
+        let datalen = borsh::object_length(&data).unwrap();
+        account.realloc(datalen, false);
+        data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    }
+
+    // 反序列化现有数据
+    let mut data_account = DataAccount::try_from_slice(&account.data.borrow())?;
+
+    // 根据指令类型处理不同操作
+    match instruction {
+        0 => {  // 操作A：基数加 10 并计数
+            data_account.base = data_account.base.wrapping_add(10);
+            data_account.a_count += 1;
+            msg!("Executed Operation A");
+        },
+        1 => {  // 操作B：倍率翻倍并计数
+            data_account.multiplier = data_account.multiplier.wrapping_mul(2);
+            data_account.b_count += 1;
+            msg!("Executed Operation B");
+        },
+        _ => {
+            msg!("Invalid instruction");
+            return Err(ProgramError::InvalidInstructionData)
+        }
+    }
+
+    // 序列化并保存修改后的数据
+    let datalen = borsh::object_length(&data_account).unwrap();
+    account.realloc(datalen, false);
+    data_account.serialize(&mut &mut account.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn operation_a_operation_b_then_operation_a() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化并执行 A
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // B
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // A
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::DataAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.base, 20);
+        assert_eq!(state.multiplier, 2);
+        assert_eq!(state.a_count, 2);
+        assert_eq!(state.b_count, 1);
+    }
+}
@@ -0,0 +1,103 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MathAccount {
+    /// 绝对值与符号分开保存：平方只作用于绝对值，取反只翻转符号，两者可以交换
+    magnitude: u64,
+    negative: bool,
+    history: [String; 2],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    // 验证账户所有权
+    if account.owner != program_id {
+        msg!("Invalid account owner");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // 初始化空账户
+    if account.data_len() == 0 {
+        let init_data = MathAccount {
+            magnitude: 100,
+            negative: false,
+            history: ["init".to_string(), "init".to_string()],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Account initialized");
+        return Ok(());
+    }
+
+    // 解析指令
+    let instruction = instruction_data[0];
+    let mut data = MathAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 平方运算
+            data.magnitude = data
+                .magnitude
+                .checked_pow(2)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            data.history[0] = "square".to_string();
+        },
+        1 => { // 取反运算
+            data.negative = !data.negative;
+            data.history[1] = "negate".to_string();
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_negate_then_square() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 取反
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 平方
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::MathAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.magnitude, 10_000);
+        assert!(state.negative);
+        assert_eq!(state.history, ["square".to_string(), "negate".to_string()]);
+    }
+}
@@ -0,0 +1,117 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StringAccount {
+    /// 原文保持不变，变换只记次数
+    content: String,
+    encodings: u8,
+    reversals: u8,
+    transformations: u8,
+}
+
+impl StringAccount {
+    /// 按固定顺序展示：先反转，再逐次编码
+    fn rendered(&self) -> String {
+        let mut out = if self.reversals % 2 == 1 {
+            self.content.chars().rev().collect()
+        } else {
+            self.content.clone()
+        };
+        for _ in 0..self.encodings {
+            out = base64::encode(&out);
+        }
+        out
+    }
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // 初始化检查
+    if account.data_len() == 0 {
+        let init_data = StringAccount {
+            content: "default".to_string(),
+            encodings: 0,
+            reversals: 0,
+            transformations: 0,
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = StringAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // Base64编码
+            data.encodings = data.encodings.checked_add(1).ok_or(ProgramError::InvalidInstructionData)?;
+            data.transformations += 1;
+        },
+        1 => { // 反转字符串
+            data.reversals = data.reversals.wrapping_add(1);
+            data.transformations += 1;
+        },
+        2 => { // 展示变换后的内容
+            msg!("{}", data.rendered());
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+    let datalen = borsh::object_length(&data).unwrap();
+    account.realloc(datalen, false);
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_reverse_then_base64_encode() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 反转
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // Base64 编码
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::StringAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.content, "default");
+        assert_eq!(state.encodings, 1);
+        assert_eq!(state.reversals, 1);
+        assert_eq!(state.transformations, 2);
+    }
+}
@@ -0,0 +1,101 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct MatrixAccount {
+    matrix: [[i32; 2]; 2],
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let account = next_account_info(accounts_iter)?;
+
+    if account.owner != program_id {
+        msg!("Invalid owner");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if account.data_len() == 0 {
+        let init_data = MatrixAccount {
+            matrix: [[1, 2], [3, 4]],
+        };
+        let datalen = borsh::object_length(&init_data).unwrap();
+        account.realloc(datalen, false);
+        init_data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+        msg!("Matrix initialized");
+        return Ok(());
+    }
+
+    let instruction = instruction_data[0];
+    let mut data = MatrixAccount::try_from_slice(&account.data.borrow())?;
+
+    match instruction {
+        0 => { // 转置矩阵
+            let mut new_matrix = [[0; 2]; 2];
+            for i in 0..2 {
+                for j in 0..2 {
+                    new_matrix[j][i] = data.matrix[i][j];
+                }
+            }
+            data.matrix = new_matrix;
+            msg!("Matrix transposed");
+        },
+        1 => { // 所有元素加3，任一元素溢出时整条指令失败
+            for i in 0..2 {
+                for j in 0..2 {
+                    data.matrix[i][j] = data.matrix[i][j]
+                        .checked_add(3)
+                        .ok_or(ProgramError::ArithmeticOverflow)?;
+                }
+            }
+            msg!("Elements incremented");
+        },
+        _ => return Err(ProgramError::InvalidInstructionData)
+    }
+
+    data.serialize(&mut &mut account.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshDeserialize;
+    use solana_program::pubkey::Pubkey;
+    use solracebench_testkit::{name_key, Env, TestAccount};
+
+    #[test]
+    fn initialize_add_three_then_transpose() {
+        let program_id = Pubkey::new_unique();
+        let env = Env::new(program_id);
+        let mut account = TestAccount::new(name_key("account"), program_id);
+
+        // 初始化
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        // 加 3
+        env.call_with(super::process_instruction, &mut [&mut account], &[1])
+            .assert_ok();
+
+        // 转置
+        env.call_with(super::process_instruction, &mut [&mut account], &[0])
+            .assert_ok();
+
+        let state = super::MatrixAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(state.matrix, [[4, 6], [5, 7]]);
+    }
+}